@@ -2,7 +2,9 @@
 extern crate log;
 extern crate ofws_rendering_glium;
 
-use ofws_core::data::color::{Color, BLACK, BLUE, CYAN, GREEN, ORANGE, RED, WHITE, YELLOW};
+use ofws_core::data::color::{
+    Color, Palette, BLACK, BLUE, CYAN, GREEN, ORANGE, RED, WHITE, YELLOW,
+};
 use ofws_core::data::map::generation::io::read_map_generator;
 use ofws_core::data::map::Map2d;
 use ofws_core::data::math::selector::Selector;
@@ -54,15 +56,18 @@ impl BiomeExample {
     }
 }
 
-fn create_map(path: &str) -> Option<Map2d> {
+fn update_map(path: &str, map: &mut Option<Map2d>) {
     match read_map_generator(path) {
         Ok(map_generation) => {
             info!("Loaded map generator from '{}'", path);
-            Some(map_generation.generate())
+            match map {
+                Some(map) => map_generation.generate_into(map),
+                None => *map = Some(map_generation.generate()),
+            }
         }
         Err(error) => {
             error!("Failed loading '{}' with {:?}", path, error);
-            None
+            *map = None;
         }
     }
 }
@@ -88,16 +93,8 @@ fn create_elevation_color_interpolator() -> Selector<Color> {
 }
 
 fn create_temperature_color_interpolator() -> Selector<Color> {
-    let vector = vec![
-        (0u8, WHITE),
-        (51, CYAN),
-        (102, BLUE),
-        (153, GREEN),
-        (204, YELLOW),
-        (255, RED),
-    ];
-
-    Selector::new_interpolate_vector(vector).unwrap()
+    let palette = Palette::new(vec![WHITE, CYAN, BLUE, GREEN, YELLOW, RED]);
+    Selector::from_palette_ramp(&[0, 51, 102, 153, 204, 255], &palette).unwrap()
 }
 
 fn create_rainfall_color_interpolator() -> Selector<Color> {
@@ -176,7 +173,7 @@ fn create_biome_renderer() -> CellRenderer {
 impl App for BiomeExample {
     fn init(&mut self, initialization: &mut dyn Initialization) {
         self.texture_id = initialization.load_texture("ascii.png");
-        self.map = create_map(&self.path);
+        update_map(&self.path, &mut self.map);
     }
 
     fn render(&mut self, renderer: &mut dyn Renderer) {
@@ -216,7 +213,7 @@ impl App for BiomeExample {
         } else if key == KeyCode::Key4 {
             self.attribute_renderer = create_biome_renderer();
         } else if key == KeyCode::Space {
-            self.map = create_map(&self.path);
+            update_map(&self.path, &mut self.map);
             self.start_x = 0;
             self.start_y = 0;
             self.tile_size = DEFAULT_TILE_SIZE;
@@ -56,6 +56,57 @@ impl Color {
     pub fn b(&self) -> u8 {
         self.b
     }
+
+    /// Returns a new color from the HSV color model.
+    ///
+    /// The hue is in degrees, while saturation & value are in the range [0,1].
+    ///
+    /// ```
+    ///# use ofws_core::data::color::{Color, BLUE, RED, WHITE, YELLOW};
+    /// assert_eq!(Color::from_hsv(0.0, 1.0, 1.0), RED);
+    /// assert_eq!(Color::from_hsv(60.0, 1.0, 1.0), YELLOW);
+    /// assert_eq!(Color::from_hsv(240.0, 1.0, 1.0), BLUE);
+    /// assert_eq!(Color::from_hsv(360.0, 1.0, 1.0), RED);
+    /// assert_eq!(Color::from_hsv(123.0, 0.0, 1.0), WHITE);
+    /// ```
+    pub fn from_hsv(hue: f32, saturation: f32, value: f32) -> Color {
+        let convert = |n: f32| {
+            let k = (n + hue / 60.0).rem_euclid(6.0);
+            let factor = value - value * saturation * k.min(4.0 - k).clamp(0.0, 1.0);
+            (factor * 255.0).round() as u8
+        };
+
+        Color::new(convert(5.0), convert(3.0), convert(1.0))
+    }
+
+    /// Returns the hue in degrees, the saturation & the value of the HSV color model.
+    ///
+    /// ```
+    ///# use ofws_core::data::color::{Color, BLACK, CYAN, RED};
+    /// assert_eq!(RED.to_hsv(), (0.0, 1.0, 1.0));
+    /// assert_eq!(CYAN.to_hsv(), (180.0, 1.0, 1.0));
+    /// assert_eq!(BLACK.to_hsv(), (0.0, 0.0, 0.0));
+    /// assert_eq!(Color::gray(51).to_hsv(), (0.0, 0.0, 0.2));
+    /// ```
+    pub fn to_hsv(&self) -> (f32, f32, f32) {
+        let [r, g, b]: [f32; 3] = (*self).into();
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let chroma = max - min;
+
+        let hue = if chroma == 0.0 {
+            0.0
+        } else if max == r {
+            60.0 * ((g - b) / chroma).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / chroma + 2.0)
+        } else {
+            60.0 * ((r - g) / chroma + 4.0)
+        };
+        let saturation = if max == 0.0 { 0.0 } else { chroma / max };
+
+        (hue, saturation, max)
+    }
 }
 
 impl Interpolate for Color {
@@ -79,7 +130,34 @@ impl Interpolate for Color {
     }
 }
 
-impl Selection for Color {}
+impl Selection for Color {
+    /// Interpolates with another color in the HSV color model along the shorter arc of the hue.
+    ///
+    /// ```
+    ///# use ofws_core::data::color::{Color, BLUE, GREEN, RED, YELLOW};
+    ///# use ofws_core::data::math::selector::Selection;
+    /// assert_eq!(RED.lerp_hsv(&GREEN, 0.5), YELLOW);
+    /// assert_eq!(RED.lerp_hsv(&BLUE, 0.5), Color::new(255, 0, 255));
+    /// ```
+    fn lerp_hsv(&self, other: &Color, factor: f32) -> Color {
+        let factor = factor.clamp(0.0, 1.0);
+        let (hue0, saturation0, value0) = self.to_hsv();
+        let (hue1, saturation1, value1) = other.to_hsv();
+        let mut diff = hue1 - hue0;
+
+        if diff > 180.0 {
+            diff -= 360.0;
+        } else if diff < -180.0 {
+            diff += 360.0;
+        }
+
+        Color::from_hsv(
+            (hue0 + diff * factor).rem_euclid(360.0),
+            saturation0 + (saturation1 - saturation0) * factor,
+            value0 + (value1 - value0) * factor,
+        )
+    }
+}
 
 impl Default for Color {
     fn default() -> Self {
@@ -103,6 +181,51 @@ impl From<Color> for [f32; 3] {
     }
 }
 
+/// An ordered list of colors, that can be combined with different thresholds.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Palette {
+    colors: Vec<Color>,
+}
+
+impl Palette {
+    pub fn new(colors: Vec<Color>) -> Palette {
+        Palette { colors }
+    }
+
+    /// Returns the colors of the palette.
+    ///
+    /// ```
+    ///# use ofws_core::data::color::{Palette, BLUE, RED};
+    /// let palette = Palette::new(vec![RED, BLUE]);
+    ///
+    /// assert_eq!(palette.get_colors(), &[RED, BLUE]);
+    /// ```
+    pub fn get_colors(&self) -> &[Color] {
+        &self.colors
+    }
+
+    /// Returns the number of colors.
+    ///
+    /// ```
+    ///# use ofws_core::data::color::{Palette, BLUE, RED};
+    /// assert_eq!(Palette::new(vec![RED, BLUE]).len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.colors.len()
+    }
+
+    /// Returns true, if the palette has no colors.
+    ///
+    /// ```
+    ///# use ofws_core::data::color::{Palette, RED};
+    /// assert!(Palette::new(vec![]).is_empty());
+    /// assert!(!Palette::new(vec![RED]).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.colors.is_empty()
+    }
+}
+
 pub const BLACK: Color = Color::new(0, 0, 0);
 pub const BLUE: Color = Color::new(0, 0, 255);
 pub const CYAN: Color = Color::new(0, 255, 255);
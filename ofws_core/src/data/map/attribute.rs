@@ -6,7 +6,7 @@ use crate::data::math::size2d::Size2d;
 /// * elevation
 /// * rainfall
 /// * temperature
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Attribute {
     name: String,
     size: Size2d,
@@ -43,6 +43,10 @@ impl Attribute {
         &self.name
     }
 
+    pub(crate) fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+
     /// Returns the size of the map.
     ///
     /// ```
@@ -157,6 +161,76 @@ impl Attribute {
         self.values = values;
     }
 
+    /// Swaps all values with the ones in the vector.
+    pub(crate) fn swap_all(&mut self, values: &mut Vec<u8>) {
+        assert_eq!(
+            values.len(),
+            self.values.len(),
+            "Wrong number of new values!"
+        );
+        std::mem::swap(&mut self.values, values);
+    }
+
+    /// Sets all of the attribute's values to the same value.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::attribute::Attribute;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut attribute = Attribute::new("elevation", Size2d::new(1, 2), vec![10, 15]);
+    ///
+    /// attribute.fill(7);
+    ///
+    /// assert_eq!(attribute.get_all(), &vec![7u8, 7]);
+    /// ```
+    pub fn fill(&mut self, value: u8) {
+        self.values.iter_mut().for_each(|v| *v = value);
+    }
+
+    /// Sets all values inside a rectangle to the same value.
+    /// Both corners are included, swapped if inverted & clamped to the size of the attribute.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::attribute::Attribute;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut attribute = Attribute::default_value("elevation", Size2d::new(4, 3), 0);
+    ///
+    /// attribute.fill_rect(1, 1, 2, 1, 9);
+    ///
+    /// assert_eq!(attribute.get_all(), &vec![0u8, 0, 0, 0,
+    ///                                       0, 9, 9, 0,
+    ///                                       0, 0, 0, 0]);
+    /// ```
+    ///
+    /// A rectangle extending past the edge is clamped:
+    ///
+    /// ```
+    ///# use ofws_core::data::map::attribute::Attribute;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut attribute = Attribute::default_value("elevation", Size2d::new(4, 3), 0);
+    ///
+    /// attribute.fill_rect(10, 5, 2, 1, 9);
+    ///
+    /// assert_eq!(attribute.get_all(), &vec![0u8, 0, 0, 0,
+    ///                                       0, 0, 9, 9,
+    ///                                       0, 0, 9, 9]);
+    /// ```
+    pub fn fill_rect(&mut self, min_x: u32, min_y: u32, max_x: u32, max_y: u32, value: u8) {
+        if self.size.get_area() == 0 {
+            return;
+        }
+
+        let (min_x, max_x) = (min_x.min(max_x), min_x.max(max_x));
+        let (min_y, max_y) = (min_y.min(max_y), min_y.max(max_y));
+        let max_x = max_x.min(self.size.width() - 1);
+        let max_y = max_y.min(self.size.height() - 1);
+
+        for y in min_y..=max_y {
+            for x in min_x..=max_x {
+                self.values[self.size.to_index_risky(x, y)] = value;
+            }
+        }
+    }
+
     /// Replaces some of the attribute's values.
     ///
     /// ```
@@ -185,4 +259,59 @@ impl Attribute {
             self.values[*index] = value;
         }
     }
+
+    /// Returns the smallest & the biggest value. An empty attribute returns (0, 0).
+    ///
+    /// ```
+    ///# use ofws_core::data::map::attribute::Attribute;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let attribute = Attribute::new("elevation", Size2d::new(2, 2), vec![10, 200, 30, 10]);
+    ///
+    /// assert_eq!(attribute.min_max(), (10, 200));
+    /// ```
+    pub fn min_max(&self) -> (u8, u8) {
+        let min = self.values.iter().min().copied().unwrap_or(0);
+        let max = self.values.iter().max().copied().unwrap_or(0);
+        (min, max)
+    }
+
+    /// Returns how often each value occurs.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::attribute::Attribute;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let attribute = Attribute::new("elevation", Size2d::new(2, 2), vec![10, 200, 30, 10]);
+    /// let histogram = attribute.histogram();
+    ///
+    /// assert_eq!(histogram[10], 2);
+    /// assert_eq!(histogram[30], 1);
+    /// assert_eq!(histogram[200], 1);
+    /// assert_eq!(histogram.iter().sum::<u32>(), 4);
+    /// ```
+    pub fn histogram(&self) -> [u32; 256] {
+        let mut histogram = [0; 256];
+
+        for value in self.values.iter() {
+            histogram[*value as usize] += 1;
+        }
+
+        histogram
+    }
+
+    /// Iterates over the coordinates & value of each cell in row-major order.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::attribute::Attribute;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let attribute = Attribute::new("elevation", Size2d::new(2, 2), vec![10, 20, 30, 40]);
+    /// let cells: Vec<(u32, u32, u8)> = attribute.iter_with_coords().collect();
+    ///
+    /// assert_eq!(cells, vec![(0, 0, 10), (1, 0, 20), (0, 1, 30), (1, 1, 40)]);
+    /// ```
+    pub fn iter_with_coords(&self) -> impl Iterator<Item = (u32, u32, u8)> + '_ {
+        self.values.iter().enumerate().map(move |(index, value)| {
+            let [x, y] = self.size.to_x_and_y(index);
+            (x, y, *value)
+        })
+    }
 }
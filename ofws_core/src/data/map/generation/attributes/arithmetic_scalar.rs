@@ -0,0 +1,98 @@
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
+use crate::data::map::Map2d;
+use crate::data::math::operation::ScalarOp;
+use serde::{Deserialize, Serialize};
+
+/// Applies a constant to each value of an [`Attribute`].
+///
+/// See [`ScalarOp`].
+#[derive(new, Debug, Clone)]
+pub struct ArithmeticScalar {
+    attribute_id: usize,
+    op: ScalarOp,
+    operand: u8,
+}
+
+impl ArithmeticScalar {
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::arithmetic_scalar::ArithmeticScalar;
+    ///# use ofws_core::data::math::operation::ScalarOp;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let tests = vec![
+    ///     (ScalarOp::Add, 100, vec![100u8, 150, 255]),
+    ///     (ScalarOp::Div, 2, vec![0u8, 25, 100]),
+    ///     (ScalarOp::Mul, 3, vec![0u8, 150, 255]),
+    ///     (ScalarOp::Sub, 100, vec![0u8, 0, 100]),
+    /// ];
+    ///
+    /// for (op, operand, result) in tests {
+    ///     let mut map = Map2d::new(Size2d::new(3, 1));
+    ///     let attribute_id = map.create_attribute_from("elevation", vec![0, 50, 200]).unwrap();
+    ///     let step = ArithmeticScalar::new(attribute_id, op, operand);
+    ///
+    ///     step.run(&mut map);
+    ///
+    ///     assert_eq!(map.get_attribute(attribute_id).get_all(), &result);
+    /// }
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        log_info!(
+            "Apply {:?} with {} to attribute '{}' of map '{}'",
+            self.op,
+            self.operand,
+            map.get_attribute(self.attribute_id).get_name(),
+            map.get_name(),
+        );
+
+        let attribute = map.get_attribute_mut(self.attribute_id);
+        let values = attribute
+            .get_all()
+            .iter()
+            .map(|value| self.op.apply(*value, self.operand))
+            .collect();
+
+        attribute.replace_all(values);
+    }
+}
+
+/// For serializing, deserializing & validating [`ArithmeticScalar`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::arithmetic_scalar::{ArithmeticScalarData, ArithmeticScalar};
+///# use ofws_core::data::math::operation::ScalarOp;
+/// let data = ArithmeticScalarData::new("attribute".to_string(), ScalarOp::Mul, 3);
+/// let attributes = vec!["attribute".to_string()];
+/// let step: ArithmeticScalar = data.clone().try_convert(&attributes).unwrap();
+/// let result: ArithmeticScalarData = step.convert(&attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct ArithmeticScalarData {
+    attribute: String,
+    op: ScalarOp,
+    operand: u8,
+}
+
+impl ArithmeticScalarData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(vec![self.attribute.clone()], vec![self.attribute.clone()])
+    }
+
+    pub fn try_convert(
+        self,
+        attributes: &[String],
+    ) -> Result<ArithmeticScalar, GenerationStepError> {
+        let id = get_attribute_id(&self.attribute, attributes)?;
+        Ok(ArithmeticScalar::new(id, self.op, self.operand))
+    }
+}
+
+impl ArithmeticScalar {
+    pub fn convert(&self, attributes: &[String]) -> ArithmeticScalarData {
+        let attribute = attributes[self.attribute_id].clone();
+        ArithmeticScalarData::new(attribute, self.op, self.operand)
+    }
+}
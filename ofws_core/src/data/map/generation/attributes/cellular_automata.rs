@@ -0,0 +1,189 @@
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
+use crate::data::map::Map2d;
+use crate::data::math::connectivity::Connectivity;
+use crate::data::math::size2d::Size2d;
+use serde::{Deserialize, Serialize};
+
+const EMPTY: u8 = 0;
+const SOLID: u8 = 255;
+
+/// Smooths an [`Attribute`] with a cellular automaton, e.g. to create caves or islands.
+///
+/// Cells above the threshold are solid. Each iteration counts the solid cells
+/// in the Moore neighborhood of each cell:
+/// An empty cell becomes solid with at least birth solid neighbors,
+/// while a solid cell stays solid with at least survive solid neighbors.
+/// Cells outside the map are solid, if border is true.
+/// The result is written back as 0 for empty & 255 for solid cells.
+#[derive(new, Debug, Clone)]
+pub struct CellularAutomataStep {
+    attribute_id: usize,
+    threshold: u8,
+    iterations: u32,
+    birth: u8,
+    survive: u8,
+    border: bool,
+}
+
+impl CellularAutomataStep {
+    fn calculate_values(&self, size: Size2d, values: &[u8]) -> Vec<u8> {
+        let mut cells: Vec<bool> = values.iter().map(|v| *v > self.threshold).collect();
+
+        for _ in 0..self.iterations {
+            cells = self.iterate(size, &cells);
+        }
+
+        cells
+            .into_iter()
+            .map(|solid| if solid { SOLID } else { EMPTY })
+            .collect()
+    }
+
+    fn iterate(&self, size: Size2d, cells: &[bool]) -> Vec<bool> {
+        let mut result = Vec::with_capacity(cells.len());
+
+        for y in 0..size.height() as i32 {
+            for x in 0..size.width() as i32 {
+                let neighbors = Connectivity::Eight
+                    .offsets()
+                    .iter()
+                    .filter(|(dx, dy)| self.is_solid(size, cells, x + dx, y + dy))
+                    .count();
+
+                let is_solid = if cells[size.to_index_risky(x as u32, y as u32)] {
+                    neighbors >= self.survive as usize
+                } else {
+                    neighbors >= self.birth as usize
+                };
+
+                result.push(is_solid);
+            }
+        }
+
+        result
+    }
+
+    fn is_solid(&self, size: Size2d, cells: &[bool], x: i32, y: i32) -> bool {
+        if x < 0 || y < 0 || x >= size.width() as i32 || y >= size.height() as i32 {
+            return self.border;
+        }
+
+        cells[size.to_index_risky(x as u32, y as u32)]
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::cellular_automata::CellularAutomataStep;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(5, 5));
+    /// let values = vec![
+    ///     200, 200, 200, 200, 200,
+    ///     200, 200, 200,  10, 200,
+    ///     200, 200, 200, 200, 200,
+    ///      10,  10,  10,  10,  10,
+    ///      10, 200,  10,  10,  10,
+    /// ];
+    /// let attribute_id = map.create_attribute_from("cave", values).unwrap();
+    /// let step = CellularAutomataStep::new(attribute_id, 100, 1, 5, 4, true);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// // the isolated empty & solid cells are removed
+    /// assert_eq!(map.get_attribute(attribute_id).get_all(), &vec![
+    ///     255, 255, 255, 255, 255,
+    ///     255, 255, 255, 255, 255,
+    ///     255, 255, 255, 255, 255,
+    ///     255,   0,   0,   0, 255,
+    ///     255,   0,   0,   0, 255,
+    /// ]);
+    /// ```
+    ///
+    /// Without a solid border, the corners erode:
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::cellular_automata::CellularAutomataStep;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(3, 3));
+    /// let attribute_id = map.create_attribute("cave", 200).unwrap();
+    /// let step = CellularAutomataStep::new(attribute_id, 100, 1, 5, 4, false);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(
+    ///     map.get_attribute(attribute_id).get_all(),
+    ///     &vec![0, 255, 0, 255, 255, 255, 0, 255, 0]
+    /// );
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        log_info!(
+            "Run cellular automata for {} iterations on attribute '{}' of map '{}'",
+            self.iterations,
+            map.get_attribute(self.attribute_id).get_name(),
+            map.get_name(),
+        );
+
+        let size = map.size;
+        let attribute = map.get_attribute_mut(self.attribute_id);
+        let values = self.calculate_values(size, attribute.get_all());
+
+        attribute.replace_all(values);
+    }
+}
+
+/// For serializing, deserializing & validating [`CellularAutomataStep`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::cellular_automata::{CellularAutomataStepData, CellularAutomataStep};
+/// let data = CellularAutomataStepData::new("attribute".to_string(), 100, 3, 5, 4, true);
+/// let attributes = vec!["attribute".to_string()];
+/// let step: CellularAutomataStep = data.clone().try_convert(&attributes).unwrap();
+/// let result: CellularAutomataStepData = step.convert(&attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct CellularAutomataStepData {
+    attribute: String,
+    threshold: u8,
+    iterations: u32,
+    birth: u8,
+    survive: u8,
+    border: bool,
+}
+
+impl CellularAutomataStepData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(vec![self.attribute.clone()], vec![self.attribute.clone()])
+    }
+
+    pub fn try_convert(
+        self,
+        attributes: &[String],
+    ) -> Result<CellularAutomataStep, GenerationStepError> {
+        let id = get_attribute_id(&self.attribute, attributes)?;
+        Ok(CellularAutomataStep::new(
+            id,
+            self.threshold,
+            self.iterations,
+            self.birth,
+            self.survive,
+            self.border,
+        ))
+    }
+}
+
+impl CellularAutomataStep {
+    pub fn convert(&self, attributes: &[String]) -> CellularAutomataStepData {
+        let attribute = attributes[self.attribute_id].clone();
+        CellularAutomataStepData::new(
+            attribute,
+            self.threshold,
+            self.iterations,
+            self.birth,
+            self.survive,
+            self.border,
+        )
+    }
+}
@@ -0,0 +1,100 @@
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
+use crate::data::map::Map2d;
+use serde::{Deserialize, Serialize};
+
+/// Clamps each value of an [`Attribute`] to the range [min,max].
+#[derive(Debug, Clone)]
+pub struct ClampStep {
+    attribute_id: usize,
+    min: u8,
+    max: u8,
+}
+
+impl ClampStep {
+    /// Returns the step, if min <= max.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::generation::attributes::clamp::ClampStep;
+    ///# use ofws_core::data::map::generation::step::GenerationStepError;
+    /// assert_eq!(ClampStep::new(0, 200, 100).unwrap_err(), GenerationStepError::MinBiggerThanMax(200, 100));
+    /// ```
+    pub fn new(attribute_id: usize, min: u8, max: u8) -> Result<ClampStep, GenerationStepError> {
+        if min > max {
+            return Err(GenerationStepError::MinBiggerThanMax(min, max));
+        }
+
+        Ok(ClampStep {
+            attribute_id,
+            min,
+            max,
+        })
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::clamp::ClampStep;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(5, 1));
+    /// let attribute_id = map.create_attribute_from("elevation", vec![0, 50, 100, 150, 255]).unwrap();
+    /// let step = ClampStep::new(attribute_id, 50, 120).unwrap();
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(map.get_attribute(attribute_id).get_all(), &vec![50u8, 50, 100, 120, 120]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        log_info!(
+            "Clamp attribute '{}' of map '{}' to [{},{}]",
+            map.get_attribute(self.attribute_id).get_name(),
+            map.get_name(),
+            self.min,
+            self.max,
+        );
+
+        let attribute = map.get_attribute_mut(self.attribute_id);
+        let values = attribute
+            .get_all()
+            .iter()
+            .map(|value| (*value).clamp(self.min, self.max))
+            .collect();
+
+        attribute.replace_all(values);
+    }
+}
+
+/// For serializing, deserializing & validating [`ClampStep`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::clamp::{ClampStepData, ClampStep};
+/// let data = ClampStepData::new("attribute".to_string(), 20, 200);
+/// let attributes = vec!["attribute".to_string()];
+/// let step: ClampStep = data.clone().try_convert(&attributes).unwrap();
+/// let result: ClampStepData = step.convert(&attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct ClampStepData {
+    attribute: String,
+    min: u8,
+    max: u8,
+}
+
+impl ClampStepData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(vec![self.attribute.clone()], vec![self.attribute.clone()])
+    }
+
+    pub fn try_convert(self, attributes: &[String]) -> Result<ClampStep, GenerationStepError> {
+        let id = get_attribute_id(&self.attribute, attributes)?;
+        ClampStep::new(id, self.min, self.max)
+    }
+}
+
+impl ClampStep {
+    pub fn convert(&self, attributes: &[String]) -> ClampStepData {
+        let attribute = attributes[self.attribute_id].clone();
+        ClampStepData::new(attribute, self.min, self.max)
+    }
+}
@@ -0,0 +1,132 @@
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
+use crate::data::map::Map2d;
+use crate::data::math::size2d::Size2d;
+use serde::{Deserialize, Serialize};
+
+/// Softens cliffs along the coast by lowering land next to water.
+///
+/// A cell is water, if its elevation is below the sea level.
+/// Each iteration lowers every land cell next to water by the strength,
+/// but never below the sea level. So the coast stays in place.
+#[derive(new, Debug, Clone)]
+pub struct CoastalErosionStep {
+    elevation_id: usize,
+    sea_level: u8,
+    iterations: u32,
+    strength: u8,
+}
+
+impl CoastalErosionStep {
+    fn is_next_to_water(&self, size: Size2d, values: &[u8], x: u32, y: u32) -> bool {
+        let neighbors = [
+            (x.checked_sub(1), Some(y)),
+            (x.checked_add(1), Some(y)),
+            (Some(x), y.checked_sub(1)),
+            (Some(x), y.checked_add(1)),
+        ];
+
+        neighbors.iter().any(|neighbor| match neighbor {
+            (Some(x), Some(y)) => size
+                .to_index(*x, *y)
+                .map(|index| values[index] < self.sea_level)
+                .unwrap_or(false),
+            _ => false,
+        })
+    }
+
+    fn erode(&self, size: Size2d, values: &[u8]) -> Vec<u8> {
+        let mut eroded = values.to_vec();
+
+        for y in 0..size.height() {
+            for x in 0..size.width() {
+                let index = size.to_index_risky(x, y);
+                let value = values[index];
+
+                if value >= self.sea_level && self.is_next_to_water(size, values, x, y) {
+                    eroded[index] = value.saturating_sub(self.strength).max(self.sea_level);
+                }
+            }
+        }
+
+        eroded
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::coastal_erosion::CoastalErosionStep;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(4, 2));
+    /// let elevation_id = map.create_attribute_from("elevation", vec![10, 200, 200, 200,
+    ///                                                                 10, 250, 200, 200]).unwrap();
+    /// let step = CoastalErosionStep::new(elevation_id, 50, 3, 60);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(map.get_attribute(elevation_id).get_all(), &vec![10u8, 50, 200, 200,
+    ///                                                             10, 70, 200, 200]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        log_info!(
+            "Erode the coast of attribute '{}' of map '{}' {} times",
+            map.get_attribute(self.elevation_id).get_name(),
+            map.get_name(),
+            self.iterations,
+        );
+
+        let size = map.size;
+        let attribute = map.get_attribute_mut(self.elevation_id);
+        let mut values = attribute.get_all().clone();
+
+        for _ in 0..self.iterations {
+            values = self.erode(size, &values);
+        }
+
+        attribute.replace_all(values);
+    }
+}
+
+/// For serializing, deserializing & validating [`CoastalErosionStep`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::coastal_erosion::{CoastalErosionStepData, CoastalErosionStep};
+/// let data = CoastalErosionStepData::new("elevation".to_string(), 76, 5, 10);
+/// let attributes = vec!["elevation".to_string()];
+/// let step: CoastalErosionStep = data.clone().try_convert(&attributes).unwrap();
+/// let result: CoastalErosionStepData = step.convert(&attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct CoastalErosionStepData {
+    elevation: String,
+    sea_level: u8,
+    iterations: u32,
+    strength: u8,
+}
+
+impl CoastalErosionStepData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(vec![self.elevation.clone()], vec![self.elevation.clone()])
+    }
+
+    pub fn try_convert(
+        self,
+        attributes: &[String],
+    ) -> Result<CoastalErosionStep, GenerationStepError> {
+        let id = get_attribute_id(&self.elevation, attributes)?;
+        Ok(CoastalErosionStep::new(
+            id,
+            self.sea_level,
+            self.iterations,
+            self.strength,
+        ))
+    }
+}
+
+impl CoastalErosionStep {
+    pub fn convert(&self, attributes: &[String]) -> CoastalErosionStepData {
+        let elevation = attributes[self.elevation_id].clone();
+        CoastalErosionStepData::new(elevation, self.sea_level, self.iterations, self.strength)
+    }
+}
@@ -0,0 +1,128 @@
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
+use crate::data::map::Map2d;
+use crate::data::math::operation::CombineOp;
+use serde::{Deserialize, Serialize};
+
+/// Sets an [`Attribute`] to the combination of 2 others, e.g. moisture = rainfall - evaporation.
+///
+/// See [`CombineOp`], except [`CombineOp::Set`], which would only copy the 2nd source.
+#[derive(Debug, Clone)]
+pub struct CombineAttributes {
+    source_id0: usize,
+    source_id1: usize,
+    target_id: usize,
+    op: CombineOp,
+}
+
+impl CombineAttributes {
+    /// Returns the step, if the operation isn't [`CombineOp::Set`].
+    ///
+    /// ```
+    ///# use ofws_core::data::map::generation::attributes::combine::CombineAttributes;
+    ///# use ofws_core::data::map::generation::step::GenerationStepError;
+    ///# use ofws_core::data::math::operation::CombineOp;
+    /// assert_eq!(CombineAttributes::new(0, 1, 2, CombineOp::Set).unwrap_err(), GenerationStepError::InvalidCombineOp(CombineOp::Set));
+    /// ```
+    pub fn new(
+        source_id0: usize,
+        source_id1: usize,
+        target_id: usize,
+        op: CombineOp,
+    ) -> Result<CombineAttributes, GenerationStepError> {
+        if op == CombineOp::Set {
+            return Err(GenerationStepError::InvalidCombineOp(op));
+        }
+
+        Ok(CombineAttributes {
+            source_id0,
+            source_id1,
+            target_id,
+            op,
+        })
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::combine::CombineAttributes;
+    ///# use ofws_core::data::math::operation::CombineOp;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(2, 2));
+    /// let rainfall = map.create_attribute_from("rainfall", vec![100, 50, 200, 10]).unwrap();
+    /// let evaporation = map.create_attribute_from("evaporation", vec![30, 50, 20, 40]).unwrap();
+    /// let moisture = map.create_attribute("moisture", 0).unwrap();
+    /// let step = CombineAttributes::new(rainfall, evaporation, moisture, CombineOp::Sub).unwrap();
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(map.get_attribute(moisture).get_all(), &vec![70u8, 0, 180, 0]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        log_info!(
+            "Set attribute '{}' of map '{}' to {:?} of attributes '{}' & '{}'",
+            map.get_attribute(self.target_id).get_name(),
+            map.get_name(),
+            self.op,
+            map.get_attribute(self.source_id0).get_name(),
+            map.get_attribute(self.source_id1).get_name(),
+        );
+
+        let source0 = map.get_attribute(self.source_id0).get_all();
+        let source1 = map.get_attribute(self.source_id1).get_all();
+        let values = source0
+            .iter()
+            .zip(source1.iter())
+            .map(|(value0, value1)| self.op.combine(*value0, *value1))
+            .collect();
+
+        map.get_attribute_mut(self.target_id).replace_all(values);
+    }
+}
+
+/// For serializing, deserializing & validating [`CombineAttributes`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::combine::{CombineAttributesData, CombineAttributes};
+///# use ofws_core::data::math::operation::CombineOp;
+/// let data = CombineAttributesData::new("s0".to_string(), "s1".to_string(), "t".to_string(), CombineOp::Average);
+/// let attributes = vec!["s0".to_string(), "s1".to_string(), "t".to_string()];
+/// let step: CombineAttributes = data.clone().try_convert(&attributes).unwrap();
+/// let result: CombineAttributesData = step.convert(&attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct CombineAttributesData {
+    source0: String,
+    source1: String,
+    target: String,
+    op: CombineOp,
+}
+
+impl CombineAttributesData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(
+            vec![self.source0.clone(), self.source1.clone()],
+            vec![self.target.clone()],
+        )
+    }
+
+    pub fn try_convert(
+        self,
+        attributes: &[String],
+    ) -> Result<CombineAttributes, GenerationStepError> {
+        let source_id0 = get_attribute_id(&self.source0, attributes)?;
+        let source_id1 = get_attribute_id(&self.source1, attributes)?;
+        let target_id = get_attribute_id(&self.target, attributes)?;
+        CombineAttributes::new(source_id0, source_id1, target_id, self.op)
+    }
+}
+
+impl CombineAttributes {
+    pub fn convert(&self, attributes: &[String]) -> CombineAttributesData {
+        let source0 = attributes[self.source_id0].clone();
+        let source1 = attributes[self.source_id1].clone();
+        let target = attributes[self.target_id].clone();
+        CombineAttributesData::new(source0, source1, target, self.op)
+    }
+}
@@ -0,0 +1,93 @@
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
+use crate::data::map::Map2d;
+use serde::{Deserialize, Serialize};
+
+/// Copies all values of an [`Attribute`] into another one of the same size.
+#[derive(new, Debug, Clone)]
+pub struct CopyAttributeStep {
+    source_id: usize,
+    target_id: usize,
+}
+
+impl CopyAttributeStep {
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::copy::CopyAttributeStep;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(3, 1));
+    /// let source_id = map.create_attribute_from("source", vec![10, 20, 30]).unwrap();
+    /// let target_id = map.create_attribute("target", 99).unwrap();
+    /// let step = CopyAttributeStep::new(source_id, target_id);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(map.get_attribute(source_id).get_all(), &vec![10u8, 20, 30]);
+    /// assert_eq!(map.get_attribute(target_id).get_all(), &vec![10u8, 20, 30]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if both attributes have different sizes.
+    pub fn run(&self, map: &mut Map2d) {
+        let source = map.get_attribute(self.source_id);
+        let target = map.get_attribute(self.target_id);
+
+        log_info!(
+            "Copy attribute '{}' to attribute '{}' of map '{}'",
+            source.get_name(),
+            target.get_name(),
+            map.get_name()
+        );
+
+        assert_eq!(
+            source.get_size(),
+            target.get_size(),
+            "Source & target have different sizes!"
+        );
+
+        let values = source.get_all().clone();
+
+        map.get_attribute_mut(self.target_id).replace_all(values);
+    }
+}
+
+/// For serializing, deserializing & validating [`CopyAttributeStep`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::copy::{CopyAttributeStepData, CopyAttributeStep};
+/// let data = CopyAttributeStepData::new("s".to_string(), "t".to_string());
+/// let attributes = vec!["s".to_string(), "t".to_string()];
+/// let step: CopyAttributeStep = data.clone().try_convert(&attributes).unwrap();
+/// let result: CopyAttributeStepData = step.convert(&attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct CopyAttributeStepData {
+    source: String,
+    target: String,
+}
+
+impl CopyAttributeStepData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(vec![self.source.clone()], vec![self.target.clone()])
+    }
+
+    pub fn try_convert(
+        self,
+        attributes: &[String],
+    ) -> Result<CopyAttributeStep, GenerationStepError> {
+        let source_id = get_attribute_id(&self.source, attributes)?;
+        let target_id = get_attribute_id(&self.target, attributes)?;
+        Ok(CopyAttributeStep::new(source_id, target_id))
+    }
+}
+
+impl CopyAttributeStep {
+    pub fn convert(&self, attributes: &[String]) -> CopyAttributeStepData {
+        let source = attributes[self.source_id].clone();
+        let target = attributes[self.target_id].clone();
+        CopyAttributeStepData::new(source, target)
+    }
+}
@@ -22,6 +22,8 @@ impl CreateAttribute {
 
     /// Runs the step.
     ///
+    /// An existing [`Attribute`] with the same name is reset to the default value instead.
+    ///
     /// ```
     ///# use ofws_core::data::map::Map2d;
     ///# use ofws_core::data::map::generation::attributes::create::CreateAttribute;
@@ -36,14 +38,23 @@ impl CreateAttribute {
     /// assert_eq!(attribute.get_name(), "test0");
     /// assert_eq!(attribute.get_size(), &size);
     /// assert_eq!(attribute.get_all(), &vec![9u8, 9, 9, 9, 9, 9]);
+    ///
+    /// *map.get_attribute_mut(0).get_mut(2) = 4;
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(map.get_attribute(0).get_all(), &vec![9u8, 9, 9, 9, 9, 9]);
     /// ```
     pub fn run(&self, map: &mut Map2d) {
-        info!(
+        log_info!(
             "Create attribute '{}' of map '{}'",
             self.name,
             map.get_name()
         );
 
-        map.create_attribute(self.name.clone(), self.default);
+        if let Some(id) = map.get_attribute_id(&self.name) {
+            map.get_attribute_mut(id).fill(self.default);
+        } else {
+            map.create_attribute(self.name.clone(), self.default);
+        }
     }
 }
@@ -0,0 +1,209 @@
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
+use crate::data::map::Map2d;
+use crate::data::math::size2d::Size2d;
+use serde::{Deserialize, Serialize};
+
+const ORTHOGONAL: u32 = 3;
+const DIAGONAL: u32 = 4;
+/// The distance of cells without a reachable match, which is kept by the saturating addition.
+const NO_MATCH: u32 = u32::MAX;
+const FORWARD: [(i32, i32, u32); 4] = [
+    (-1, -1, DIAGONAL),
+    (0, -1, ORTHOGONAL),
+    (1, -1, DIAGONAL),
+    (-1, 0, ORTHOGONAL),
+];
+const BACKWARD: [(i32, i32, u32); 4] = [
+    (1, 0, ORTHOGONAL),
+    (-1, 1, DIAGONAL),
+    (0, 1, ORTHOGONAL),
+    (1, 1, DIAGONAL),
+];
+
+/// Sets an [`Attribute`] to the distance of each cell to the nearest cell of another attribute,
+/// that matches a value.
+///
+/// The distance is approximated with a 2-pass chamfer distance algorithm,
+/// multiplied by scale & clamped to 255.
+/// All cells are 255, if no cell matches.
+#[derive(new, Debug, Clone)]
+pub struct DistanceTransformStep {
+    source_id: usize,
+    target_id: usize,
+    match_value: u8,
+    scale: u32,
+}
+
+impl DistanceTransformStep {
+    fn calculate_values(&self, size: Size2d, source: &[u8]) -> Vec<u8> {
+        let mut distances: Vec<u32> = source
+            .iter()
+            .map(|value| {
+                if *value == self.match_value {
+                    0
+                } else {
+                    NO_MATCH
+                }
+            })
+            .collect();
+
+        for y in 0..size.height() {
+            for x in 0..size.width() {
+                update(size, &mut distances, x, y, &FORWARD);
+            }
+        }
+
+        for y in (0..size.height()).rev() {
+            for x in (0..size.width()).rev() {
+                update(size, &mut distances, x, y, &BACKWARD);
+            }
+        }
+
+        distances
+            .into_iter()
+            .map(|distance| {
+                if distance == NO_MATCH {
+                    return 255;
+                }
+
+                (distance as u64 * self.scale as u64 / ORTHOGONAL as u64).min(255) as u8
+            })
+            .collect()
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::distance_transform::DistanceTransformStep;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(5, 5));
+    /// let source_id = map.create_attribute("water", 0).unwrap();
+    /// *map.get_attribute_mut(source_id).get_mut(12) = 1;
+    /// let target_id = map.create_attribute("distance", 0).unwrap();
+    /// let step = DistanceTransformStep::new(source_id, target_id, 1, 10);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(map.get_attribute(target_id).get_all(), &vec![26u8, 23, 20, 23, 26,
+    ///                                                          23, 13, 10, 13, 23,
+    ///                                                          20, 10,  0, 10, 20,
+    ///                                                          23, 13, 10, 13, 23,
+    ///                                                          26, 23, 20, 23, 26]);
+    /// ```
+    ///
+    /// Without a matching cell all cells are 255:
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::distance_transform::DistanceTransformStep;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(3, 2));
+    /// let source_id = map.create_attribute("water", 0).unwrap();
+    /// let target_id = map.create_attribute("distance", 0).unwrap();
+    /// let step = DistanceTransformStep::new(source_id, target_id, 1, 10);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(map.get_attribute(target_id).get_all(), &vec![255u8; 6]);
+    /// ```
+    ///
+    /// Even if the scale is 0:
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::distance_transform::DistanceTransformStep;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(3, 2));
+    /// let source_id = map.create_attribute("water", 0).unwrap();
+    /// let target_id = map.create_attribute("distance", 0).unwrap();
+    /// let step = DistanceTransformStep::new(source_id, target_id, 1, 0);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(map.get_attribute(target_id).get_all(), &vec![255u8; 6]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        log_info!(
+            "Set attribute '{}' of map '{}' to the distance to {} in attribute '{}'",
+            map.get_attribute(self.target_id).get_name(),
+            map.get_name(),
+            self.match_value,
+            map.get_attribute(self.source_id).get_name(),
+        );
+
+        let source = map.get_attribute(self.source_id);
+        let values = self.calculate_values(map.size, source.get_all());
+        let attribute = map.get_attribute_mut(self.target_id);
+
+        attribute.replace_all(values);
+    }
+}
+
+/// Updates the distance of a cell with the distances of some of its neighbors.
+fn update(size: Size2d, distances: &mut [u32], x: u32, y: u32, neighbors: &[(i32, i32, u32)]) {
+    let index = size.to_index_risky(x, y);
+
+    for (dx, dy, weight) in neighbors {
+        let neighbor_x = x as i32 + dx;
+        let neighbor_y = y as i32 + dy;
+
+        if neighbor_x < 0 || neighbor_y < 0 {
+            continue;
+        }
+
+        if let Some(neighbor) = size.to_index(neighbor_x as u32, neighbor_y as u32) {
+            let distance = distances[neighbor].saturating_add(*weight);
+
+            if distance < distances[index] {
+                distances[index] = distance;
+            }
+        }
+    }
+}
+
+/// For serializing, deserializing & validating [`DistanceTransformStep`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::distance_transform::{DistanceTransformStepData, DistanceTransformStep};
+/// let data = DistanceTransformStepData::new("s".to_string(), "t".to_string(), 1, 10);
+/// let attributes = vec!["s".to_string(), "t".to_string()];
+/// let step: DistanceTransformStep = data.clone().try_convert(&attributes).unwrap();
+/// let result: DistanceTransformStepData = step.convert(&attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct DistanceTransformStepData {
+    source: String,
+    target: String,
+    match_value: u8,
+    scale: u32,
+}
+
+impl DistanceTransformStepData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(vec![self.source.clone()], vec![self.target.clone()])
+    }
+
+    pub fn try_convert(
+        self,
+        attributes: &[String],
+    ) -> Result<DistanceTransformStep, GenerationStepError> {
+        let source_id = get_attribute_id(&self.source, attributes)?;
+        let target_id = get_attribute_id(&self.target, attributes)?;
+        Ok(DistanceTransformStep::new(
+            source_id,
+            target_id,
+            self.match_value,
+            self.scale,
+        ))
+    }
+}
+
+impl DistanceTransformStep {
+    pub fn convert(&self, attributes: &[String]) -> DistanceTransformStepData {
+        let source = attributes[self.source_id].clone();
+        let target = attributes[self.target_id].clone();
+        DistanceTransformStepData::new(source, target, self.match_value, self.scale)
+    }
+}
@@ -1,5 +1,5 @@
 use crate::data::map::attribute::Attribute;
-use crate::data::map::generation::step::{get_attribute_id, GenerationStepError};
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
 use crate::data::map::Map2d;
 use crate::data::math::generator::generator1d::{Generator1d, Generator1dData};
 use serde::{Deserialize, Serialize};
@@ -16,29 +16,26 @@ impl Distortion1d {
     fn distort_row(&self, y: u32, shift: u8, attribute: &Attribute, values: &mut Vec<u8>) {
         let start = attribute.get_size().to_index_risky(0, y);
         let start_value = attribute.get(start);
+        let width = attribute.get_size().width();
+        let shift = (shift as u32).min(width);
 
         for _x in 0..shift {
             values.push(start_value);
         }
 
-        let width = attribute.get_size().width().saturating_sub(shift as u32) as usize;
-
-        for x in 0..width {
+        for x in 0..(width - shift) as usize {
             values.push(attribute.get(start + x));
         }
     }
 
-    fn distort_map_along_x(&self, map: &Map2d) -> Vec<u8> {
-        let length = map.size.get_area();
+    fn distort_map_along_x(&self, map: &Map2d, values: &mut Vec<u8>) {
         let attribute = map.get_attribute(self.attribute_id);
-        let mut values = Vec::with_capacity(length);
+        values.reserve(map.size.get_area());
 
         for y in 0..map.size.height() {
             let shift = self.generator.generate(y);
-            self.distort_row(y, shift, attribute, &mut values);
+            self.distort_row(y, shift, attribute, values);
         }
-
-        values
     }
 
     /// Shifts each each row along the x-axis based on a [`Generator1d`].
@@ -60,16 +57,15 @@ impl Distortion1d {
     /// assert_eq!(attribute.get_all(), &vec![1u8, 2, 3, 4, 4, 5, 7, 7, 7]);
     /// ```
     pub fn distort_along_x(&self, map: &mut Map2d) {
-        info!(
+        log_info!(
             "Distort attribute '{}' of map '{}' along the x-axis.",
             map.get_attribute(self.attribute_id).get_name(),
             map.get_name()
         );
 
-        let values = self.distort_map_along_x(map);
-        let attribute = map.get_attribute_mut(self.attribute_id);
-
-        attribute.replace_all(values);
+        let mut values = map.take_scratch();
+        self.distort_map_along_x(map, &mut values);
+        map.replace_all_from_scratch(self.attribute_id, values);
     }
 
     fn distort_column(&self, x: u32, shift: u8, attribute: &Attribute, values: &mut Vec<u8>) {
@@ -77,13 +73,15 @@ impl Distortion1d {
         let start_value = attribute.get(start);
         let mut index = start;
         let width = attribute.get_size().width() as usize;
+        let height = attribute.get_size().height();
+        let shift = (shift as u32).min(height);
 
         for _y in 0..shift {
             values[index] = start_value;
             index += width;
         }
 
-        let remaining_height = attribute.get_size().height().saturating_sub(shift as u32);
+        let remaining_height = height - shift;
         let mut distorted_index = start;
 
         for _y in 0..remaining_height {
@@ -93,17 +91,14 @@ impl Distortion1d {
         }
     }
 
-    fn distort_map_along_y(&self, map: &Map2d) -> Vec<u8> {
-        let length = map.size.get_area();
+    fn distort_map_along_y(&self, map: &Map2d, values: &mut Vec<u8>) {
         let attribute = map.get_attribute(self.attribute_id);
-        let mut values = vec![0; length];
+        values.resize(map.size.get_area(), 0);
 
         for x in 0..map.size.width() {
             let shift = self.generator.generate(x);
-            self.distort_column(x, shift, attribute, &mut values);
+            self.distort_column(x, shift, attribute, values);
         }
-
-        values
     }
 
     /// Shifts each each column along the y-axis based on a [`Generator1d`].
@@ -125,16 +120,15 @@ impl Distortion1d {
     /// assert_eq!(attribute.get_all(), &vec![1u8, 2, 3, 4, 2, 3, 7, 5, 3]);
     /// ```
     pub fn distort_along_y(&self, map: &mut Map2d) {
-        info!(
+        log_info!(
             "Distort attribute '{}' of map '{}' along the y-axis.",
             map.get_attribute(self.attribute_id).get_name(),
             map.get_name()
         );
 
-        let values = self.distort_map_along_y(map);
-        let attribute = map.get_attribute_mut(self.attribute_id);
-
-        attribute.replace_all(values);
+        let mut values = map.take_scratch();
+        self.distort_map_along_y(map, &mut values);
+        map.replace_all_from_scratch(self.attribute_id, values);
     }
 }
 
@@ -151,13 +145,17 @@ impl Distortion1d {
 /// let result: Distortion1dData = step.convert(&attributes);
 /// assert_eq!(data, result)
 ///```
-#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[derive(new, Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct Distortion1dData {
     attribute: String,
     generator: Generator1dData,
 }
 
 impl Distortion1dData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(vec![self.attribute.clone()], vec![self.attribute.clone()])
+    }
+
     pub fn try_convert(self, attributes: &[String]) -> Result<Distortion1d, GenerationStepError> {
         let id = get_attribute_id(&self.attribute, attributes)?;
         let generator: Generator1d = self.generator.try_into()?;
@@ -171,3 +169,68 @@ impl Distortion1d {
         Distortion1dData::new(attribute, (&self.generator).into())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::math::generator::generator1d::Generator1d::InputAsOutput;
+    use crate::data::math::size2d::Size2d;
+
+    #[test]
+    fn test_chained_distortions_reuse_scratch() {
+        let mut map = Map2d::new(Size2d::new(3, 3));
+        let values = vec![1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let attribute_id = map.create_attribute_from("test", values).unwrap();
+        let step = Distortion1d::new(attribute_id, InputAsOutput);
+
+        step.distort_along_x(&mut map);
+        assert_eq!(
+            map.get_attribute(0).get_all(),
+            &vec![1, 2, 3, 4, 4, 5, 7, 7, 7]
+        );
+
+        step.distort_along_x(&mut map);
+        assert_eq!(
+            map.get_attribute(0).get_all(),
+            &vec![1, 2, 3, 4, 4, 4, 7, 7, 7]
+        );
+
+        step.distort_along_y(&mut map);
+        assert_eq!(
+            map.get_attribute(0).get_all(),
+            &vec![1, 2, 3, 4, 2, 3, 7, 4, 3]
+        );
+
+        assert_eq!(map.scratch.len(), 9);
+    }
+
+    #[test]
+    fn test_shift_exceeds_width() {
+        let mut map = Map2d::new(Size2d::new(3, 6));
+        let attribute_id = map
+            .create_attribute_from("test", (1..=18).collect())
+            .unwrap();
+        let step = Distortion1d::new(attribute_id, InputAsOutput);
+
+        step.distort_along_x(&mut map);
+
+        let values = map.get_attribute(attribute_id).get_all();
+        assert_eq!(values.len(), map.size.get_area());
+        assert_eq!(&values[9..], &[10, 10, 10, 13, 13, 13, 16, 16, 16]);
+    }
+
+    #[test]
+    fn test_shift_exceeds_height() {
+        let mut map = Map2d::new(Size2d::new(6, 3));
+        let attribute_id = map
+            .create_attribute_from("test", (1..=18).collect())
+            .unwrap();
+        let step = Distortion1d::new(attribute_id, InputAsOutput);
+
+        step.distort_along_y(&mut map);
+
+        let values = map.get_attribute(attribute_id).get_all();
+        assert_eq!(values.len(), map.size.get_area());
+        assert_eq!(&values[12..], &[13, 8, 3, 4, 5, 6]);
+    }
+}
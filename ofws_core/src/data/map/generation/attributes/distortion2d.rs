@@ -1,4 +1,4 @@
-use crate::data::map::generation::step::{get_attribute_id, GenerationStepError};
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
 use crate::data::map::Map2d;
 use crate::data::math::generator::generator2d::{Generator2d, Generator2dData};
 use serde::{Deserialize, Serialize};
@@ -13,10 +13,9 @@ pub struct Distortion2d {
 }
 
 impl Distortion2d {
-    fn distort_map(&self, map: &Map2d) -> Vec<u8> {
-        let length = map.size.get_area();
+    fn distort_map(&self, map: &Map2d, values: &mut Vec<u8>) {
         let attribute = map.get_attribute(self.attribute_id);
-        let mut values = Vec::with_capacity(length);
+        values.reserve(map.size.get_area());
 
         for y in 0..map.size.height() {
             for x in 0..map.size.width() {
@@ -28,22 +27,19 @@ impl Distortion2d {
                 values.push(attribute.get(index));
             }
         }
-
-        values
     }
 
     // Runs the step.
     pub fn run(&self, map: &mut Map2d) {
-        info!(
+        log_info!(
             "Distort attribute '{}' of map '{}' in 2 dimensions.",
             map.get_attribute(self.attribute_id).get_name(),
             map.get_name()
         );
 
-        let values = self.distort_map(map);
-        let attribute = map.get_attribute_mut(self.attribute_id);
-
-        attribute.replace_all(values);
+        let mut values = map.take_scratch();
+        self.distort_map(map, &mut values);
+        map.replace_all_from_scratch(self.attribute_id, values);
     }
 }
 
@@ -62,7 +58,7 @@ impl Distortion2d {
 /// let result: Distortion2dData = step.convert(&attributes);
 /// assert_eq!(data, result)
 ///```
-#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[derive(new, Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct Distortion2dData {
     attribute: String,
     generator_x: Generator2dData,
@@ -70,6 +66,10 @@ pub struct Distortion2dData {
 }
 
 impl Distortion2dData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(vec![self.attribute.clone()], vec![self.attribute.clone()])
+    }
+
     pub fn try_convert(self, attributes: &[String]) -> Result<Distortion2d, GenerationStepError> {
         let id = get_attribute_id(&self.attribute, attributes)?;
         let generator_x: Generator2d = self.generator_x.try_into()?;
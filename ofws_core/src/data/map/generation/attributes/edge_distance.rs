@@ -0,0 +1,110 @@
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
+use crate::data::map::Map2d;
+use crate::data::math::size2d::Size2d;
+use serde::{Deserialize, Serialize};
+
+/// Sets an [`Attribute`] to the distance of each cell to the nearest edge of the map.
+///
+/// The distance is scaled, so that max_distance maps to 255.
+/// Cells farther away are also 255.
+#[derive(new, Debug, Clone)]
+pub struct EdgeDistanceStep {
+    target_id: usize,
+    max_distance: u32,
+}
+
+impl EdgeDistanceStep {
+    fn calculate_value(&self, size: Size2d, x: u32, y: u32) -> u8 {
+        let distance = x
+            .min(y)
+            .min(size.width() - 1 - x)
+            .min(size.height() - 1 - y);
+
+        if distance >= self.max_distance {
+            return 255;
+        }
+
+        (distance * 255 / self.max_distance) as u8
+    }
+
+    fn calculate_values(&self, size: Size2d) -> Vec<u8> {
+        let mut values = Vec::with_capacity(size.get_area());
+
+        for y in 0..size.height() {
+            for x in 0..size.width() {
+                values.push(self.calculate_value(size, x, y));
+            }
+        }
+
+        values
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::edge_distance::EdgeDistanceStep;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(5, 5));
+    /// let attribute_id = map.create_attribute("island", 9).unwrap();
+    /// let step = EdgeDistanceStep::new(attribute_id, 2);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// let attribute = map.get_attribute(attribute_id);
+    /// assert_eq!(attribute.get_all(), &vec![0u8,   0,   0,   0, 0,
+    ///                                        0, 127, 127, 127, 0,
+    ///                                        0, 127, 255, 127, 0,
+    ///                                        0, 127, 127, 127, 0,
+    ///                                        0,   0,   0,   0, 0]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        log_info!(
+            "Set attribute '{}' of map '{}' to the distance to the edge",
+            map.get_attribute(self.target_id).get_name(),
+            map.get_name()
+        );
+
+        let values = self.calculate_values(map.size);
+        let attribute = map.get_attribute_mut(self.target_id);
+
+        attribute.replace_all(values);
+    }
+}
+
+/// For serializing, deserializing & validating [`EdgeDistanceStep`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::edge_distance::{EdgeDistanceStepData, EdgeDistanceStep};
+/// let data = EdgeDistanceStepData::new("attribute".to_string(), 10);
+/// let attributes = vec!["attribute".to_string()];
+/// let step: EdgeDistanceStep = data.clone().try_convert(&attributes).unwrap();
+/// let result: EdgeDistanceStepData = step.convert(&attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct EdgeDistanceStepData {
+    target: String,
+    max_distance: u32,
+}
+
+impl EdgeDistanceStepData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(vec![], vec![self.target.clone()])
+    }
+
+    pub fn try_convert(
+        self,
+        attributes: &[String],
+    ) -> Result<EdgeDistanceStep, GenerationStepError> {
+        let id = get_attribute_id(&self.target, attributes)?;
+        Ok(EdgeDistanceStep::new(id, self.max_distance))
+    }
+}
+
+impl EdgeDistanceStep {
+    pub fn convert(&self, attributes: &[String]) -> EdgeDistanceStepData {
+        let target = attributes[self.target_id].clone();
+        EdgeDistanceStepData::new(target, self.max_distance)
+    }
+}
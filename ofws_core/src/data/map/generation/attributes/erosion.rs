@@ -0,0 +1,266 @@
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
+use crate::data::map::Map2d;
+use crate::data::math::random::Random;
+use crate::data::math::size2d::Size2d;
+use serde::{Deserialize, Serialize};
+
+const MAX_STEPS: u32 = 64;
+const MIN_SLOPE: f32 = 0.01;
+const EVAPORATION: f32 = 0.02;
+const GRAVITY: f32 = 4.0;
+
+/// Simulates hydraulic erosion of an elevation [`Attribute`] with water droplets.
+///
+/// Each droplet starts at a random position & flows downhill.
+/// The simulation uses heights normalized to [0, 1].
+/// It erodes material while it can carry more sediment & deposits it otherwise,
+/// e.g. when it slows down or flows uphill.
+///
+/// * inertia defines how much a droplet keeps its direction. 0 follows the slope & 1 ignores it.
+/// * capacity defines how much sediment a droplet can carry.
+/// * erosion & deposition are the fractions of the free & surplus capacity, that are eroded & deposited.
+#[derive(new, Debug, Clone)]
+pub struct ErosionStep {
+    attribute_id: usize,
+    droplets: u32,
+    seed: u32,
+    inertia: f32,
+    capacity: f32,
+    erosion: f32,
+    deposition: f32,
+}
+
+impl ErosionStep {
+    fn calculate_values(&self, size: Size2d, values: &[u8]) -> Vec<u8> {
+        let mut heights: Vec<f32> = values.iter().map(|v| *v as f32 / 255.0).collect();
+
+        if size.width() < 2 || size.height() < 2 {
+            return values.to_vec();
+        }
+
+        let mut random = Random::new(self.seed);
+
+        for _ in 0..self.droplets {
+            let x = random_position(&mut random, size.width());
+            let y = random_position(&mut random, size.height());
+            self.simulate_droplet(size, &mut heights, x, y);
+        }
+
+        heights
+            .iter()
+            .map(|height| (height * 255.0).round().clamp(0.0, 255.0) as u8)
+            .collect()
+    }
+
+    fn simulate_droplet(&self, size: Size2d, heights: &mut [f32], mut x: f32, mut y: f32) {
+        let max_x = (size.width() - 1) as f32;
+        let max_y = (size.height() - 1) as f32;
+        let (mut dir_x, mut dir_y) = (0.0, 0.0);
+        let mut speed = 1.0;
+        let mut water = 1.0;
+        let mut sediment = 0.0;
+
+        for _ in 0..MAX_STEPS {
+            let (height, gradient_x, gradient_y) =
+                calculate_height_and_gradient(size, heights, x, y);
+
+            dir_x = dir_x * self.inertia - gradient_x * (1.0 - self.inertia);
+            dir_y = dir_y * self.inertia - gradient_y * (1.0 - self.inertia);
+
+            let length = (dir_x * dir_x + dir_y * dir_y).sqrt();
+
+            if length <= f32::EPSILON {
+                break;
+            }
+
+            dir_x /= length;
+            dir_y /= length;
+
+            let new_x = x + dir_x;
+            let new_y = y + dir_y;
+
+            if new_x < 0.0 || new_y < 0.0 || new_x >= max_x || new_y >= max_y {
+                break;
+            }
+
+            let (new_height, _, _) = calculate_height_and_gradient(size, heights, new_x, new_y);
+            let delta = new_height - height;
+            let capacity = (-delta).max(MIN_SLOPE) * speed * water * self.capacity;
+
+            if delta > 0.0 || sediment > capacity {
+                let deposit = if delta > 0.0 {
+                    delta.min(sediment)
+                } else {
+                    (sediment - capacity) * self.deposition
+                };
+                sediment -= deposit;
+                distribute(size, heights, x, y, deposit);
+            } else {
+                let eroded = ((capacity - sediment) * self.erosion).min(-delta);
+                sediment += eroded;
+                distribute(size, heights, x, y, -eroded);
+            }
+
+            speed = (speed * speed - delta * GRAVITY).max(0.0).sqrt();
+            water *= 1.0 - EVAPORATION;
+            x = new_x;
+            y = new_y;
+        }
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::erosion::ErosionStep;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let size = Size2d::new(5, 5);
+    /// let mut map = Map2d::new(size);
+    /// let attribute_id = map.create_attribute("elevation", 100).unwrap();
+    /// let step = ErosionStep::new(attribute_id, 10, 42, 0.1, 1.0, 0.1, 0.3);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// // a flat map doesn't change
+    /// assert_eq!(map.get_attribute(attribute_id).get_all(), &vec![100u8; 25]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        log_info!(
+            "Erode attribute '{}' of map '{}' with {} droplets",
+            map.get_attribute(self.attribute_id).get_name(),
+            map.get_name(),
+            self.droplets,
+        );
+
+        let size = map.size;
+        let attribute = map.get_attribute_mut(self.attribute_id);
+        let values = self.calculate_values(size, attribute.get_all());
+
+        attribute.replace_all(values);
+    }
+}
+
+fn random_position(random: &mut Random, length: u32) -> f32 {
+    let fraction = random.next_u32() as f32 / u32::MAX as f32;
+    fraction * (length - 1) as f32 * 0.999
+}
+
+/// Returns the bilinear interpolated height & its gradient at a position.
+fn calculate_height_and_gradient(size: Size2d, heights: &[f32], x: f32, y: f32) -> (f32, f32, f32) {
+    let (index, u, v) = split(size, x, y);
+    let width = size.width() as usize;
+    let top_left = heights[index];
+    let top_right = heights[index + 1];
+    let bottom_left = heights[index + width];
+    let bottom_right = heights[index + width + 1];
+
+    let gradient_x = (top_right - top_left) * (1.0 - v) + (bottom_right - bottom_left) * v;
+    let gradient_y = (bottom_left - top_left) * (1.0 - u) + (bottom_right - top_right) * u;
+    let height = top_left * (1.0 - u) * (1.0 - v)
+        + top_right * u * (1.0 - v)
+        + bottom_left * (1.0 - u) * v
+        + bottom_right * u * v;
+
+    (height, gradient_x, gradient_y)
+}
+
+/// Adds an amount to the 4 cells around a position, weighted by their distance.
+fn distribute(size: Size2d, heights: &mut [f32], x: f32, y: f32, amount: f32) {
+    let (index, u, v) = split(size, x, y);
+    let width = size.width() as usize;
+
+    heights[index] += amount * (1.0 - u) * (1.0 - v);
+    heights[index + 1] += amount * u * (1.0 - v);
+    heights[index + width] += amount * (1.0 - u) * v;
+    heights[index + width + 1] += amount * u * v;
+}
+
+/// Splits a position into the index of the top left cell & the offsets inside the cell.
+fn split(size: Size2d, x: f32, y: f32) -> (usize, f32, f32) {
+    let cell_x = x.floor();
+    let cell_y = y.floor();
+    let index = size.to_index_risky(cell_x as u32, cell_y as u32);
+
+    (index, x - cell_x, y - cell_y)
+}
+
+/// For serializing, deserializing & validating [`ErosionStep`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::erosion::{ErosionStepData, ErosionStep};
+/// let data = ErosionStepData::new("attribute".to_string(), 1000, 42, 0.1, 1.0, 0.1, 0.3);
+/// let attributes = vec!["attribute".to_string()];
+/// let step: ErosionStep = data.clone().try_convert(&attributes).unwrap();
+/// let result: ErosionStepData = step.convert(&attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(new, Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct ErosionStepData {
+    attribute: String,
+    droplets: u32,
+    seed: u32,
+    inertia: f32,
+    capacity: f32,
+    erosion: f32,
+    deposition: f32,
+}
+
+impl ErosionStepData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(vec![self.attribute.clone()], vec![self.attribute.clone()])
+    }
+
+    pub fn try_convert(self, attributes: &[String]) -> Result<ErosionStep, GenerationStepError> {
+        let id = get_attribute_id(&self.attribute, attributes)?;
+        Ok(ErosionStep::new(
+            id,
+            self.droplets,
+            self.seed,
+            self.inertia,
+            self.capacity,
+            self.erosion,
+            self.deposition,
+        ))
+    }
+}
+
+impl ErosionStep {
+    pub fn convert(&self, attributes: &[String]) -> ErosionStepData {
+        let attribute = attributes[self.attribute_id].clone();
+        ErosionStepData::new(
+            attribute,
+            self.droplets,
+            self.seed,
+            self.inertia,
+            self.capacity,
+            self.erosion,
+            self.deposition,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_material_moves_downhill() {
+        let size = Size2d::new(9, 3);
+        let valley: Vec<u8> = (0..3)
+            .flat_map(|_| (0..9i32).map(|x| ((x - 4).abs() * 40) as u8))
+            .collect();
+        let step = ErosionStep::new(0, 20, 42, 0.1, 1.0, 0.1, 0.3);
+
+        let values = step.calculate_values(size, &valley);
+
+        let sum = |values: &[u8], x: u32| -> u32 {
+            (0..3)
+                .map(|y| values[size.to_index_risky(x, y)] as u32)
+                .sum()
+        };
+
+        assert!(sum(&values, 1) + sum(&values, 7) < sum(&valley, 1) + sum(&valley, 7));
+        assert!(sum(&values, 4) > sum(&valley, 4));
+        assert_eq!(values, step.calculate_values(size, &valley));
+    }
+}
@@ -0,0 +1,193 @@
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
+use crate::data::map::Map2d;
+use crate::data::math::random::Random;
+use crate::data::math::size2d::Size2d;
+use serde::{Deserialize, Serialize};
+
+type Segment = ((f32, f32), (f32, f32));
+
+/// Raises ridges along random fault lines, e.g. for mountain ranges along plate boundaries.
+///
+/// Each fault is a line segment between 2 random points of the map.
+/// Cells on a fault are raised by the ridge height,
+/// which decreases linearly to 0 at the falloff distance.
+/// Overlapping faults use the highest ridge instead of adding them up.
+#[derive(new, Debug, Clone)]
+pub struct FaultLinesStep {
+    elevation_id: usize,
+    seed: u32,
+    num_faults: u32,
+    ridge_height: u8,
+    falloff: u32,
+}
+
+impl FaultLinesStep {
+    fn calculate_segments(&self, size: Size2d) -> Vec<Segment> {
+        let mut random = Random::new(self.seed);
+        let mut random_point = || {
+            (
+                random.next_range(size.width()) as f32,
+                random.next_range(size.height()) as f32,
+            )
+        };
+
+        (0..self.num_faults)
+            .map(|_| (random_point(), random_point()))
+            .collect()
+    }
+
+    fn calculate_ridge(&self, distance: f32) -> u8 {
+        if distance >= self.falloff.max(1) as f32 {
+            return 0;
+        }
+
+        let factor = 1.0 - distance / self.falloff.max(1) as f32;
+        (self.ridge_height as f32 * factor).round() as u8
+    }
+
+    fn raise(&self, size: Size2d, values: &[u8]) -> Vec<u8> {
+        let segments = self.calculate_segments(size);
+
+        values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| {
+                let [x, y] = size.to_x_and_y(index);
+                let point = (x as f32, y as f32);
+                let ridge = segments
+                    .iter()
+                    .map(|segment| self.calculate_ridge(distance_to_segment(point, *segment)))
+                    .max()
+                    .unwrap_or(0);
+                value.saturating_add(ridge)
+            })
+            .collect()
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::fault_lines::FaultLinesStep;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let step = FaultLinesStep::new(0, 42, 3, 100, 4);
+    /// let mut map0 = Map2d::new(Size2d::new(20, 20));
+    /// let mut map1 = Map2d::new(Size2d::new(20, 20));
+    /// map0.create_attribute("elevation", 10);
+    /// map1.create_attribute("elevation", 10);
+    ///
+    /// step.run(&mut map0);
+    /// step.run(&mut map1);
+    ///
+    /// let values = map0.get_attribute(0).get_all();
+    /// assert_eq!(values, map1.get_attribute(0).get_all());
+    /// assert_eq!(values.iter().max(), Some(&110));
+    /// assert!(values.iter().all(|value| *value >= 10 && *value <= 110));
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        log_info!(
+            "Raise {} fault lines in attribute '{}' of map '{}'",
+            self.num_faults,
+            map.get_attribute(self.elevation_id).get_name(),
+            map.get_name(),
+        );
+
+        let size = map.size;
+        let attribute = map.get_attribute_mut(self.elevation_id);
+        let values = self.raise(size, attribute.get_all());
+
+        attribute.replace_all(values);
+    }
+}
+
+/// Calculates the distance between a point & a line segment.
+fn distance_to_segment(point: (f32, f32), segment: Segment) -> f32 {
+    let ((x0, y0), (x1, y1)) = segment;
+    let dx = x1 - x0;
+    let dy = y1 - y0;
+    let length_squared = dx * dx + dy * dy;
+    let t = if length_squared > 0.0 {
+        (((point.0 - x0) * dx + (point.1 - y0) * dy) / length_squared).clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let closest_x = x0 + t * dx;
+    let closest_y = y0 + t * dy;
+
+    ((point.0 - closest_x).powi(2) + (point.1 - closest_y).powi(2)).sqrt()
+}
+
+/// For serializing, deserializing & validating [`FaultLinesStep`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::fault_lines::{FaultLinesStepData, FaultLinesStep};
+/// let data = FaultLinesStepData::new("elevation".to_string(), 42, 5, 100, 8);
+/// let attributes = vec!["elevation".to_string()];
+/// let step: FaultLinesStep = data.clone().try_convert(&attributes).unwrap();
+/// let result: FaultLinesStepData = step.convert(&attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct FaultLinesStepData {
+    elevation: String,
+    seed: u32,
+    num_faults: u32,
+    ridge_height: u8,
+    falloff: u32,
+}
+
+impl FaultLinesStepData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(vec![self.elevation.clone()], vec![self.elevation.clone()])
+    }
+
+    pub fn try_convert(self, attributes: &[String]) -> Result<FaultLinesStep, GenerationStepError> {
+        let id = get_attribute_id(&self.elevation, attributes)?;
+        Ok(FaultLinesStep::new(
+            id,
+            self.seed,
+            self.num_faults,
+            self.ridge_height,
+            self.falloff,
+        ))
+    }
+}
+
+impl FaultLinesStep {
+    pub fn convert(&self, attributes: &[String]) -> FaultLinesStepData {
+        let elevation = attributes[self.elevation_id].clone();
+        FaultLinesStepData::new(
+            elevation,
+            self.seed,
+            self.num_faults,
+            self.ridge_height,
+            self.falloff,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cells_on_fault_are_raised_by_ridge_height() {
+        let size = Size2d::new(20, 10);
+        let step = FaultLinesStep::new(0, 7, 2, 50, 3);
+        let values = step.raise(size, &vec![100; size.get_area()]);
+
+        for ((x0, y0), (x1, y1)) in step.calculate_segments(size) {
+            assert_eq!(values[size.to_index_risky(x0 as u32, y0 as u32)], 150);
+            assert_eq!(values[size.to_index_risky(x1 as u32, y1 as u32)], 150);
+        }
+    }
+
+    #[test]
+    fn test_distance_to_segment() {
+        let segment = ((0.0, 0.0), (10.0, 0.0));
+
+        assert_eq!(distance_to_segment((5.0, 0.0), segment), 0.0);
+        assert_eq!(distance_to_segment((5.0, 3.0), segment), 3.0);
+        assert_eq!(distance_to_segment((13.0, 4.0), segment), 5.0);
+    }
+}
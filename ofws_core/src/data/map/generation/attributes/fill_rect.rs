@@ -0,0 +1,92 @@
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
+use crate::data::map::Map2d;
+use serde::{Deserialize, Serialize};
+
+/// Sets all values of an [`Attribute`] inside a rectangle to the same value.
+///
+/// See [`Attribute::fill_rect`].
+#[derive(new, Debug, Clone)]
+pub struct FillRectStep {
+    attribute_id: usize,
+    min_x: u32,
+    min_y: u32,
+    max_x: u32,
+    max_y: u32,
+    value: u8,
+}
+
+impl FillRectStep {
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::fill_rect::FillRectStep;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(3, 3));
+    /// let attribute_id = map.create_attribute("elevation", 100).unwrap();
+    /// let step = FillRectStep::new(attribute_id, 0, 1, 1, 5, 0);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(map.get_attribute(attribute_id).get_all(), &vec![100u8, 100, 100,
+    ///                                                               0,   0, 100,
+    ///                                                               0,   0, 100]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        log_info!(
+            "Fill rectangle ({},{})-({},{}) of attribute '{}' of map '{}' with {}",
+            self.min_x,
+            self.min_y,
+            self.max_x,
+            self.max_y,
+            map.get_attribute(self.attribute_id).get_name(),
+            map.get_name(),
+            self.value,
+        );
+
+        map.get_attribute_mut(self.attribute_id)
+            .fill_rect(self.min_x, self.min_y, self.max_x, self.max_y, self.value);
+    }
+}
+
+/// For serializing, deserializing & validating [`FillRectStep`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::fill_rect::{FillRectStepData, FillRectStep};
+/// let data = FillRectStepData::new("attribute".to_string(), 1, 2, 3, 4, 5);
+/// let attributes = vec!["attribute".to_string()];
+/// let step: FillRectStep = data.clone().try_convert(&attributes).unwrap();
+/// let result: FillRectStepData = step.convert(&attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct FillRectStepData {
+    attribute: String,
+    min_x: u32,
+    min_y: u32,
+    max_x: u32,
+    max_y: u32,
+    value: u8,
+}
+
+impl FillRectStepData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(vec![self.attribute.clone()], vec![self.attribute.clone()])
+    }
+
+    pub fn try_convert(self, attributes: &[String]) -> Result<FillRectStep, GenerationStepError> {
+        let id = get_attribute_id(&self.attribute, attributes)?;
+        Ok(FillRectStep::new(
+            id, self.min_x, self.min_y, self.max_x, self.max_y, self.value,
+        ))
+    }
+}
+
+impl FillRectStep {
+    pub fn convert(&self, attributes: &[String]) -> FillRectStepData {
+        let attribute = attributes[self.attribute_id].clone();
+        FillRectStepData::new(
+            attribute, self.min_x, self.min_y, self.max_x, self.max_y, self.value,
+        )
+    }
+}
@@ -0,0 +1,126 @@
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
+use crate::data::map::Map2d;
+use crate::data::math::connectivity::Connectivity;
+use crate::data::math::size2d::Size2d;
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+/// Fills the sinks of an elevation [`Attribute`], so that water can flow to the edge of the map.
+///
+/// Each depression is raised to the level of its lowest outlet with the priority-flood algorithm.
+#[derive(new, Debug, Clone)]
+pub struct FillSinksStep {
+    elevation_id: usize,
+}
+
+impl FillSinksStep {
+    fn fill(&self, size: Size2d, values: &[u8]) -> Vec<u8> {
+        let mut filled = values.to_vec();
+        let mut is_visited = vec![false; values.len()];
+        let mut queue = BinaryHeap::new();
+
+        for (index, value) in values.iter().enumerate() {
+            let [x, y] = size.to_x_and_y(index);
+
+            if x == 0 || y == 0 || x + 1 == size.width() || y + 1 == size.height() {
+                is_visited[index] = true;
+                queue.push(Reverse((*value, index)));
+            }
+        }
+
+        while let Some(Reverse((value, index))) = queue.pop() {
+            for neighbor in Connectivity::Four.neighbors(size, index) {
+                if is_visited[neighbor] {
+                    continue;
+                }
+
+                is_visited[neighbor] = true;
+                filled[neighbor] = filled[neighbor].max(value);
+                queue.push(Reverse((filled[neighbor], neighbor)));
+            }
+        }
+
+        filled
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::fill_sinks::FillSinksStep;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(4, 3));
+    /// let elevation_id = map.create_attribute_from("elevation", vec![50, 50, 50, 50,
+    ///                                                                 50, 10, 60, 50,
+    ///                                                                 50, 40, 50, 50]).unwrap();
+    /// let step = FillSinksStep::new(elevation_id);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(map.get_attribute(elevation_id).get_all(), &vec![50u8, 50, 50, 50,
+    ///                                                             50, 40, 60, 50,
+    ///                                                             50, 40, 50, 50]);
+    /// ```
+    ///
+    /// Flat areas are unchanged:
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::fill_sinks::FillSinksStep;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(5, 5));
+    /// let elevation_id = map.create_attribute("elevation", 100).unwrap();
+    /// let step = FillSinksStep::new(elevation_id);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(map.get_attribute(elevation_id).get_all(), &vec![100u8; 25]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        log_info!(
+            "Fill the sinks of attribute '{}' of map '{}'",
+            map.get_attribute(self.elevation_id).get_name(),
+            map.get_name(),
+        );
+
+        let size = map.size;
+        let attribute = map.get_attribute_mut(self.elevation_id);
+        let values = self.fill(size, attribute.get_all());
+
+        attribute.replace_all(values);
+    }
+}
+
+/// For serializing, deserializing & validating [`FillSinksStep`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::fill_sinks::{FillSinksStepData, FillSinksStep};
+/// let data = FillSinksStepData::new("elevation".to_string());
+/// let attributes = vec!["elevation".to_string()];
+/// let step: FillSinksStep = data.clone().try_convert(&attributes).unwrap();
+/// let result: FillSinksStepData = step.convert(&attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct FillSinksStepData {
+    elevation: String,
+}
+
+impl FillSinksStepData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(vec![self.elevation.clone()], vec![self.elevation.clone()])
+    }
+
+    pub fn try_convert(self, attributes: &[String]) -> Result<FillSinksStep, GenerationStepError> {
+        let id = get_attribute_id(&self.elevation, attributes)?;
+        Ok(FillSinksStep::new(id))
+    }
+}
+
+impl FillSinksStep {
+    pub fn convert(&self, attributes: &[String]) -> FillSinksStepData {
+        let elevation = attributes[self.elevation_id].clone();
+        FillSinksStepData::new(elevation)
+    }
+}
@@ -1,6 +1,10 @@
-use crate::data::map::generation::step::{get_attribute_id, GenerationStepError};
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
 use crate::data::map::Map2d;
 use crate::data::math::generator::generator2d::{Generator2d, Generator2dData};
+use crate::data::math::operation::CombineOp;
+use crate::data::math::size2d::Size2d;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::convert::TryInto;
 
@@ -44,25 +48,14 @@ impl GeneratorStep {
     /// assert_eq!(attribute.get_all(), &vec![40u8, 41, 42, 43, 44, 45]);
     /// ```
     pub fn add(&self, map: &mut Map2d) {
-        info!(
+        log_info!(
             "Add '{}' to attribute '{}' of map '{}'",
             self.name,
             map.get_attribute(self.attribute_id).get_name(),
             map.get_name()
         );
 
-        let size = map.size;
-        let attribute = map.get_attribute_mut(self.attribute_id);
-        let mut index = 0;
-
-        for y in 0..size.height() {
-            for x in 0..size.width() {
-                let value = self.generator.generate(x, y);
-                let sum = attribute.get(index).saturating_add(value);
-                *attribute.get_mut(index) = sum;
-                index += 1;
-            }
-        }
+        self.combine(map, CombineOp::Add);
     }
 
     // Subtracts the values.
@@ -85,25 +78,153 @@ impl GeneratorStep {
     /// assert_eq!(attribute.get_all(), &vec![40u8, 39, 38, 37, 36, 35]);
     /// ```
     pub fn sub(&self, map: &mut Map2d) {
-        info!(
+        log_info!(
             "Subtract '{}' from attribute '{}' of map '{}'",
             self.name,
             map.get_attribute(self.attribute_id).get_name(),
             map.get_name()
         );
 
-        let size = map.size;
+        self.combine(map, CombineOp::Sub);
+    }
+
+    // Keeps the bigger values.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::generator::GeneratorStep;
+    ///# use ofws_core::data::math::generator::generator2d::Generator2d::IndexGenerator;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let size = Size2d::new(2, 3);
+    /// let mut map = Map2d::new(size);
+    /// let attribute_id = map.create_attribute("elevation", 2).unwrap();
+    /// let generator = IndexGenerator(size);
+    /// let step = GeneratorStep::new("test", attribute_id, generator);
+    ///
+    /// step.max(&mut map);
+    ///
+    /// let attribute = map.get_attribute(attribute_id);
+    /// assert_eq!(attribute.get_all(), &vec![2u8, 2, 2, 3, 4, 5]);
+    ///
+    /// // never lowers an existing value
+    /// let step = GeneratorStep::new("test", attribute_id, IndexGenerator(size));
+    /// *map.get_attribute_mut(attribute_id).get_mut(1) = 200;
+    ///
+    /// step.max(&mut map);
+    ///
+    /// let attribute = map.get_attribute(attribute_id);
+    /// assert_eq!(attribute.get_all(), &vec![2u8, 200, 2, 3, 4, 5]);
+    /// ```
+    pub fn max(&self, map: &mut Map2d) {
+        log_info!(
+            "Maximum of '{}' & attribute '{}' of map '{}'",
+            self.name,
+            map.get_attribute(self.attribute_id).get_name(),
+            map.get_name()
+        );
+
+        self.combine(map, CombineOp::Max);
+    }
+
+    // Keeps the smaller values.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::generator::GeneratorStep;
+    ///# use ofws_core::data::math::generator::generator2d::Generator2d::IndexGenerator;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let size = Size2d::new(2, 3);
+    /// let mut map = Map2d::new(size);
+    /// let attribute_id = map.create_attribute("elevation", 2).unwrap();
+    /// let generator = IndexGenerator(size);
+    /// let step = GeneratorStep::new("test", attribute_id, generator);
+    ///
+    /// step.min(&mut map);
+    ///
+    /// let attribute = map.get_attribute(attribute_id);
+    /// assert_eq!(attribute.get_all(), &vec![0u8, 1, 2, 2, 2, 2]);
+    /// ```
+    pub fn min(&self, map: &mut Map2d) {
+        log_info!(
+            "Minimum of '{}' & attribute '{}' of map '{}'",
+            self.name,
+            map.get_attribute(self.attribute_id).get_name(),
+            map.get_name()
+        );
+
+        self.combine(map, CombineOp::Min);
+    }
+
+    // Overwrites the values.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::generator::GeneratorStep;
+    ///# use ofws_core::data::math::generator::generator2d::Generator2d::IndexGenerator;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let size = Size2d::new(2, 3);
+    /// let mut map = Map2d::new(size);
+    /// let attribute_id = map.create_attribute("elevation", 40).unwrap();
+    /// *map.get_attribute_mut(attribute_id).get_mut(3) = 200;
+    /// let generator = IndexGenerator(size);
+    /// let step = GeneratorStep::new("test", attribute_id, generator);
+    ///
+    /// step.set(&mut map);
+    ///
+    /// let attribute = map.get_attribute(attribute_id);
+    /// assert_eq!(attribute.get_all(), &vec![0u8, 1, 2, 3, 4, 5]);
+    /// ```
+    pub fn set(&self, map: &mut Map2d) {
+        log_info!(
+            "Set attribute '{}' of map '{}' to '{}'",
+            map.get_attribute(self.attribute_id).get_name(),
+            map.get_name(),
+            self.name
+        );
+
+        self.combine(map, CombineOp::Set);
+    }
+
+    fn combine(&self, map: &mut Map2d, op: CombineOp) {
+        let values = self.generate(map.size);
         let attribute = map.get_attribute_mut(self.attribute_id);
-        let mut index = 0;
+
+        for (index, value) in values.into_iter().enumerate() {
+            let result = op.combine(attribute.get(index), value);
+            *attribute.get_mut(index) = result;
+        }
+    }
+
+    #[cfg(feature = "parallel")]
+    fn generate(&self, size: Size2d) -> Vec<u8> {
+        self.generate_parallel(size)
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    fn generate(&self, size: Size2d) -> Vec<u8> {
+        self.generate_serial(size)
+    }
+
+    /// Generates the values of all cells in parallel.
+    #[cfg(feature = "parallel")]
+    fn generate_parallel(&self, size: Size2d) -> Vec<u8> {
+        (0..size.get_area())
+            .into_par_iter()
+            .map(|index| self.generator.generate(size.to_x(index), size.to_y(index)))
+            .collect()
+    }
+
+    #[cfg_attr(all(feature = "parallel", not(test)), allow(dead_code))]
+    fn generate_serial(&self, size: Size2d) -> Vec<u8> {
+        let mut values = Vec::with_capacity(size.get_area());
 
         for y in 0..size.height() {
             for x in 0..size.width() {
-                let value = self.generator.generate(x, y);
-                let sum = attribute.get(index).saturating_sub(value);
-                *attribute.get_mut(index) = sum;
-                index += 1;
+                values.push(self.generator.generate(x, y));
             }
         }
+
+        values
     }
 }
 
@@ -121,7 +242,7 @@ impl GeneratorStep {
 /// let result: GeneratorStepData = step.convert(&attributes);
 /// assert_eq!(data, result)
 ///```
-#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[derive(new, Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct GeneratorStepData {
     name: String,
     attribute: String,
@@ -129,6 +250,10 @@ pub struct GeneratorStepData {
 }
 
 impl GeneratorStepData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(vec![self.attribute.clone()], vec![self.attribute.clone()])
+    }
+
     pub fn try_convert(self, attributes: &[String]) -> Result<GeneratorStep, GenerationStepError> {
         let id = get_attribute_id(&self.attribute, attributes)?;
         let generator: Generator2d = self.generator.try_into()?;
@@ -142,3 +267,20 @@ impl GeneratorStep {
         GeneratorStepData::new(self.name.clone(), attribute, (&self.generator).into())
     }
 }
+
+#[cfg(test)]
+#[cfg(feature = "parallel")]
+mod tests {
+    use super::*;
+    use crate::data::math::generator::generator1d::Generator1d;
+
+    #[test]
+    fn test_parallel_equals_serial() {
+        let size = Size2d::new(37, 23);
+        let generator = Generator2d::new_apply_to_distance(Generator1d::InputAsOutput, 10, 5);
+        let generator = Generator2d::new_cached(20, 20, generator);
+        let step = GeneratorStep::new("test", 0, generator);
+
+        assert_eq!(step.generate_parallel(size), step.generate_serial(size));
+    }
+}
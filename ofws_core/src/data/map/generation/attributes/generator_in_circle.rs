@@ -0,0 +1,156 @@
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
+use crate::data::map::Map2d;
+use crate::data::math::distance::abs_diff;
+use crate::data::math::generator::generator2d::{Generator2d, Generator2dData};
+use crate::data::math::operation::CombineOp;
+use serde::{Deserialize, Serialize};
+use std::convert::TryInto;
+
+/// Modifies an [`Attribute`] with the values generated by a [`Generator2d`],
+/// but only inside a circle.
+pub struct GeneratorInCircleStep {
+    attribute_id: usize,
+    generator: Generator2d,
+    center_x: u32,
+    center_y: u32,
+    radius: u32,
+    operation: CombineOp,
+}
+
+impl GeneratorInCircleStep {
+    pub fn new(
+        attribute_id: usize,
+        generator: Generator2d,
+        center_x: u32,
+        center_y: u32,
+        radius: u32,
+        operation: CombineOp,
+    ) -> GeneratorInCircleStep {
+        GeneratorInCircleStep {
+            attribute_id,
+            generator,
+            center_x,
+            center_y,
+            radius,
+            operation,
+        }
+    }
+
+    fn is_inside(&self, x: u32, y: u32) -> bool {
+        let diff_x = abs_diff(x, self.center_x) as u64;
+        let diff_y = abs_diff(y, self.center_y) as u64;
+        let radius = self.radius as u64;
+        diff_x * diff_x + diff_y * diff_y <= radius * radius
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::generator_in_circle::GeneratorInCircleStep;
+    ///# use ofws_core::data::math::generator::generator1d::Generator1d;
+    ///# use ofws_core::data::math::generator::generator2d::Generator2d;
+    ///# use ofws_core::data::math::generator::gradient::Gradient;
+    ///# use ofws_core::data::math::operation::CombineOp;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let size = Size2d::new(5, 4);
+    /// let mut map = Map2d::new(size);
+    /// let attribute_id = map.create_attribute("elevation", 10).unwrap();
+    /// let constant = Generator1d::Gradient(Gradient::new(200, 200, 0, 1));
+    /// let generator = Generator2d::new_apply_to_x(constant);
+    /// let step = GeneratorInCircleStep::new(attribute_id, generator, 2, 1, 1, CombineOp::Set);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// let attribute = map.get_attribute(attribute_id);
+    /// assert_eq!(attribute.get_all(), &vec![10u8,  10, 200,  10, 10,
+    ///                                        10, 200, 200, 200, 10,
+    ///                                        10,  10, 200,  10, 10,
+    ///                                        10,  10,  10,  10, 10]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        log_info!(
+            "Apply generator with radius {} around ({},{}) to attribute '{}' of map '{}'",
+            self.radius,
+            self.center_x,
+            self.center_y,
+            map.get_attribute(self.attribute_id).get_name(),
+            map.get_name()
+        );
+
+        let size = map.size;
+        let attribute = map.get_attribute_mut(self.attribute_id);
+        let mut index = 0;
+
+        for y in 0..size.height() {
+            for x in 0..size.width() {
+                if self.is_inside(x, y) {
+                    let value = self.generator.generate(x, y);
+                    let combined = self.operation.combine(attribute.get(index), value);
+                    *attribute.get_mut(index) = combined;
+                }
+                index += 1;
+            }
+        }
+    }
+}
+
+/// For serializing, deserializing & validating [`GeneratorInCircleStep`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::generator_in_circle::{GeneratorInCircleStepData, GeneratorInCircleStep};
+///# use ofws_core::data::math::generator::generator2d::Generator2dData;
+///# use ofws_core::data::math::operation::CombineOp;
+///# use ofws_core::data::math::size2d::Size2d;
+/// let generator = Generator2dData::IndexGenerator(Size2d::new(1, 2));
+/// let data = GeneratorInCircleStepData::new("attribute".to_string(), generator, 3, 4, 5, CombineOp::Max);
+/// let attributes = vec!["attribute".to_string()];
+/// let step: GeneratorInCircleStep = data.clone().try_convert(&attributes).unwrap();
+/// let result: GeneratorInCircleStepData = step.convert(&attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(new, Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct GeneratorInCircleStepData {
+    attribute: String,
+    generator: Generator2dData,
+    center_x: u32,
+    center_y: u32,
+    radius: u32,
+    operation: CombineOp,
+}
+
+impl GeneratorInCircleStepData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(vec![self.attribute.clone()], vec![self.attribute.clone()])
+    }
+
+    pub fn try_convert(
+        self,
+        attributes: &[String],
+    ) -> Result<GeneratorInCircleStep, GenerationStepError> {
+        let id = get_attribute_id(&self.attribute, attributes)?;
+        let generator: Generator2d = self.generator.try_into()?;
+        Ok(GeneratorInCircleStep::new(
+            id,
+            generator,
+            self.center_x,
+            self.center_y,
+            self.radius,
+            self.operation,
+        ))
+    }
+}
+
+impl GeneratorInCircleStep {
+    pub fn convert(&self, attributes: &[String]) -> GeneratorInCircleStepData {
+        let attribute = attributes[self.attribute_id].clone();
+        GeneratorInCircleStepData::new(
+            attribute,
+            (&self.generator).into(),
+            self.center_x,
+            self.center_y,
+            self.radius,
+            self.operation,
+        )
+    }
+}
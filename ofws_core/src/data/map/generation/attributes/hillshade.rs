@@ -0,0 +1,120 @@
+use crate::data::map::generation::attributes::slope::calculate_difference;
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
+use crate::data::map::Map2d;
+use crate::data::math::size2d::Size2d;
+use serde::{Deserialize, Serialize};
+
+/// Sets an [`Attribute`] to the shaded relief of an elevation attribute, e.g. for a 3d look.
+///
+/// The brightness of each cell depends on the angle between its surface normal & the light.
+/// The azimuth is the direction of the light in degrees,
+/// where 0 comes from the positive x-axis & 90 from the positive y-axis.
+/// The altitude is the angle of the light above the horizon in degrees.
+#[derive(new, Debug, Clone)]
+pub struct HillshadeStep {
+    source_id: usize,
+    target_id: usize,
+    azimuth: f32,
+    altitude: f32,
+}
+
+impl HillshadeStep {
+    fn calculate_values(&self, size: Size2d, heights: &[u8]) -> Vec<u8> {
+        let mut values = Vec::with_capacity(size.get_area());
+        let height = |x: u32, y: u32| heights[size.to_index_risky(x, y)] as f32;
+        let azimuth = self.azimuth.to_radians();
+        let altitude = self.altitude.to_radians();
+        let light_x = altitude.cos() * azimuth.cos();
+        let light_y = altitude.cos() * azimuth.sin();
+        let light_z = altitude.sin();
+
+        for y in 0..size.height() {
+            for x in 0..size.width() {
+                let gradient_x = calculate_difference(x, size.width(), |x| height(x, y));
+                let gradient_y = calculate_difference(y, size.height(), |y| height(x, y));
+                let length = (gradient_x * gradient_x + gradient_y * gradient_y + 1.0).sqrt();
+                let shade = (-gradient_x * light_x - gradient_y * light_y + light_z) / length;
+
+                values.push((shade.max(0.0) * 255.0).round().min(255.0) as u8);
+            }
+        }
+
+        values
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::hillshade::HillshadeStep;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(5, 1));
+    /// let source_id = map.create_attribute_from("elevation", vec![0, 2, 4, 2, 0]).unwrap();
+    /// let target_id = map.create_attribute("hillshade", 0).unwrap();
+    /// let step = HillshadeStep::new(source_id, target_id, 0.0, 45.0);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// let values = map.get_attribute(target_id).get_all();
+    /// // the slope facing the light is brighter than the one facing away
+    /// assert!(values[3] > values[2]);
+    /// assert!(values[2] > values[1]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        log_info!(
+            "Set attribute '{}' of map '{}' to the hillshade of attribute '{}'",
+            map.get_attribute(self.target_id).get_name(),
+            map.get_name(),
+            map.get_attribute(self.source_id).get_name(),
+        );
+
+        let source = map.get_attribute(self.source_id);
+        let values = self.calculate_values(map.size, source.get_all());
+        let attribute = map.get_attribute_mut(self.target_id);
+
+        attribute.replace_all(values);
+    }
+}
+
+/// For serializing, deserializing & validating [`HillshadeStep`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::hillshade::{HillshadeStepData, HillshadeStep};
+/// let data = HillshadeStepData::new("s".to_string(), "t".to_string(), 315.0, 45.0);
+/// let attributes = vec!["s".to_string(), "t".to_string()];
+/// let step: HillshadeStep = data.clone().try_convert(&attributes).unwrap();
+/// let result: HillshadeStepData = step.convert(&attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(new, Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct HillshadeStepData {
+    source: String,
+    target: String,
+    azimuth: f32,
+    altitude: f32,
+}
+
+impl HillshadeStepData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(vec![self.source.clone()], vec![self.target.clone()])
+    }
+
+    pub fn try_convert(self, attributes: &[String]) -> Result<HillshadeStep, GenerationStepError> {
+        let source_id = get_attribute_id(&self.source, attributes)?;
+        let target_id = get_attribute_id(&self.target, attributes)?;
+        Ok(HillshadeStep::new(
+            source_id,
+            target_id,
+            self.azimuth,
+            self.altitude,
+        ))
+    }
+}
+
+impl HillshadeStep {
+    pub fn convert(&self, attributes: &[String]) -> HillshadeStepData {
+        let source = attributes[self.source_id].clone();
+        let target = attributes[self.target_id].clone();
+        HillshadeStepData::new(source, target, self.azimuth, self.altitude)
+    }
+}
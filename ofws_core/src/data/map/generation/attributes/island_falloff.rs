@@ -0,0 +1,143 @@
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
+use crate::data::map::Map2d;
+use crate::data::math::distance::calculate_distance;
+use crate::data::math::size2d::Size2d;
+use serde::{Deserialize, Serialize};
+
+/// Pulls the values of an [`Attribute`] down towards the edges of the map, e.g. to create an island.
+///
+/// Each value is multiplied by a factor based on the normalized distance (0-255) to the center.
+/// The factor is 1 until the distance reaches falloff_start
+/// & then decreases linearly until the farthest corner,
+/// where strength determines how much is removed (255 removes everything).
+#[derive(new, Debug, Clone)]
+pub struct IslandFalloff {
+    attribute_id: usize,
+    falloff_start: u8,
+    strength: u8,
+}
+
+impl IslandFalloff {
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::island_falloff::IslandFalloff;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(7, 7));
+    /// let attribute_id = map.create_attribute("elevation", 200).unwrap();
+    /// let step = IslandFalloff::new(attribute_id, 0, 255);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// let attribute = map.get_attribute(attribute_id);
+    /// let center = attribute.get(24);
+    /// let middle = attribute.get(8);
+    /// let corner = attribute.get(0);
+    ///
+    /// assert_eq!(center, 200);
+    /// assert!(middle < center);
+    /// assert!(corner < middle);
+    /// assert_eq!(corner, 0);
+    /// ```
+    ///
+    /// Values closer to the center than falloff_start are unchanged:
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::island_falloff::IslandFalloff;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(7, 7));
+    /// let attribute_id = map.create_attribute("elevation", 200).unwrap();
+    /// let step = IslandFalloff::new(attribute_id, 128, 128);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// let attribute = map.get_attribute(attribute_id);
+    ///
+    /// assert_eq!(attribute.get(24), 200);
+    /// assert_eq!(attribute.get(8), 200);
+    /// assert_eq!(attribute.get(0), 99);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        log_info!(
+            "Island falloff of attribute '{}' of map '{}' starting at {} with strength {}",
+            map.get_attribute(self.attribute_id).get_name(),
+            map.get_name(),
+            self.falloff_start,
+            self.strength,
+        );
+
+        let size = map.size;
+        let attribute = map.get_attribute_mut(self.attribute_id);
+
+        for (index, value) in self.calculate_factors(size).into_iter().enumerate() {
+            let old = attribute.get(index);
+            *attribute.get_mut(index) = (old as f32 * value) as u8;
+        }
+    }
+
+    fn calculate_factors(&self, size: Size2d) -> Vec<f32> {
+        let center_x = size.width() / 2;
+        let center_y = size.height() / 2;
+        let max_x = center_x.max(size.width().saturating_sub(1) - center_x);
+        let max_y = center_y.max(size.height().saturating_sub(1) - center_y);
+        let max_distance = calculate_distance(0, 0, max_x, max_y).max(1);
+        let mut factors = Vec::with_capacity(size.get_area());
+
+        for y in 0..size.height() {
+            for x in 0..size.width() {
+                let distance = calculate_distance(center_x, center_y, x, y);
+                let normalized = (distance * 255 / max_distance).min(255) as u8;
+                factors.push(self.calculate_factor(normalized));
+            }
+        }
+
+        factors
+    }
+
+    fn calculate_factor(&self, distance: u8) -> f32 {
+        if distance <= self.falloff_start {
+            return 1.0;
+        }
+
+        let progress = (distance - self.falloff_start) as f32 / (255 - self.falloff_start) as f32;
+
+        1.0 - progress * self.strength as f32 / 255.0
+    }
+}
+
+/// For serializing, deserializing & validating [`IslandFalloff`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::island_falloff::{IslandFalloffData, IslandFalloff};
+/// let data = IslandFalloffData::new("attribute".to_string(), 100, 200);
+/// let attributes = vec!["attribute".to_string()];
+/// let step: IslandFalloff = data.clone().try_convert(&attributes).unwrap();
+/// let result: IslandFalloffData = step.convert(&attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct IslandFalloffData {
+    attribute: String,
+    falloff_start: u8,
+    strength: u8,
+}
+
+impl IslandFalloffData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(vec![self.attribute.clone()], vec![self.attribute.clone()])
+    }
+
+    pub fn try_convert(self, attributes: &[String]) -> Result<IslandFalloff, GenerationStepError> {
+        let id = get_attribute_id(&self.attribute, attributes)?;
+        Ok(IslandFalloff::new(id, self.falloff_start, self.strength))
+    }
+}
+
+impl IslandFalloff {
+    pub fn convert(&self, attributes: &[String]) -> IslandFalloffData {
+        let attribute = attributes[self.attribute_id].clone();
+        IslandFalloffData::new(attribute, self.falloff_start, self.strength)
+    }
+}
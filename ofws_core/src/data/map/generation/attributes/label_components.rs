@@ -0,0 +1,202 @@
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
+use crate::data::map::Map2d;
+use crate::data::math::connectivity::Connectivity;
+use crate::data::math::size2d::Size2d;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Labels the connected components of an [`Attribute`] & writes the labels into another.
+///
+/// A component is a group of connected cells with the same value, that isn't the background.
+/// Whether diagonal cells are connected depends on the [`Connectivity`]. The background gets the label 0
+/// & the components are numbered from 1 in the order they are found.
+/// The labels wrap around after 255.
+#[derive(new, Debug, Clone)]
+pub struct LabelComponentsStep {
+    source_id: usize,
+    target_id: usize,
+    background: u8,
+    connectivity: Connectivity,
+}
+
+impl LabelComponentsStep {
+    fn calculate_values(&self, size: Size2d, values: &[u8]) -> Vec<u8> {
+        let mut parents: Vec<usize> = Vec::new();
+        let mut provisional = vec![0usize; values.len()];
+
+        for (index, value) in values.iter().enumerate() {
+            if *value == self.background {
+                continue;
+            }
+
+            let mut label = None;
+
+            for neighbor in self.connectivity.neighbors(size, index) {
+                if neighbor >= index || values[neighbor] != *value {
+                    continue;
+                }
+
+                match label {
+                    None => label = Some(provisional[neighbor]),
+                    Some(label) => union(&mut parents, label, provisional[neighbor]),
+                }
+            }
+
+            provisional[index] = label.unwrap_or_else(|| {
+                parents.push(parents.len() + 1);
+                parents.len()
+            });
+        }
+
+        let mut labels: HashMap<usize, usize> = HashMap::new();
+
+        values
+            .iter()
+            .zip(provisional)
+            .map(|(value, label)| {
+                if *value == self.background {
+                    return 0;
+                }
+
+                let root = find(&mut parents, label);
+                let next_label = labels.len() + 1;
+                (*labels.entry(root).or_insert(next_label) % 256) as u8
+            })
+            .collect()
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::label_components::LabelComponentsStep;
+    ///# use ofws_core::data::math::connectivity::Connectivity;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(5, 4));
+    /// let source_id = map.create_attribute_from("source", vec![9, 9, 0, 9, 9,
+    ///                                                          0, 9, 0, 0, 9,
+    ///                                                          0, 0, 0, 9, 9,
+    ///                                                          7, 7, 9, 9, 0]).unwrap();
+    /// let target_id = map.create_attribute("target", 100).unwrap();
+    /// let step = LabelComponentsStep::new(source_id, target_id, 0, Connectivity::Four);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(map.get_attribute(target_id).get_all(), &vec![1u8, 1, 0, 2, 2,
+    ///                                                          0, 1, 0, 0, 2,
+    ///                                                          0, 0, 0, 2, 2,
+    ///                                                          3, 3, 2, 2, 0]);
+    /// ```
+    ///
+    /// Diagonal cells are only connected with [`Connectivity::Eight`].
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::label_components::LabelComponentsStep;
+    ///# use ofws_core::data::math::connectivity::Connectivity;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(2, 2));
+    /// let source_id = map.create_attribute_from("source", vec![0, 9,
+    ///                                                          9, 0]).unwrap();
+    /// let four_id = map.create_attribute("four", 0).unwrap();
+    /// let eight_id = map.create_attribute("eight", 0).unwrap();
+    ///
+    /// LabelComponentsStep::new(source_id, four_id, 0, Connectivity::Four).run(&mut map);
+    /// LabelComponentsStep::new(source_id, eight_id, 0, Connectivity::Eight).run(&mut map);
+    ///
+    /// assert_eq!(map.get_attribute(four_id).get_all(), &vec![0u8, 1, 2, 0]);
+    /// assert_eq!(map.get_attribute(eight_id).get_all(), &vec![0u8, 1, 1, 0]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        log_info!(
+            "Label the components of attribute '{}' in attribute '{}' of map '{}'",
+            map.get_attribute(self.source_id).get_name(),
+            map.get_attribute(self.target_id).get_name(),
+            map.get_name()
+        );
+
+        let values = self.calculate_values(map.size, map.get_attribute(self.source_id).get_all());
+        let attribute = map.get_attribute_mut(self.target_id);
+
+        attribute.replace_all(values);
+    }
+}
+
+/// Returns the root of a label & compresses the path to it.
+fn find(parents: &mut [usize], label: usize) -> usize {
+    let mut root = label;
+
+    while parents[root - 1] != root {
+        root = parents[root - 1];
+    }
+
+    let mut current = label;
+
+    while current != root {
+        let next = parents[current - 1];
+        parents[current - 1] = root;
+        current = next;
+    }
+
+    root
+}
+
+/// Merges the sets of 2 labels.
+fn union(parents: &mut [usize], label0: usize, label1: usize) {
+    let root0 = find(parents, label0);
+    let root1 = find(parents, label1);
+
+    if root0 < root1 {
+        parents[root1 - 1] = root0;
+    } else if root1 < root0 {
+        parents[root0 - 1] = root1;
+    }
+}
+
+/// For serializing, deserializing & validating [`LabelComponentsStep`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::label_components::{LabelComponentsStepData, LabelComponentsStep};
+///# use ofws_core::data::math::connectivity::Connectivity;
+/// let data = LabelComponentsStepData::new("s".to_string(), "t".to_string(), 42, Connectivity::Eight);
+/// let attributes = vec!["s".to_string(), "t".to_string()];
+/// let step: LabelComponentsStep = data.clone().try_convert(&attributes).unwrap();
+/// let result: LabelComponentsStepData = step.convert(&attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct LabelComponentsStepData {
+    source: String,
+    target: String,
+    background: u8,
+    #[serde(default)]
+    connectivity: Connectivity,
+}
+
+impl LabelComponentsStepData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(vec![self.source.clone()], vec![self.target.clone()])
+    }
+
+    pub fn try_convert(
+        self,
+        attributes: &[String],
+    ) -> Result<LabelComponentsStep, GenerationStepError> {
+        let source_id = get_attribute_id(&self.source, attributes)?;
+        let target_id = get_attribute_id(&self.target, attributes)?;
+        Ok(LabelComponentsStep::new(
+            source_id,
+            target_id,
+            self.background,
+            self.connectivity,
+        ))
+    }
+}
+
+impl LabelComponentsStep {
+    pub fn convert(&self, attributes: &[String]) -> LabelComponentsStepData {
+        let source = attributes[self.source_id].clone();
+        let target = attributes[self.target_id].clone();
+        LabelComponentsStepData::new(source, target, self.background, self.connectivity)
+    }
+}
@@ -0,0 +1,144 @@
+use crate::data::map::generation::step::{
+    get_attribute_id, GenerationStep, GenerationStepData, GenerationStepError, StepDependencies,
+};
+use crate::data::map::Map2d;
+use serde::{Deserialize, Serialize};
+
+/// Runs another [`GenerationStep`] only where a mask [`Attribute`] reaches a threshold.
+///
+/// The values of the target attribute are restored in all other cells.
+pub struct MaskedStep {
+    step: Box<GenerationStep>,
+    attribute_id: usize,
+    mask_id: usize,
+    threshold: u8,
+}
+
+impl MaskedStep {
+    pub fn new(
+        step: GenerationStep,
+        attribute_id: usize,
+        mask_id: usize,
+        threshold: u8,
+    ) -> MaskedStep {
+        MaskedStep {
+            step: Box::new(step),
+            attribute_id,
+            mask_id,
+            threshold,
+        }
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::clamp::ClampStep;
+    ///# use ofws_core::data::map::generation::attributes::masked::MaskedStep;
+    ///# use ofws_core::data::map::generation::step::GenerationStep;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(4, 1));
+    /// let attribute_id = map.create_attribute_from("elevation", vec![10, 20, 150, 200]).unwrap();
+    /// let mask_id = map.create_attribute_from("land", vec![0, 200, 200, 0]).unwrap();
+    /// let clamp = ClampStep::new(attribute_id, 50, 100).unwrap();
+    /// let step = MaskedStep::new(GenerationStep::Clamp(clamp), attribute_id, mask_id, 100);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(map.get_attribute(attribute_id).get_all(), &vec![10u8, 50, 100, 200]);
+    /// assert_eq!(map.get_attribute(mask_id).get_all(), &vec![0u8, 200, 200, 0]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        log_info!(
+            "Mask attribute '{}' of map '{}' with attribute '{}'",
+            map.get_attribute(self.attribute_id).get_name(),
+            map.get_name(),
+            map.get_attribute(self.mask_id).get_name(),
+        );
+
+        let original = map.get_attribute(self.attribute_id).get_all().clone();
+
+        self.step.run(map);
+
+        let mask = map.get_attribute(self.mask_id).get_all().clone();
+        let attribute = map.get_attribute_mut(self.attribute_id);
+
+        for (index, value) in original.into_iter().enumerate() {
+            if mask[index] < self.threshold {
+                *attribute.get_mut(index) = value;
+            }
+        }
+    }
+}
+
+/// For serializing, deserializing & validating [`MaskedStep`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::clamp::ClampStepData;
+///# use ofws_core::data::map::generation::attributes::masked::{MaskedStepData, MaskedStep};
+///# use ofws_core::data::map::generation::step::GenerationStepData;
+/// let clamp = ClampStepData::new("a".to_string(), 20, 200);
+/// let data = MaskedStepData::new(GenerationStepData::Clamp(clamp), "a".to_string(), "m".to_string(), 100);
+/// let mut attributes = vec!["a".to_string(), "m".to_string()];
+/// let step: MaskedStep = data.clone().try_convert(&mut attributes).unwrap();
+/// let result: MaskedStepData = step.convert(&mut attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct MaskedStepData {
+    step: Box<GenerationStepData>,
+    attribute: String,
+    mask: String,
+    threshold: u8,
+}
+
+impl MaskedStepData {
+    pub fn new(
+        step: GenerationStepData,
+        attribute: String,
+        mask: String,
+        threshold: u8,
+    ) -> MaskedStepData {
+        MaskedStepData {
+            step: Box::new(step),
+            attribute,
+            mask,
+            threshold,
+        }
+    }
+
+    pub fn dependencies(&self) -> StepDependencies {
+        let mut dependencies = self.step.dependencies();
+
+        for attribute in [&self.attribute, &self.mask] {
+            if !dependencies.reads.contains(attribute) {
+                dependencies.reads.push(attribute.clone());
+            }
+        }
+
+        if !dependencies.writes.contains(&self.attribute) {
+            dependencies.writes.push(self.attribute.clone());
+        }
+
+        dependencies
+    }
+
+    pub fn try_convert(
+        self,
+        attributes: &mut Vec<String>,
+    ) -> Result<MaskedStep, GenerationStepError> {
+        let step = self.step.try_convert(attributes)?;
+        let attribute_id = get_attribute_id(&self.attribute, attributes)?;
+        let mask_id = get_attribute_id(&self.mask, attributes)?;
+        Ok(MaskedStep::new(step, attribute_id, mask_id, self.threshold))
+    }
+}
+
+impl MaskedStep {
+    pub fn convert(&self, attributes: &mut Vec<String>) -> MaskedStepData {
+        let step = self.step.convert(attributes);
+        let attribute = attributes[self.attribute_id].clone();
+        let mask = attributes[self.mask_id].clone();
+        MaskedStepData::new(step, attribute, mask, self.threshold)
+    }
+}
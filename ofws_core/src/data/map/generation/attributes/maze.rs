@@ -0,0 +1,153 @@
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
+use crate::data::map::Map2d;
+use crate::data::math::random::Random;
+use crate::data::math::size2d::Size2d;
+use serde::{Deserialize, Serialize};
+
+const NEIGHBORS: [(i32, i32); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Carves a perfect maze into an [`Attribute`] with a randomized depth-first search.
+///
+/// The cells of the maze are at odd coordinates, while walls are at even coordinates.
+/// So a map with width 7 has 3 cells along the x-axis.
+#[derive(new, Debug, Clone)]
+pub struct MazeStep {
+    attribute_id: usize,
+    seed: u32,
+    wall_value: u8,
+    floor_value: u8,
+}
+
+impl MazeStep {
+    fn calculate_values(&self, size: Size2d) -> Vec<u8> {
+        let mut values = vec![self.wall_value; size.get_area()];
+        let cells_x = (size.width().saturating_sub(1) / 2) as i32;
+        let cells_y = (size.height().saturating_sub(1) / 2) as i32;
+
+        if cells_x == 0 || cells_y == 0 {
+            return values;
+        }
+
+        let mut random = Random::new(self.seed);
+        let mut visited = vec![false; (cells_x * cells_y) as usize];
+        let mut stack = vec![(0, 0)];
+        let to_index = |x: i32, y: i32| size.to_index_risky(x as u32, y as u32);
+
+        visited[0] = true;
+        values[to_index(1, 1)] = self.floor_value;
+
+        while let Some(&(x, y)) = stack.last() {
+            let candidates: Vec<(i32, i32)> = NEIGHBORS
+                .iter()
+                .map(|(dx, dy)| (x + dx, y + dy))
+                .filter(|&(nx, ny)| {
+                    nx >= 0
+                        && ny >= 0
+                        && nx < cells_x
+                        && ny < cells_y
+                        && !visited[(ny * cells_x + nx) as usize]
+                })
+                .collect();
+
+            if candidates.is_empty() {
+                stack.pop();
+                continue;
+            }
+
+            let (nx, ny) = candidates[random.next_range(candidates.len() as u32) as usize];
+            visited[(ny * cells_x + nx) as usize] = true;
+            values[to_index(x + nx + 1, y + ny + 1)] = self.floor_value;
+            values[to_index(nx * 2 + 1, ny * 2 + 1)] = self.floor_value;
+            stack.push((nx, ny));
+        }
+
+        values
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::maze::MazeStep;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let size = Size2d::new(7, 5);
+    /// let mut map = Map2d::new(size);
+    /// let attribute_id = map.create_attribute("maze", 0).unwrap();
+    /// let step = MazeStep::new(attribute_id, 42, 0, 1);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// let values = map.get_attribute(attribute_id).get_all().clone();
+    /// // 3 * 2 cells & 5 passages between them
+    /// assert_eq!(values.iter().filter(|&&v| v == 1).count(), 11);
+    ///
+    /// // every floor cell is reachable
+    /// let mut reached = vec![false; values.len()];
+    /// let mut open = vec![size.to_index_risky(1, 1)];
+    /// while let Some(index) = open.pop() {
+    ///     if values[index] == 0 || reached[index] {
+    ///         continue;
+    ///     }
+    ///     reached[index] = true;
+    ///     open.extend(&[index - 1, index + 1, index - 7, index + 7]);
+    /// }
+    /// assert_eq!(reached.iter().filter(|&&r| r).count(), 11);
+    ///
+    /// // the same seed creates the same maze
+    /// step.run(&mut map);
+    /// assert_eq!(map.get_attribute(attribute_id).get_all(), &values);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        log_info!(
+            "Create maze in attribute '{}' of map '{}'",
+            map.get_attribute(self.attribute_id).get_name(),
+            map.get_name()
+        );
+
+        let values = self.calculate_values(map.size);
+        let attribute = map.get_attribute_mut(self.attribute_id);
+
+        attribute.replace_all(values);
+    }
+}
+
+/// For serializing, deserializing & validating [`MazeStep`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::maze::{MazeStepData, MazeStep};
+/// let data = MazeStepData::new("attribute".to_string(), 42, 10, 200);
+/// let attributes = vec!["attribute".to_string()];
+/// let step: MazeStep = data.clone().try_convert(&attributes).unwrap();
+/// let result: MazeStepData = step.convert(&attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct MazeStepData {
+    attribute: String,
+    seed: u32,
+    wall_value: u8,
+    floor_value: u8,
+}
+
+impl MazeStepData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(vec![], vec![self.attribute.clone()])
+    }
+
+    pub fn try_convert(self, attributes: &[String]) -> Result<MazeStep, GenerationStepError> {
+        let id = get_attribute_id(&self.attribute, attributes)?;
+        Ok(MazeStep::new(
+            id,
+            self.seed,
+            self.wall_value,
+            self.floor_value,
+        ))
+    }
+}
+
+impl MazeStep {
+    pub fn convert(&self, attributes: &[String]) -> MazeStepData {
+        let attribute = attributes[self.attribute_id].clone();
+        MazeStepData::new(attribute, self.seed, self.wall_value, self.floor_value)
+    }
+}
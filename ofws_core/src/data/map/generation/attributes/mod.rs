@@ -1,6 +1,34 @@
+pub mod arithmetic_scalar;
+pub mod cellular_automata;
+pub mod clamp;
+pub mod coastal_erosion;
+pub mod combine;
+pub mod copy;
 pub mod create;
+pub mod distance_transform;
 pub mod distortion1d;
 pub mod distortion2d;
+pub mod edge_distance;
+pub mod erosion;
+pub mod fault_lines;
+pub mod fill_rect;
+pub mod fill_sinks;
 pub mod generator;
+pub mod generator_in_circle;
+pub mod hillshade;
+pub mod island_falloff;
+pub mod label_components;
+pub mod masked;
+pub mod maze;
 pub mod modify;
+pub mod normalize;
+pub mod paste;
+pub mod place_points;
+pub mod radial_stamp;
+pub mod rescale;
+pub mod set_border;
+pub mod slope;
+pub mod smooth;
+pub mod snap;
 pub mod transformer;
+pub mod warped_biome;
@@ -1,5 +1,6 @@
-use crate::data::map::generation::step::{get_attribute_id, GenerationStepError};
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
 use crate::data::map::Map2d;
+use crate::data::math::selector::Selector;
 use serde::{Deserialize, Serialize};
 
 /// Modifies one [`Attribute`] with another transformed one.
@@ -9,10 +10,42 @@ pub struct ModifyWithAttribute {
     target_id: usize,
     factor: f32,
     minimum: u8,
+    #[new(default)]
+    curve: Option<Selector<u8>>,
 }
 
 impl ModifyWithAttribute {
+    /// Returns the same step, but the source values are mapped by a curve before applying the factor.
+    ///
+    /// A curve, that zeroes low source values, leaves the target unchanged there:
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::modify::ModifyWithAttribute;
+    ///# use ofws_core::data::math::selector::Selector;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(4, 1));
+    /// map.create_attribute_from("rainfall", vec![0, 50, 150, 200]);
+    /// map.create_attribute_from("temperature", vec![10, 10, 10, 10]);
+    /// let curve = Selector::new_interpolate_vector(vec![(0, 0), (100, 0), (200, 100)]).unwrap();
+    /// let step = ModifyWithAttribute::new(0, 1, 0.5, 0).with_curve(curve);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(map.get_attribute(1).get_all(), &vec![10, 10, 35, 60]);
+    /// ```
+    pub fn with_curve(self, curve: Selector<u8>) -> ModifyWithAttribute {
+        ModifyWithAttribute {
+            curve: Some(curve),
+            ..self
+        }
+    }
+
     fn calculate_value(&self, source: u8, target: u8) -> u8 {
+        let source = match &self.curve {
+            Some(curve) => curve.get(source),
+            None => source,
+        };
         (target as f32 + (source.max(self.minimum) - self.minimum) as f32 * self.factor) as u8
     }
 
@@ -33,7 +66,7 @@ impl ModifyWithAttribute {
 
     // Runs the step.
     pub fn run(&self, map: &mut Map2d) {
-        info!(
+        log_info!(
             "{} attribute '{}' with attribute '{}' of map '{}'",
             if self.factor < 0.0 {
                 "Decrease"
@@ -53,27 +86,46 @@ impl ModifyWithAttribute {
 }
 
 /// For serializing, deserializing & validating [`ModifyWithAttribute`].
-#[derive(new, Debug, Eq, PartialEq, Clone, Serialize, Deserialize)]
+#[derive(new, Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct ModifyWithAttributeData {
     source: String,
     target: String,
     percentage: i32,
     minimum: u8,
+    #[new(default)]
+    #[serde(default)]
+    curve: Option<Selector<u8>>,
 }
 
 impl ModifyWithAttributeData {
+    /// Returns the same data, but with a curve for the source values.
+    pub fn with_curve(self, curve: Selector<u8>) -> ModifyWithAttributeData {
+        ModifyWithAttributeData {
+            curve: Some(curve),
+            ..self
+        }
+    }
+
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(
+            vec![self.source.clone(), self.target.clone()],
+            vec![self.target.clone()],
+        )
+    }
+
     pub fn try_convert(
         self,
         attributes: &[String],
     ) -> Result<ModifyWithAttribute, GenerationStepError> {
         let source_id = get_attribute_id(&self.source, attributes)?;
         let target_id = get_attribute_id(&self.target, attributes)?;
-        Ok(ModifyWithAttribute::new(
+        Ok(ModifyWithAttribute {
             source_id,
             target_id,
-            self.percentage as f32 / 100.0,
-            self.minimum,
-        ))
+            factor: self.percentage as f32 / 100.0,
+            minimum: self.minimum,
+            curve: self.curve,
+        })
     }
 }
 
@@ -81,6 +133,12 @@ impl ModifyWithAttribute {
     pub fn convert(&self, attributes: &[String]) -> ModifyWithAttributeData {
         let source = attributes[self.source_id].clone();
         let target = attributes[self.target_id].clone();
-        ModifyWithAttributeData::new(source, target, (self.factor * 100.0) as i32, self.minimum)
+        ModifyWithAttributeData {
+            source,
+            target,
+            percentage: (self.factor * 100.0) as i32,
+            minimum: self.minimum,
+            curve: self.curve.clone(),
+        }
     }
 }
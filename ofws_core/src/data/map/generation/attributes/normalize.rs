@@ -0,0 +1,109 @@
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
+use crate::data::map::Map2d;
+use serde::{Deserialize, Serialize};
+
+/// Stretches the values of an [`Attribute`] to the full range of [0,255].
+///
+/// A flat attribute is not changed.
+#[derive(new, Debug, Clone)]
+pub struct NormalizeStep {
+    attribute_id: usize,
+}
+
+impl NormalizeStep {
+    fn calculate_values(&self, values: &[u8]) -> Option<Vec<u8>> {
+        let (min, max) = values.iter().fold((u8::MAX, u8::MIN), |(min, max), value| {
+            (min.min(*value), max.max(*value))
+        });
+
+        if min >= max {
+            return None;
+        }
+
+        let min = min as u32;
+        let range = max as u32 - min;
+
+        Some(
+            values
+                .iter()
+                .map(|value| ((*value as u32 - min) * 255 / range) as u8)
+                .collect(),
+        )
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::normalize::NormalizeStep;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(3, 1));
+    /// let attribute_id = map.create_attribute_from("elevation", vec![50, 100, 150]).unwrap();
+    /// let step = NormalizeStep::new(attribute_id);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(map.get_attribute(attribute_id).get_all(), &vec![0u8, 127, 255]);
+    /// ```
+    ///
+    /// A flat attribute is not changed:
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::normalize::NormalizeStep;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(3, 1));
+    /// let attribute_id = map.create_attribute("elevation", 42).unwrap();
+    /// let step = NormalizeStep::new(attribute_id);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(map.get_attribute(attribute_id).get_all(), &vec![42u8, 42, 42]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        log_info!(
+            "Normalize attribute '{}' of map '{}'",
+            map.get_attribute(self.attribute_id).get_name(),
+            map.get_name(),
+        );
+
+        let attribute = map.get_attribute_mut(self.attribute_id);
+
+        if let Some(values) = self.calculate_values(attribute.get_all()) {
+            attribute.replace_all(values);
+        }
+    }
+}
+
+/// For serializing, deserializing & validating [`NormalizeStep`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::normalize::{NormalizeStepData, NormalizeStep};
+/// let data = NormalizeStepData::new("attribute".to_string());
+/// let attributes = vec!["attribute".to_string()];
+/// let step: NormalizeStep = data.clone().try_convert(&attributes).unwrap();
+/// let result: NormalizeStepData = step.convert(&attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct NormalizeStepData {
+    attribute: String,
+}
+
+impl NormalizeStepData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(vec![self.attribute.clone()], vec![self.attribute.clone()])
+    }
+
+    pub fn try_convert(self, attributes: &[String]) -> Result<NormalizeStep, GenerationStepError> {
+        let id = get_attribute_id(&self.attribute, attributes)?;
+        Ok(NormalizeStep::new(id))
+    }
+}
+
+impl NormalizeStep {
+    pub fn convert(&self, attributes: &[String]) -> NormalizeStepData {
+        let attribute = attributes[self.attribute_id].clone();
+        NormalizeStepData::new(attribute)
+    }
+}
@@ -0,0 +1,128 @@
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
+use crate::data::map::Map2d;
+use crate::data::math::operation::CombineOp;
+use serde::{Deserialize, Serialize};
+
+/// Pastes an [`Attribute`] into another at an offset.
+///
+/// Overlapping cells are combined with an operation
+/// & cells outside of the target are ignored.
+#[derive(new, Debug, Clone)]
+pub struct PasteAttributeStep {
+    source_id: usize,
+    target_id: usize,
+    offset: (u32, u32),
+    operation: CombineOp,
+}
+
+impl PasteAttributeStep {
+    fn calculate_values(&self, map: &Map2d) -> Vec<u8> {
+        let source = map.get_attribute(self.source_id);
+        let target = map.get_attribute(self.target_id);
+        let source_size = source.get_size();
+        let target_size = target.get_size();
+        let mut values = target.get_all().clone();
+        let (offset_x, offset_y) = self.offset;
+
+        for y in 0..source_size.height() {
+            for x in 0..source_size.width() {
+                let target_x = x.checked_add(offset_x);
+                let target_y = y.checked_add(offset_y);
+
+                if let (Some(target_x), Some(target_y)) = (target_x, target_y) {
+                    if let Some(index) = target_size.to_index(target_x, target_y) {
+                        let value = source.get(source_size.to_index_risky(x, y));
+                        values[index] = self.operation.combine(values[index], value);
+                    }
+                }
+            }
+        }
+
+        values
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::paste::PasteAttributeStep;
+    ///# use ofws_core::data::math::operation::CombineOp;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(3, 3));
+    /// let source_id = map.create_attribute_from("source", vec![10, 20, 30,
+    ///                                                          40, 50, 60,
+    ///                                                          70, 80, 90]).unwrap();
+    /// let target_id = map.create_attribute("target", 35).unwrap();
+    /// let step = PasteAttributeStep::new(source_id, target_id, (1, 1), CombineOp::Max);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(map.get_attribute(target_id).get_all(), &vec![35u8, 35, 35,
+    ///                                                          35, 35, 35,
+    ///                                                          35, 40, 50]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        log_info!(
+            "Paste attribute '{}' into '{}' of map '{}' at {:?}",
+            map.get_attribute(self.source_id).get_name(),
+            map.get_attribute(self.target_id).get_name(),
+            map.get_name(),
+            self.offset,
+        );
+
+        let values = self.calculate_values(map);
+        let attribute = map.get_attribute_mut(self.target_id);
+
+        attribute.replace_all(values);
+    }
+}
+
+/// For serializing, deserializing & validating [`PasteAttributeStep`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::paste::{PasteAttributeStepData, PasteAttributeStep};
+///# use ofws_core::data::math::operation::CombineOp;
+/// let data = PasteAttributeStepData::new("s".to_string(), "t".to_string(), (2, 3), CombineOp::Add);
+/// let attributes = vec!["s".to_string(), "t".to_string()];
+/// let step: PasteAttributeStep = data.clone().try_convert(&attributes).unwrap();
+/// let result: PasteAttributeStepData = step.convert(&attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct PasteAttributeStepData {
+    source: String,
+    target: String,
+    offset: (u32, u32),
+    operation: CombineOp,
+}
+
+impl PasteAttributeStepData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(
+            vec![self.source.clone(), self.target.clone()],
+            vec![self.target.clone()],
+        )
+    }
+
+    pub fn try_convert(
+        self,
+        attributes: &[String],
+    ) -> Result<PasteAttributeStep, GenerationStepError> {
+        let source_id = get_attribute_id(&self.source, attributes)?;
+        let target_id = get_attribute_id(&self.target, attributes)?;
+        Ok(PasteAttributeStep::new(
+            source_id,
+            target_id,
+            self.offset,
+            self.operation,
+        ))
+    }
+}
+
+impl PasteAttributeStep {
+    pub fn convert(&self, attributes: &[String]) -> PasteAttributeStepData {
+        let source = attributes[self.source_id].clone();
+        let target = attributes[self.target_id].clone();
+        PasteAttributeStepData::new(source, target, self.offset, self.operation)
+    }
+}
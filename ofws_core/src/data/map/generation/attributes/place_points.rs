@@ -0,0 +1,81 @@
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
+use crate::data::map::Map2d;
+use serde::{Deserialize, Serialize};
+
+/// Writes hand-placed points into an [`Attribute`].
+#[derive(new, Debug, Clone)]
+pub struct PlacePointsStep {
+    attribute_id: usize,
+    points: Vec<(u32, u32, u8)>,
+}
+
+impl PlacePointsStep {
+    /// Runs the step. Points outside the map are ignored.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::place_points::PlacePointsStep;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(3, 2));
+    /// let attribute_id = map.create_attribute("cities", 0).unwrap();
+    /// let step = PlacePointsStep::new(attribute_id, vec![(1, 0, 10), (2, 1, 20), (3, 0, 30)]);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(map.get_attribute(attribute_id).get_all(), &vec![0u8, 10, 0, 0, 0, 20]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        log_info!(
+            "Place {} points in attribute '{}' of map '{}'",
+            self.points.len(),
+            map.get_attribute(self.attribute_id).get_name(),
+            map.get_name(),
+        );
+
+        let size = map.size;
+        let attribute = map.get_attribute_mut(self.attribute_id);
+
+        for (x, y, value) in &self.points {
+            if let Some(index) = size.to_index(*x, *y) {
+                *attribute.get_mut(index) = *value;
+            }
+        }
+    }
+}
+
+/// For serializing, deserializing & validating [`PlacePointsStep`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::place_points::{PlacePointsStepData, PlacePointsStep};
+/// let data = PlacePointsStepData::new("attribute".to_string(), vec![(1, 2, 3), (4, 5, 6)]);
+/// let attributes = vec!["attribute".to_string()];
+/// let step: PlacePointsStep = data.clone().try_convert(&attributes).unwrap();
+/// let result: PlacePointsStepData = step.convert(&attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct PlacePointsStepData {
+    attribute: String,
+    points: Vec<(u32, u32, u8)>,
+}
+
+impl PlacePointsStepData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(vec![self.attribute.clone()], vec![self.attribute.clone()])
+    }
+
+    pub fn try_convert(
+        self,
+        attributes: &[String],
+    ) -> Result<PlacePointsStep, GenerationStepError> {
+        let id = get_attribute_id(&self.attribute, attributes)?;
+        Ok(PlacePointsStep::new(id, self.points))
+    }
+}
+
+impl PlacePointsStep {
+    pub fn convert(&self, attributes: &[String]) -> PlacePointsStepData {
+        let attribute = attributes[self.attribute_id].clone();
+        PlacePointsStepData::new(attribute, self.points.clone())
+    }
+}
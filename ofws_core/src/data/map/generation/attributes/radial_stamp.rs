@@ -0,0 +1,138 @@
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
+use crate::data::map::Map2d;
+use crate::data::math::distance::calculate_distance;
+use crate::data::math::generator::generator1d::{Generator1d, Generator1dData};
+use crate::data::math::operation::CombineOp;
+use serde::{Deserialize, Serialize};
+use std::convert::TryInto;
+
+/// Stamps the radial profile of a [`Generator1d`] around a center onto an [`Attribute`].
+///
+/// The generator gets the distance of each cell to the center
+/// & its result is combined with the existing value.
+pub struct RadialStampStep {
+    attribute_id: usize,
+    center_x: u32,
+    center_y: u32,
+    generator: Generator1d,
+    operation: CombineOp,
+}
+
+impl RadialStampStep {
+    pub fn new(
+        attribute_id: usize,
+        center_x: u32,
+        center_y: u32,
+        generator: Generator1d,
+        operation: CombineOp,
+    ) -> RadialStampStep {
+        RadialStampStep {
+            attribute_id,
+            center_x,
+            center_y,
+            generator,
+            operation,
+        }
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::radial_stamp::RadialStampStep;
+    ///# use ofws_core::data::math::generator::generator1d::Generator1d;
+    ///# use ofws_core::data::math::generator::gradient::Gradient;
+    ///# use ofws_core::data::math::operation::CombineOp;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(5, 3));
+    /// let attribute_id = map.create_attribute("elevation", 50).unwrap();
+    /// let generator = Generator1d::Gradient(Gradient::new(200, 0, 0, 2));
+    /// let step = RadialStampStep::new(attribute_id, 2, 1, generator, CombineOp::Max);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// let attribute = map.get_attribute(attribute_id);
+    /// assert_eq!(attribute.get_all(), &vec![50u8, 100, 100, 100, 50,
+    ///                                       50, 100, 200, 100, 50,
+    ///                                       50, 100, 100, 100, 50]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        log_info!(
+            "Stamp a radial profile around ({},{}) onto attribute '{}' of map '{}'",
+            self.center_x,
+            self.center_y,
+            map.get_attribute(self.attribute_id).get_name(),
+            map.get_name()
+        );
+
+        let size = map.size;
+        let attribute = map.get_attribute_mut(self.attribute_id);
+        let mut index = 0;
+
+        for y in 0..size.height() {
+            for x in 0..size.width() {
+                let distance = calculate_distance(self.center_x, self.center_y, x, y);
+                let value = self.generator.generate(distance);
+                let combined = self.operation.combine(attribute.get(index), value);
+                *attribute.get_mut(index) = combined;
+                index += 1;
+            }
+        }
+    }
+}
+
+/// For serializing, deserializing & validating [`RadialStampStep`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::radial_stamp::{RadialStampStepData, RadialStampStep};
+///# use ofws_core::data::math::generator::generator1d::Generator1dData;
+///# use ofws_core::data::math::operation::CombineOp;
+/// let generator = Generator1dData::InputAsOutput;
+/// let data = RadialStampStepData::new("attribute".to_string(), 3, 4, generator, CombineOp::Add);
+/// let attributes = vec!["attribute".to_string()];
+/// let step: RadialStampStep = data.clone().try_convert(&attributes).unwrap();
+/// let result: RadialStampStepData = step.convert(&attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(new, Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct RadialStampStepData {
+    attribute: String,
+    center_x: u32,
+    center_y: u32,
+    generator: Generator1dData,
+    operation: CombineOp,
+}
+
+impl RadialStampStepData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(vec![self.attribute.clone()], vec![self.attribute.clone()])
+    }
+
+    pub fn try_convert(
+        self,
+        attributes: &[String],
+    ) -> Result<RadialStampStep, GenerationStepError> {
+        let id = get_attribute_id(&self.attribute, attributes)?;
+        let generator: Generator1d = self.generator.try_into()?;
+        Ok(RadialStampStep::new(
+            id,
+            self.center_x,
+            self.center_y,
+            generator,
+            self.operation,
+        ))
+    }
+}
+
+impl RadialStampStep {
+    pub fn convert(&self, attributes: &[String]) -> RadialStampStepData {
+        let attribute = attributes[self.attribute_id].clone();
+        RadialStampStepData::new(
+            attribute,
+            self.center_x,
+            self.center_y,
+            (&self.generator).into(),
+            self.operation,
+        )
+    }
+}
@@ -0,0 +1,112 @@
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
+use crate::data::map::Map2d;
+use serde::{Deserialize, Serialize};
+
+/// Maps the current range of an [`Attribute`] to a new range.
+///
+/// A flat attribute is set to the minimum of the new range.
+#[derive(new, Debug, Clone)]
+pub struct RescaleStep {
+    attribute_id: usize,
+    out_min: u8,
+    out_max: u8,
+}
+
+impl RescaleStep {
+    fn calculate_values(&self, values: &[u8]) -> Vec<u8> {
+        let min = *values.iter().min().unwrap_or(&0) as i32;
+        let max = *values.iter().max().unwrap_or(&0) as i32;
+        let out_min = self.out_min as i32;
+        let out_range = self.out_max as i32 - out_min;
+
+        values
+            .iter()
+            .map(|value| {
+                if max == min {
+                    return self.out_min;
+                }
+
+                (out_min + (*value as i32 - min) * out_range / (max - min)) as u8
+            })
+            .collect()
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::rescale::RescaleStep;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(3, 1));
+    /// let attribute_id = map.create_attribute_from("elevation", vec![0, 128, 255]).unwrap();
+    /// let step = RescaleStep::new(attribute_id, 100, 200);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(map.get_attribute(attribute_id).get_all(), &vec![100u8, 150, 200]);
+    /// ```
+    ///
+    /// A flat attribute is set to the minimum:
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::rescale::RescaleStep;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(3, 1));
+    /// let attribute_id = map.create_attribute("elevation", 42).unwrap();
+    /// let step = RescaleStep::new(attribute_id, 100, 200);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(map.get_attribute(attribute_id).get_all(), &vec![100u8, 100, 100]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        log_info!(
+            "Rescale attribute '{}' of map '{}' to [{},{}]",
+            map.get_attribute(self.attribute_id).get_name(),
+            map.get_name(),
+            self.out_min,
+            self.out_max,
+        );
+
+        let attribute = map.get_attribute_mut(self.attribute_id);
+        let values = self.calculate_values(attribute.get_all());
+
+        attribute.replace_all(values);
+    }
+}
+
+/// For serializing, deserializing & validating [`RescaleStep`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::rescale::{RescaleStepData, RescaleStep};
+/// let data = RescaleStepData::new("attribute".to_string(), 76, 200);
+/// let attributes = vec!["attribute".to_string()];
+/// let step: RescaleStep = data.clone().try_convert(&attributes).unwrap();
+/// let result: RescaleStepData = step.convert(&attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct RescaleStepData {
+    attribute: String,
+    out_min: u8,
+    out_max: u8,
+}
+
+impl RescaleStepData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(vec![self.attribute.clone()], vec![self.attribute.clone()])
+    }
+
+    pub fn try_convert(self, attributes: &[String]) -> Result<RescaleStep, GenerationStepError> {
+        let id = get_attribute_id(&self.attribute, attributes)?;
+        Ok(RescaleStep::new(id, self.out_min, self.out_max))
+    }
+}
+
+impl RescaleStep {
+    pub fn convert(&self, attributes: &[String]) -> RescaleStepData {
+        let attribute = attributes[self.attribute_id].clone();
+        RescaleStepData::new(attribute, self.out_min, self.out_max)
+    }
+}
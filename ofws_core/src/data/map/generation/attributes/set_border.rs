@@ -0,0 +1,111 @@
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
+use crate::data::map::Map2d;
+use crate::data::math::size2d::Size2d;
+use serde::{Deserialize, Serialize};
+
+/// Sets the outermost cells of an [`Attribute`] to the same value,
+/// e.g. to guarantee an ocean around the continents.
+#[derive(new, Debug, Clone)]
+pub struct SetBorder {
+    attribute_id: usize,
+    thickness: u32,
+    value: u8,
+}
+
+impl SetBorder {
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::set_border::SetBorder;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(4, 4));
+    /// let attribute_id = map.create_attribute("elevation", 100).unwrap();
+    /// let step = SetBorder::new(attribute_id, 1, 0);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(map.get_attribute(attribute_id).get_all(), &vec![0u8,   0,   0, 0,
+    ///                                                              0, 100, 100, 0,
+    ///                                                              0, 100, 100, 0,
+    ///                                                              0,   0,   0, 0]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        log_info!(
+            "Set the border of attribute '{}' of map '{}' with a thickness of {} to {}",
+            map.get_attribute(self.attribute_id).get_name(),
+            map.get_name(),
+            self.thickness,
+            self.value,
+        );
+
+        let indices = calculate_border_indices(map.size, self.thickness);
+
+        map.get_attribute_mut(self.attribute_id)
+            .replace_some(indices, self.value);
+    }
+}
+
+/// Returns the indices of all cells closer than thickness to an edge of the map.
+///
+/// ```
+///# use ofws_core::data::map::generation::attributes::set_border::calculate_border_indices;
+///# use ofws_core::data::math::size2d::Size2d;
+/// let size = Size2d::new(3, 4);
+///
+/// assert!(calculate_border_indices(size, 0).is_empty());
+/// assert_eq!(calculate_border_indices(size, 1), vec![0, 1, 2, 3, 5, 6, 8, 9, 10, 11]);
+/// assert_eq!(calculate_border_indices(size, 2), (0..12).collect::<Vec<usize>>());
+/// ```
+pub fn calculate_border_indices(size: Size2d, thickness: u32) -> Vec<usize> {
+    let mut indices = Vec::new();
+
+    for y in 0..size.height() {
+        let is_row_inside = y >= thickness && y + thickness < size.height();
+
+        for x in 0..size.width() {
+            let is_inside = is_row_inside && x >= thickness && x + thickness < size.width();
+
+            if !is_inside {
+                indices.push(size.to_index_risky(x, y));
+            }
+        }
+    }
+
+    indices
+}
+
+/// For serializing, deserializing & validating [`SetBorder`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::set_border::{SetBorderData, SetBorder};
+/// let data = SetBorderData::new("attribute".to_string(), 2, 5);
+/// let attributes = vec!["attribute".to_string()];
+/// let step: SetBorder = data.clone().try_convert(&attributes).unwrap();
+/// let result: SetBorderData = step.convert(&attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct SetBorderData {
+    attribute: String,
+    thickness: u32,
+    value: u8,
+}
+
+impl SetBorderData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(vec![self.attribute.clone()], vec![self.attribute.clone()])
+    }
+
+    pub fn try_convert(self, attributes: &[String]) -> Result<SetBorder, GenerationStepError> {
+        let id = get_attribute_id(&self.attribute, attributes)?;
+        Ok(SetBorder::new(id, self.thickness, self.value))
+    }
+}
+
+impl SetBorder {
+    pub fn convert(&self, attributes: &[String]) -> SetBorderData {
+        let attribute = attributes[self.attribute_id].clone();
+        SetBorderData::new(attribute, self.thickness, self.value)
+    }
+}
@@ -0,0 +1,116 @@
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
+use crate::data::map::Map2d;
+use crate::data::math::size2d::Size2d;
+use serde::{Deserialize, Serialize};
+
+/// Sets an [`Attribute`] to the slope of another one, e.g. to find cliffs.
+///
+/// The slope is the magnitude of the gradient, which uses central differences.
+/// Cells at the edge of the map use one-sided differences instead.
+#[derive(new, Debug, Clone)]
+pub struct SlopeStep {
+    source_id: usize,
+    target_id: usize,
+}
+
+impl SlopeStep {
+    fn calculate_values(&self, size: Size2d, heights: &[u8]) -> Vec<u8> {
+        let mut values = Vec::with_capacity(size.get_area());
+        let height = |x: u32, y: u32| heights[size.to_index_risky(x, y)] as f32;
+
+        for y in 0..size.height() {
+            for x in 0..size.width() {
+                let gradient_x = calculate_difference(x, size.width(), |x| height(x, y));
+                let gradient_y = calculate_difference(y, size.height(), |y| height(x, y));
+                let slope = (gradient_x * gradient_x + gradient_y * gradient_y).sqrt();
+
+                values.push(slope.round().min(255.0) as u8);
+            }
+        }
+
+        values
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::slope::SlopeStep;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(6, 1));
+    /// let source_id = map.create_attribute_from("elevation", vec![10, 10, 10, 210, 210, 210]).unwrap();
+    /// let target_id = map.create_attribute("slope", 0).unwrap();
+    /// let step = SlopeStep::new(source_id, target_id);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(map.get_attribute(target_id).get_all(), &vec![0u8, 0, 100, 100, 0, 0]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        log_info!(
+            "Set attribute '{}' of map '{}' to the slope of attribute '{}'",
+            map.get_attribute(self.target_id).get_name(),
+            map.get_name(),
+            map.get_attribute(self.source_id).get_name(),
+        );
+
+        let source = map.get_attribute(self.source_id);
+        let values = self.calculate_values(map.size, source.get_all());
+        let attribute = map.get_attribute_mut(self.target_id);
+
+        attribute.replace_all(values);
+    }
+}
+
+/// Returns the difference along an axis of the given length.
+pub(crate) fn calculate_difference<F: Fn(u32) -> f32>(
+    position: u32,
+    length: u32,
+    height: F,
+) -> f32 {
+    if length < 2 {
+        0.0
+    } else if position == 0 {
+        height(1) - height(0)
+    } else if position == length - 1 {
+        height(position) - height(position - 1)
+    } else {
+        (height(position + 1) - height(position - 1)) / 2.0
+    }
+}
+
+/// For serializing, deserializing & validating [`SlopeStep`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::slope::{SlopeStepData, SlopeStep};
+/// let data = SlopeStepData::new("s".to_string(), "t".to_string());
+/// let attributes = vec!["s".to_string(), "t".to_string()];
+/// let step: SlopeStep = data.clone().try_convert(&attributes).unwrap();
+/// let result: SlopeStepData = step.convert(&attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct SlopeStepData {
+    source: String,
+    target: String,
+}
+
+impl SlopeStepData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(vec![self.source.clone()], vec![self.target.clone()])
+    }
+
+    pub fn try_convert(self, attributes: &[String]) -> Result<SlopeStep, GenerationStepError> {
+        let source_id = get_attribute_id(&self.source, attributes)?;
+        let target_id = get_attribute_id(&self.target, attributes)?;
+        Ok(SlopeStep::new(source_id, target_id))
+    }
+}
+
+impl SlopeStep {
+    pub fn convert(&self, attributes: &[String]) -> SlopeStepData {
+        let source = attributes[self.source_id].clone();
+        let target = attributes[self.target_id].clone();
+        SlopeStepData::new(source, target)
+    }
+}
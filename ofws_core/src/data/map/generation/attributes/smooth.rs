@@ -0,0 +1,112 @@
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
+use crate::data::map::Map2d;
+use crate::data::math::size2d::Size2d;
+use serde::{Deserialize, Serialize};
+
+/// Smooths an [`Attribute`] with a box blur.
+///
+/// Each cell is replaced by the average of its `(2 * radius + 1)^2` neighborhood.
+/// Only cells inside the map are part of the neighborhood.
+#[derive(new, Debug, Clone)]
+pub struct SmoothStep {
+    attribute_id: usize,
+    radius: u32,
+}
+
+impl SmoothStep {
+    fn calculate_values(&self, size: Size2d, values: &[u8]) -> Vec<u8> {
+        let mut result = Vec::with_capacity(values.len());
+
+        for y in 0..size.height() {
+            let start_y = y.saturating_sub(self.radius);
+            let end_y = y.saturating_add(self.radius).min(size.height() - 1);
+
+            for x in 0..size.width() {
+                let start_x = x.saturating_sub(self.radius);
+                let end_x = x.saturating_add(self.radius).min(size.width() - 1);
+                let mut sum = 0u64;
+                let mut count = 0u64;
+
+                for neighbor_y in start_y..=end_y {
+                    for neighbor_x in start_x..=end_x {
+                        sum += values[size.to_index_risky(neighbor_x, neighbor_y)] as u64;
+                        count += 1;
+                    }
+                }
+
+                result.push((sum / count) as u8);
+            }
+        }
+
+        result
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::smooth::SmoothStep;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(3, 3));
+    /// let values = vec![0, 0, 0, 0, 90, 0, 0, 0, 0];
+    /// let attribute_id = map.create_attribute_from("elevation", values).unwrap();
+    /// let other_id = map.create_attribute("other", 42).unwrap();
+    /// let step = SmoothStep::new(attribute_id, 1);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(
+    ///     map.get_attribute(attribute_id).get_all(),
+    ///     &vec![22u8, 15, 22, 15, 10, 15, 22, 15, 22]
+    /// );
+    /// assert_eq!(map.get_attribute(other_id).get_all(), &vec![42u8; 9]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        log_info!(
+            "Smooth attribute '{}' of map '{}' with radius {}",
+            map.get_attribute(self.attribute_id).get_name(),
+            map.get_name(),
+            self.radius,
+        );
+
+        let size = map.size;
+        let attribute = map.get_attribute_mut(self.attribute_id);
+        let values = self.calculate_values(size, attribute.get_all());
+
+        attribute.replace_all(values);
+    }
+}
+
+/// For serializing, deserializing & validating [`SmoothStep`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::smooth::{SmoothStepData, SmoothStep};
+/// let data = SmoothStepData::new("attribute".to_string(), 2);
+/// let attributes = vec!["attribute".to_string()];
+/// let step: SmoothStep = data.clone().try_convert(&attributes).unwrap();
+/// let result: SmoothStepData = step.convert(&attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct SmoothStepData {
+    attribute: String,
+    radius: u32,
+}
+
+impl SmoothStepData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(vec![self.attribute.clone()], vec![self.attribute.clone()])
+    }
+
+    pub fn try_convert(self, attributes: &[String]) -> Result<SmoothStep, GenerationStepError> {
+        let id = get_attribute_id(&self.attribute, attributes)?;
+        Ok(SmoothStep::new(id, self.radius))
+    }
+}
+
+impl SmoothStep {
+    pub fn convert(&self, attributes: &[String]) -> SmoothStepData {
+        let attribute = attributes[self.attribute_id].clone();
+        SmoothStepData::new(attribute, self.radius)
+    }
+}
@@ -0,0 +1,111 @@
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
+use crate::data::map::Map2d;
+use serde::{Deserialize, Serialize};
+
+/// Replaces each value of an [`Attribute`] with the nearest allowed value.
+#[derive(Debug, Clone)]
+pub struct SnapToValuesStep {
+    attribute_id: usize,
+    allowed: Vec<u8>,
+}
+
+impl SnapToValuesStep {
+    /// Returns the step, if at least 1 value is allowed.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::generation::attributes::snap::SnapToValuesStep;
+    ///# use ofws_core::data::map::generation::step::GenerationStepError;
+    /// assert_eq!(SnapToValuesStep::new(0, vec![]).unwrap_err(), GenerationStepError::NoAllowedValues);
+    /// ```
+    pub fn new(
+        attribute_id: usize,
+        allowed: Vec<u8>,
+    ) -> Result<SnapToValuesStep, GenerationStepError> {
+        if allowed.is_empty() {
+            return Err(GenerationStepError::NoAllowedValues);
+        }
+
+        Ok(SnapToValuesStep {
+            attribute_id,
+            allowed,
+        })
+    }
+
+    /// Returns the nearest allowed value. Ties are rounded down.
+    fn snap(&self, value: u8) -> u8 {
+        *self
+            .allowed
+            .iter()
+            .min_by_key(|allowed| ((**allowed as i32 - value as i32).abs(), **allowed))
+            .unwrap()
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::snap::SnapToValuesStep;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(5, 1));
+    /// let attribute_id = map.create_attribute_from("elevation", vec![40, 50, 60, 200, 255]).unwrap();
+    /// let step = SnapToValuesStep::new(attribute_id, vec![0, 100, 255]).unwrap();
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(map.get_attribute(attribute_id).get_all(), &vec![0u8, 0, 100, 255, 255]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        log_info!(
+            "Snap attribute '{}' of map '{}' to {:?}",
+            map.get_attribute(self.attribute_id).get_name(),
+            map.get_name(),
+            self.allowed,
+        );
+
+        let attribute = map.get_attribute_mut(self.attribute_id);
+        let values = attribute
+            .get_all()
+            .iter()
+            .map(|value| self.snap(*value))
+            .collect();
+
+        attribute.replace_all(values);
+    }
+}
+
+/// For serializing, deserializing & validating [`SnapToValuesStep`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::snap::{SnapToValuesStepData, SnapToValuesStep};
+/// let data = SnapToValuesStepData::new("attribute".to_string(), vec![10, 20, 30]);
+/// let attributes = vec!["attribute".to_string()];
+/// let step: SnapToValuesStep = data.clone().try_convert(&attributes).unwrap();
+/// let result: SnapToValuesStepData = step.convert(&attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct SnapToValuesStepData {
+    attribute: String,
+    allowed: Vec<u8>,
+}
+
+impl SnapToValuesStepData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(vec![self.attribute.clone()], vec![self.attribute.clone()])
+    }
+
+    pub fn try_convert(
+        self,
+        attributes: &[String],
+    ) -> Result<SnapToValuesStep, GenerationStepError> {
+        let id = get_attribute_id(&self.attribute, attributes)?;
+        SnapToValuesStep::new(id, self.allowed)
+    }
+}
+
+impl SnapToValuesStep {
+    pub fn convert(&self, attributes: &[String]) -> SnapToValuesStepData {
+        let attribute = attributes[self.attribute_id].clone();
+        SnapToValuesStepData::new(attribute, self.allowed.clone())
+    }
+}
@@ -1,9 +1,117 @@
-use crate::data::map::generation::step::{get_attribute_id, GenerationStepError};
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
 use crate::data::map::Map2d;
+use crate::data::math::transformer::clusterer3d::{Clusterer3d, Clusterer3dData};
+use crate::data::math::transformer::transformer1d::{Transformer1d, Transformer1dData};
 use crate::data::math::transformer::transformer2d::{Transformer2d, Transformer2dData};
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::convert::TryInto;
 
+/// Transforms an [`Attribute`] and writes into another.
+#[derive(new)]
+pub struct TransformAttribute1d {
+    name: String,
+    source_id: usize,
+    target_id: usize,
+    transformer: Transformer1d,
+}
+
+impl TransformAttribute1d {
+    // Runs the step.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::transformer::TransformAttribute1d;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    ///# use ofws_core::data::math::transformer::transformer1d::Transformer1d;
+    /// let mut map = Map2d::new(Size2d::new(3, 2));
+    /// map.create_attribute_from("input", vec![0, 1, 99, 100, 101, 255]);
+    /// map.create_attribute("target", 10);
+    /// let transformer = Transformer1d::new_overwrite_if_below(42, 100);
+    /// let step = TransformAttribute1d::new("name".to_string(), 0, 1, transformer);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(map.get_attribute(0).get_all(), &vec![ 0,  1, 99, 100, 101, 255]);
+    /// assert_eq!(map.get_attribute(1).get_all(), &vec![42, 42, 42,  42, 101, 255]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        log_info!(
+            "Apply transformation '{}' using '{}' to '{}' of map '{}'",
+            self.name,
+            map.get_attribute(self.source_id).get_name(),
+            map.get_attribute(self.target_id).get_name(),
+            map.get_name()
+        );
+
+        let values = map
+            .get_attribute(self.source_id)
+            .get_all()
+            .iter()
+            .map(|value| self.transformer.get(*value))
+            .collect();
+        let attribute = map.get_attribute_mut(self.target_id);
+
+        attribute.replace_all(values);
+    }
+}
+
+/// For serializing, deserializing & validating [`TransformAttribute1d`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::transformer::{TransformAttribute1dData, TransformAttribute1d};
+///# use ofws_core::data::math::transformer::transformer1d::Transformer1dData;
+/// let transformer = Transformer1dData::Const(99);
+/// let data = TransformAttribute1dData::new("name".to_string(), "s".to_string(), "t".to_string(), transformer);
+/// let attributes = vec!["s".to_string(), "t".to_string()];
+/// let step: TransformAttribute1d = data.clone().try_convert(&attributes).unwrap();
+/// let result: TransformAttribute1dData = step.convert(&attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(new, Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct TransformAttribute1dData {
+    name: String,
+    source: String,
+    target: String,
+    transformer: Transformer1dData,
+}
+
+impl TransformAttribute1dData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(vec![self.source.clone()], vec![self.target.clone()])
+    }
+
+    pub fn try_convert(
+        self,
+        attributes: &[String],
+    ) -> Result<TransformAttribute1d, GenerationStepError> {
+        let source_id = get_attribute_id(&self.source, attributes)?;
+        let target_id = get_attribute_id(&self.target, attributes)?;
+        let transformer: Transformer1d = self.transformer.try_into()?;
+
+        Ok(TransformAttribute1d::new(
+            self.name,
+            source_id,
+            target_id,
+            transformer,
+        ))
+    }
+}
+
+impl TransformAttribute1d {
+    pub fn convert(&self, attributes: &[String]) -> TransformAttribute1dData {
+        let source = attributes[self.source_id].clone();
+        let target = attributes[self.target_id].clone();
+        TransformAttribute1dData::new(
+            self.name.clone(),
+            source,
+            target,
+            (&self.transformer).into(),
+        )
+    }
+}
+
 /// Transforms 2 [`Attribute`]s and writes into another.
 #[derive(new)]
 pub struct TransformAttribute2d {
@@ -15,7 +123,30 @@ pub struct TransformAttribute2d {
 }
 
 impl TransformAttribute2d {
+    #[cfg(feature = "parallel")]
     fn transform(&self, map: &mut Map2d) -> Vec<u8> {
+        self.transform_parallel(map)
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    fn transform(&self, map: &mut Map2d) -> Vec<u8> {
+        self.transform_serial(map)
+    }
+
+    /// Transforms the values of all cells in parallel.
+    #[cfg(feature = "parallel")]
+    fn transform_parallel(&self, map: &Map2d) -> Vec<u8> {
+        let values0 = map.get_attribute(self.source_id0).get_all();
+        let values1 = map.get_attribute(self.source_id1).get_all();
+
+        (0..map.size.get_area())
+            .into_par_iter()
+            .map(|index| self.transformer.transform(values0[index], values1[index]))
+            .collect()
+    }
+
+    #[cfg_attr(all(feature = "parallel", not(test)), allow(dead_code))]
+    fn transform_serial(&self, map: &Map2d) -> Vec<u8> {
         let size = map.size;
         let source_attribute0 = map.get_attribute(self.source_id0);
         let source_attribute1 = map.get_attribute(self.source_id1);
@@ -51,7 +182,7 @@ impl TransformAttribute2d {
     /// assert_eq!(map.get_attribute(2).get_all(), &vec![ 42,  42,  42,  42, 196, 195]);
     /// ```
     pub fn run(&self, map: &mut Map2d) {
-        info!(
+        log_info!(
             "Apply transformation '{}' using '{}' & '{}' to '{}' of map '{}'",
             self.name,
             map.get_attribute(self.source_id0).get_name(),
@@ -79,7 +210,7 @@ impl TransformAttribute2d {
 /// let result: TransformAttribute2dData = step.convert(&attributes);
 /// assert_eq!(data, result)
 ///```
-#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[derive(new, Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct TransformAttribute2dData {
     name: String,
     source0: String,
@@ -89,6 +220,25 @@ pub struct TransformAttribute2dData {
 }
 
 impl TransformAttribute2dData {
+    /// Returns the [`Attribute`]s read & written by the step.
+    ///
+    ///```
+    ///# use ofws_core::data::map::generation::attributes::transformer::TransformAttribute2dData;
+    ///# use ofws_core::data::math::transformer::transformer2d::Transformer2dData;
+    /// let transformer = Transformer2dData::Const(99);
+    /// let data = TransformAttribute2dData::new("name".to_string(), "s0".to_string(), "s1".to_string(), "t".to_string(), transformer);
+    /// let dependencies = data.dependencies();
+    ///
+    /// assert_eq!(dependencies.reads, vec!["s0".to_string(), "s1".to_string()]);
+    /// assert_eq!(dependencies.writes, vec!["t".to_string()]);
+    ///```
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(
+            vec![self.source0.clone(), self.source1.clone()],
+            vec![self.target.clone()],
+        )
+    }
+
     pub fn try_convert(
         self,
         attributes: &[String],
@@ -122,3 +272,170 @@ impl TransformAttribute2d {
         )
     }
 }
+
+/// Clusters 3 [`Attribute`]s and writes into another. E.g. biome from elevation, rainfall & temperature.
+#[derive(new)]
+pub struct TransformAttribute3d {
+    name: String,
+    source_id0: usize,
+    source_id1: usize,
+    source_id2: usize,
+    target_id: usize,
+    clusterer: Clusterer3d,
+}
+
+impl TransformAttribute3d {
+    fn transform(&self, map: &mut Map2d) -> Vec<u8> {
+        let size = map.size;
+        let source_attribute0 = map.get_attribute(self.source_id0);
+        let source_attribute1 = map.get_attribute(self.source_id1);
+        let source_attribute2 = map.get_attribute(self.source_id2);
+        let mut biomes = Vec::with_capacity(size.get_area());
+
+        for index in 0..size.get_area() {
+            let value0 = source_attribute0.get(index);
+            let value1 = source_attribute1.get(index);
+            let value2 = source_attribute2.get(index);
+            biomes.push(self.clusterer.cluster(value0, value1, value2));
+        }
+
+        biomes
+    }
+
+    // Runs the step.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::transformer::TransformAttribute3d;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    ///# use ofws_core::data::math::transformer::clusterer3d::Clusterer3d;
+    /// let mut map = Map2d::new(Size2d::new(2, 2));
+    /// map.create_attribute_from("input0", vec![0, 200,   0, 255]);
+    /// map.create_attribute_from("input1", vec![0,   0, 200, 255]);
+    /// map.create_attribute_from("input2", vec![0,   0, 100, 255]);
+    /// map.create_attribute("target", 0);
+    /// let clusterer = Clusterer3d::new(2, 2, 2, vec![10, 20, 30, 40, 50, 60, 70, 80]).unwrap();
+    /// let step = TransformAttribute3d::new("name".to_string(), 0, 1, 2, 3, clusterer);
+    ///
+    /// step.run(&mut map);
+    ///
+    /// assert_eq!(map.get_attribute(3).get_all(), &vec![10, 20, 30, 80]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        log_info!(
+            "Apply transformation '{}' using '{}', '{}' & '{}' to '{}' of map '{}'",
+            self.name,
+            map.get_attribute(self.source_id0).get_name(),
+            map.get_attribute(self.source_id1).get_name(),
+            map.get_attribute(self.source_id2).get_name(),
+            map.get_attribute(self.target_id).get_name(),
+            map.get_name()
+        );
+
+        let biomes = self.transform(map);
+        let attribute = map.get_attribute_mut(self.target_id);
+
+        attribute.replace_all(biomes);
+    }
+}
+
+/// For serializing, deserializing & validating [`TransformAttribute3d`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::transformer::{TransformAttribute3dData, TransformAttribute3d};
+///# use ofws_core::data::math::transformer::clusterer3d::Clusterer3dData;
+/// let clusterer = Clusterer3dData::new(1, 1, 2, vec![3, 4]);
+/// let data = TransformAttribute3dData::new("name".to_string(), "s0".to_string(), "s1".to_string(), "s2".to_string(), "t".to_string(), clusterer);
+/// let attributes = vec!["s0".to_string(), "s1".to_string(), "s2".to_string(), "t".to_string()];
+/// let step: TransformAttribute3d = data.clone().try_convert(&attributes).unwrap();
+/// let result: TransformAttribute3dData = step.convert(&attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct TransformAttribute3dData {
+    name: String,
+    source0: String,
+    source1: String,
+    source2: String,
+    target: String,
+    clusterer: Clusterer3dData,
+}
+
+impl TransformAttribute3dData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(
+            vec![
+                self.source0.clone(),
+                self.source1.clone(),
+                self.source2.clone(),
+            ],
+            vec![self.target.clone()],
+        )
+    }
+
+    pub fn try_convert(
+        self,
+        attributes: &[String],
+    ) -> Result<TransformAttribute3d, GenerationStepError> {
+        let source_id0 = get_attribute_id(&self.source0, attributes)?;
+        let source_id1 = get_attribute_id(&self.source1, attributes)?;
+        let source_id2 = get_attribute_id(&self.source2, attributes)?;
+        let target_id = get_attribute_id(&self.target, attributes)?;
+        let clusterer: Clusterer3d = self.clusterer.try_into()?;
+
+        Ok(TransformAttribute3d::new(
+            self.name, source_id0, source_id1, source_id2, target_id, clusterer,
+        ))
+    }
+}
+
+impl TransformAttribute3d {
+    pub fn convert(&self, attributes: &[String]) -> TransformAttribute3dData {
+        let source0 = attributes[self.source_id0].clone();
+        let source1 = attributes[self.source_id1].clone();
+        let source2 = attributes[self.source_id2].clone();
+        let target = attributes[self.target_id].clone();
+        TransformAttribute3dData::new(
+            self.name.clone(),
+            source0,
+            source1,
+            source2,
+            target,
+            (&self.clusterer).into(),
+        )
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "parallel")]
+mod tests {
+    use super::*;
+    use crate::data::math::size2d::Size2d;
+    use crate::data::math::transformer::clusterer2d::Clusterer2d;
+
+    fn assert_sync<T: Sync>() {}
+
+    #[test]
+    fn test_transformer_is_sync() {
+        assert_sync::<Clusterer2d>();
+        assert_sync::<Transformer2d>();
+    }
+
+    #[test]
+    fn test_parallel_equals_serial() {
+        let size = Size2d::new(64, 48);
+        let area = size.get_area();
+        let mut map = Map2d::new(size);
+        map.create_attribute_from("rainfall", (0..area).map(|i| (i * 7 % 256) as u8).collect());
+        map.create_attribute_from(
+            "temperature",
+            (0..area).map(|i| (i * 13 % 256) as u8).collect(),
+        );
+        map.create_attribute("biome", 0);
+        let clusterer = Clusterer2d::new(Size2d::new(3, 2), vec![10, 20, 30, 40, 50, 60]).unwrap();
+        let transformer = Transformer2d::Clusterer(clusterer);
+        let step = TransformAttribute2d::new("biome".to_string(), 0, 1, 2, transformer);
+
+        assert_eq!(step.transform_parallel(&map), step.transform_serial(&map));
+    }
+}
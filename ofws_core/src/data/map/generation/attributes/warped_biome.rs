@@ -0,0 +1,155 @@
+use crate::data::map::generation::step::{get_attribute_id, GenerationStepError, StepDependencies};
+use crate::data::map::Map2d;
+use crate::data::math::generator::generator2d::{Generator2d, Generator2dData};
+use crate::data::math::transformer::clusterer2d::{Clusterer2d, Clusterer2dData};
+use serde::{Deserialize, Serialize};
+use std::convert::TryInto;
+
+/// Clusters 2 [`Attribute`]s into biomes, but warps both inputs first.
+///
+/// The warp [`Generator2d`] returns 128 for no change.
+/// Other values nudge both inputs up or down, scaled by the strength.
+/// This creates irregular borders between the biomes.
+#[derive(new)]
+pub struct WarpedBiomeStep {
+    source_id0: usize,
+    source_id1: usize,
+    target_id: usize,
+    clusterer: Clusterer2d,
+    warp: Generator2d,
+    strength: u8,
+}
+
+impl WarpedBiomeStep {
+    fn calculate_biomes(&self, map: &Map2d) -> Vec<u8> {
+        let size = map.size;
+        let source_attribute0 = map.get_attribute(self.source_id0);
+        let source_attribute1 = map.get_attribute(self.source_id1);
+        let mut biomes = Vec::with_capacity(size.get_area());
+
+        for index in 0..size.get_area() {
+            let [x, y] = size.to_x_and_y(index);
+            let offset = (self.warp.generate(x, y) as i32 - 128) * self.strength as i32 / 128;
+            let value0 = warp_value(source_attribute0.get(index), offset);
+            let value1 = warp_value(source_attribute1.get(index), offset);
+            biomes.push(self.clusterer.cluster(value0, value1));
+        }
+
+        biomes
+    }
+
+    /// Runs the step.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::attributes::warped_biome::WarpedBiomeStep;
+    ///# use ofws_core::data::math::generator::generator1d::Generator1d;
+    ///# use ofws_core::data::math::generator::generator2d::Generator2d;
+    ///# use ofws_core::data::math::generator::gradient::Gradient;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    ///# use ofws_core::data::math::transformer::clusterer2d::Clusterer2d;
+    /// let mut map = Map2d::new(Size2d::new(4, 2));
+    /// map.create_attribute_from("input0", vec![100, 120, 140, 160, 100, 120, 140, 160]);
+    /// map.create_attribute("input1", 0);
+    /// map.create_attribute("plain", 0);
+    /// map.create_attribute("warped", 0);
+    /// let clusterer = Clusterer2d::new(Size2d::new(2, 1), vec![1, 2]).unwrap();
+    /// let warp = || Generator2d::new_apply_to_y(Generator1d::Gradient(Gradient::new(128, 255, 0, 1)));
+    /// let plain = WarpedBiomeStep::new(0, 1, 2, clusterer.clone(), warp(), 0);
+    /// let warped = WarpedBiomeStep::new(0, 1, 3, clusterer, warp(), 40);
+    ///
+    /// plain.run(&mut map);
+    /// warped.run(&mut map);
+    ///
+    /// assert_eq!(map.get_attribute(2).get_all(), &vec![1u8, 1, 2, 2, 1, 1, 2, 2]);
+    /// assert_eq!(map.get_attribute(3).get_all(), &vec![1u8, 1, 2, 2, 2, 2, 2, 2]);
+    /// ```
+    pub fn run(&self, map: &mut Map2d) {
+        log_info!(
+            "Cluster warped attributes '{}' & '{}' into attribute '{}' of map '{}'",
+            map.get_attribute(self.source_id0).get_name(),
+            map.get_attribute(self.source_id1).get_name(),
+            map.get_attribute(self.target_id).get_name(),
+            map.get_name()
+        );
+
+        let biomes = self.calculate_biomes(map);
+        let attribute = map.get_attribute_mut(self.target_id);
+
+        attribute.replace_all(biomes);
+    }
+}
+
+fn warp_value(value: u8, offset: i32) -> u8 {
+    (value as i32 + offset).clamp(0, 255) as u8
+}
+
+/// For serializing, deserializing & validating [`WarpedBiomeStep`].
+///
+///```
+///# use ofws_core::data::map::generation::attributes::warped_biome::{WarpedBiomeStepData, WarpedBiomeStep};
+///# use ofws_core::data::math::generator::generator2d::Generator2dData::IndexGenerator;
+///# use ofws_core::data::math::size2d::Size2d;
+///# use ofws_core::data::math::transformer::clusterer2d::Clusterer2dData;
+/// let clusterer = Clusterer2dData::new(Size2d::new(2, 1), vec![1, 2]);
+/// let warp = IndexGenerator(Size2d::new(3, 4));
+/// let data = WarpedBiomeStepData::new("s0".to_string(), "s1".to_string(), "t".to_string(), clusterer, warp, 20);
+/// let attributes = vec!["s0".to_string(), "s1".to_string(), "t".to_string()];
+/// let step: WarpedBiomeStep = data.clone().try_convert(&attributes).unwrap();
+/// let result: WarpedBiomeStepData = step.convert(&attributes);
+/// assert_eq!(data, result)
+///```
+#[derive(new, Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct WarpedBiomeStepData {
+    source0: String,
+    source1: String,
+    target: String,
+    clusterer: Clusterer2dData,
+    warp: Generator2dData,
+    strength: u8,
+}
+
+impl WarpedBiomeStepData {
+    pub fn dependencies(&self) -> StepDependencies {
+        StepDependencies::new(
+            vec![self.source0.clone(), self.source1.clone()],
+            vec![self.target.clone()],
+        )
+    }
+
+    pub fn try_convert(
+        self,
+        attributes: &[String],
+    ) -> Result<WarpedBiomeStep, GenerationStepError> {
+        let source_id0 = get_attribute_id(&self.source0, attributes)?;
+        let source_id1 = get_attribute_id(&self.source1, attributes)?;
+        let target_id = get_attribute_id(&self.target, attributes)?;
+        let clusterer: Clusterer2d = self.clusterer.try_into()?;
+        let warp: Generator2d = self.warp.try_into()?;
+
+        Ok(WarpedBiomeStep::new(
+            source_id0,
+            source_id1,
+            target_id,
+            clusterer,
+            warp,
+            self.strength,
+        ))
+    }
+}
+
+impl WarpedBiomeStep {
+    pub fn convert(&self, attributes: &[String]) -> WarpedBiomeStepData {
+        let source0 = attributes[self.source_id0].clone();
+        let source1 = attributes[self.source_id1].clone();
+        let target = attributes[self.target_id].clone();
+        WarpedBiomeStepData::new(
+            source0,
+            source1,
+            target,
+            (&self.clusterer).into(),
+            (&self.warp).into(),
+            self.strength,
+        )
+    }
+}
@@ -3,18 +3,23 @@ use std::convert::TryInto;
 use std::fs;
 use std::fs::File;
 use std::io::Write;
+use std::path::Path;
 
+/// Reads a [`MapGeneration`] from a yaml file.
 pub fn read_map_generator(path: &str) -> Result<MapGeneration, MapGenerationError> {
     let string = fs::read_to_string(path)?;
     let data: MapGenerationData = serde_yaml::from_str(&string)?;
     data.try_into()
 }
 
+/// Writes a [`MapGeneration`] as a yaml file, which can be read with [`read_map_generator`].
+///
+/// Missing parent directories are created.
 pub fn write_map_generator(
     map_generator: &MapGeneration,
     path: &str,
 ) -> Result<(), MapGenerationError> {
-    let mut file = File::create(path)?;
+    let mut file = create_file(path)?;
 
     let data: MapGenerationData = map_generator.into();
     let s = serde_yaml::to_string(&data)?;
@@ -23,3 +28,101 @@ pub fn write_map_generator(
 
     Ok(())
 }
+
+/// Reads a [`MapGeneration`] from a json file.
+pub fn read_map_generator_json(path: &str) -> Result<MapGeneration, MapGenerationError> {
+    let string = fs::read_to_string(path)?;
+    let data: MapGenerationData = serde_json::from_str(&string)?;
+    data.try_into()
+}
+
+/// Writes a [`MapGeneration`] as a json file, which can be read with [`read_map_generator_json`].
+///
+/// Missing parent directories are created.
+pub fn write_map_generator_json(
+    map_generator: &MapGeneration,
+    path: &str,
+) -> Result<(), MapGenerationError> {
+    let mut file = create_file(path)?;
+
+    let data: MapGenerationData = map_generator.into();
+    let s = serde_json::to_string_pretty(&data)?;
+
+    file.write_all(s.as_bytes())?;
+
+    Ok(())
+}
+
+fn create_file(path: &str) -> std::io::Result<File> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    File::create(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::map::generation::attributes::create::CreateAttribute;
+    use crate::data::map::generation::attributes::modify::ModifyWithAttributeData;
+    use crate::data::map::generation::step::GenerationStepData;
+    use crate::data::math::size2d::Size2d;
+
+    fn create_data() -> MapGenerationData {
+        let step0 = GenerationStepData::CreateAttribute(CreateAttribute::new("a0", 42));
+        let step1 = GenerationStepData::CreateAttribute(CreateAttribute::new("a1", 200));
+        let modify = ModifyWithAttributeData::new("a0".to_string(), "a1".to_string(), 100, 10);
+        let step2 = GenerationStepData::ModifyWithAttribute(modify);
+        MapGenerationData::new(
+            "map".to_string(),
+            Size2d::new(4, 5),
+            vec![step0, step1, step2],
+        )
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let path = std::env::temp_dir().join("ofws_core_test_json_round_trip.json");
+        let path = path.to_str().unwrap();
+        let data = create_data();
+        let generation: MapGeneration = data.clone().try_into().unwrap();
+
+        write_map_generator_json(&generation, path).unwrap();
+        let result: MapGenerationData = (&read_map_generator_json(path).unwrap()).into();
+
+        assert_eq!(result, data);
+
+        fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn test_yaml_round_trip() {
+        let dir = std::env::temp_dir().join("ofws_core_test_yaml_round_trip");
+        let _ = fs::remove_dir_all(&dir);
+        let path = dir.join("generation").join("map.yaml");
+        let path = path.to_str().unwrap();
+        let data = create_data();
+        let generation: MapGeneration = data.clone().try_into().unwrap();
+
+        write_map_generator(&generation, path).unwrap();
+        let result: MapGenerationData = (&read_map_generator(path).unwrap()).into();
+
+        assert_eq!(result, data);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_write_into_missing_directory() {
+        let dir = std::env::temp_dir().join("ofws_core_test_write_into_missing_directory");
+        let _ = fs::remove_dir_all(&dir);
+        let path = dir.join("a").join("b").join("map.json");
+        let file = create_file(path.to_str().unwrap());
+
+        assert!(file.is_ok());
+        assert!(path.exists());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
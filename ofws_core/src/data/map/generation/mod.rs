@@ -1,9 +1,12 @@
 use std::convert::TryFrom;
+use std::fs;
 use std::ops::Sub;
+use std::path::Path;
 
 use serde::{Deserialize, Serialize};
 
 use crate::data::map::generation::step::{GenerationStep, GenerationStepData, GenerationStepError};
+use crate::data::map::io::write_attribute_png;
 use crate::data::map::Map2d;
 use crate::data::math::size2d::Size2d;
 
@@ -14,16 +17,30 @@ pub mod step;
 #[derive(Debug)]
 pub enum MapGenerationError {
     GenerationStep(usize, GenerationStepError),
+    ImageError(image::ImageError),
     IoError(std::io::Error),
+    JsonError(serde_json::Error),
     SerdeError(serde_yaml::Error),
 }
 
+impl From<image::ImageError> for MapGenerationError {
+    fn from(error: image::ImageError) -> Self {
+        MapGenerationError::ImageError(error)
+    }
+}
+
 impl From<std::io::Error> for MapGenerationError {
     fn from(error: std::io::Error) -> Self {
         MapGenerationError::IoError(error)
     }
 }
 
+impl From<serde_json::Error> for MapGenerationError {
+    fn from(error: serde_json::Error) -> Self {
+        MapGenerationError::JsonError(error)
+    }
+}
+
 impl From<serde_yaml::Error> for MapGenerationError {
     fn from(error: serde_yaml::Error) -> Self {
         MapGenerationError::SerdeError(error)
@@ -52,9 +69,111 @@ impl MapGeneration {
 
     /// Generates the map.
     pub fn generate(&self) -> Map2d {
+        let mut map = Map2d::with_name(self.name.clone(), self.size);
+        self.run_steps(&mut map);
+        map
+    }
+
+    /// Generates the map & writes the chosen [`Attribute`] as a numbered png into a directory
+    /// after each step. This helps to find the step, that broke a generation.
+    ///
+    /// Steps before the creation of the [`Attribute`] produce no snapshot.
+    /// The snapshots are the raw values as grayscale images written by [`write_attribute_png`],
+    /// so they don't use a [`CellRenderer`](crate::rendering::cell::CellRenderer)
+    /// like [`render_attribute_to_png`](crate::rendering::export::render_attribute_to_png).
+    ///
+    /// Writing the snapshots isn't included in the logged duration of the steps.
+    pub fn generate_with_snapshots(&self, dir: &str, attribute: &str) -> std::io::Result<Map2d> {
+        let dir = Path::new(dir);
+        fs::create_dir_all(dir)?;
+
+        let mut map = Map2d::with_name(self.name.clone(), self.size);
+        let mut result = Ok(());
+
+        self.run_steps_with(&mut map, |index, map| {
+            if result.is_err() {
+                return;
+            }
+
+            if let Some(id) = map.get_attribute_id(attribute) {
+                let path = dir.join(format!("{:03}.png", index));
+                result = write_attribute_png(map.get_attribute(id), &path);
+            }
+        });
+
+        result.map(|_| map)
+    }
+
+    /// Generates the map into an existing one to reuse its [`Attribute`]s.
+    ///
+    /// The map is replaced, if its name, size or attributes don't match the generation.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::map::generation::MapGeneration;
+    ///# use ofws_core::data::map::generation::attributes::create::CreateAttribute;
+    ///# use ofws_core::data::map::generation::attributes::generator::GeneratorStep;
+    ///# use ofws_core::data::map::generation::step::GenerationStep;
+    ///# use ofws_core::data::math::generator::generator2d::Generator2d;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let size = Size2d::new(2, 3);
+    /// let create0 = GenerationStep::CreateAttribute(CreateAttribute::new("a0", 42));
+    /// let create1 = GenerationStep::CreateAttribute(CreateAttribute::new("a1", 100));
+    /// let generator = GeneratorStep::new("index", 1, Generator2d::IndexGenerator(size));
+    /// let add = GenerationStep::GeneratorAdd(generator);
+    /// let generation = MapGeneration::new("map", size, vec![create0, create1, add]);
+    /// let expected = generation.generate();
+    ///
+    /// let mut map = generation.generate();
+    /// *map.get_attribute_mut(0).get_mut(3) = 0;
+    /// generation.generate_into(&mut map);
+    ///
+    /// assert!(map == expected);
+    ///
+    /// let mut map = Map2d::with_name("map", Size2d::new(4, 5));
+    /// generation.generate_into(&mut map);
+    ///
+    /// assert!(map == expected);
+    /// ```
+    pub fn generate_into(&self, map: &mut Map2d) {
+        if !self.can_reuse(map) {
+            log_debug!("Replace map '{}' instead of reusing it", map.get_name());
+            *map = Map2d::with_name(self.name.clone(), self.size);
+        }
+
+        self.run_steps(map);
+    }
+
+    /// Checks if the map has the same name, size & attributes as a generated one.
+    fn can_reuse(&self, map: &Map2d) -> bool {
+        let attributes: Vec<&str> = self
+            .steps
+            .iter()
+            .filter_map(|step| match step {
+                GenerationStep::CreateAttribute(step) => Some(step.get_attribute()),
+                _ => None,
+            })
+            .collect();
+
+        map.name == self.name
+            && map.size == self.size
+            && map.attributes.len() == attributes.len()
+            && map
+                .attributes
+                .iter()
+                .zip(attributes)
+                .all(|(attribute, name)| attribute.get_name() == name)
+    }
+
+    fn run_steps(&self, map: &mut Map2d) {
+        self.run_steps_with(map, |_, _| {});
+    }
+
+    /// Runs all steps & calls `after_step` with the index of each finished step.
+    fn run_steps_with<F: FnMut(usize, &Map2d)>(&self, map: &mut Map2d, mut after_step: F) {
         let start = std::time::Instant::now();
 
-        info!(
+        log_info!(
             "Generate the map '{}' with {:?} in {} steps:",
             self.name,
             self.size,
@@ -62,22 +181,20 @@ impl MapGeneration {
         );
 
         let mut start_step = start;
-        let mut map = Map2d::with_name(self.name.clone(), self.size);
 
-        self.steps.iter().for_each(|step| {
-            step.run(&mut map);
+        self.steps.iter().enumerate().for_each(|(index, step)| {
+            step.run(map);
             let end_step = std::time::Instant::now();
             let duration = end_step.sub(start_step);
-            debug!("Step took {:?}", duration);
-            start_step = end_step;
+            log_debug!("Step took {:?}", duration);
+            after_step(index, map);
+            start_step = std::time::Instant::now();
         });
 
         let end = std::time::Instant::now();
         let duration = end.sub(start);
 
-        info!("Finished generation of '{}' in {:?}", self.name, duration);
-
-        map
+        log_info!("Finished generation of '{}' in {:?}", self.name, duration);
     }
 }
 
@@ -102,7 +219,7 @@ impl MapGeneration {
 ///
 /// assert_eq!(data, result)
 ///```
-#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[derive(new, Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub struct MapGenerationData {
     name: String,
     size: Size2d,
@@ -138,8 +255,7 @@ impl TryFrom<MapGenerationData> for MapGeneration {
     ///            assert_eq!(step, 1);
     ///            assert_eq!(error, AttributeUnknown("a1".to_string()));
     ///        },
-    ///        MapGenerationError::IoError(_) => panic!("Wrong!"),
-    ///        MapGenerationError::SerdeError(_) => panic!("Wrong!"),
+    ///        _ => panic!("Wrong!"),
     ///    }
     /// }
     ///```
@@ -170,3 +286,37 @@ impl From<&MapGeneration> for MapGenerationData {
         MapGenerationData::new(map_generation.name.clone(), map_generation.size, steps)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::map::generation::attributes::create::CreateAttribute;
+    use crate::data::map::generation::attributes::generator::GeneratorStep;
+    use crate::data::math::generator::generator2d::Generator2d;
+
+    #[test]
+    fn test_generate_with_snapshots() {
+        let dir = std::env::temp_dir().join("ofws_core_test_generate_with_snapshots");
+        let _ = fs::remove_dir_all(&dir);
+        let size = Size2d::new(3, 2);
+        let create = GenerationStep::CreateAttribute(CreateAttribute::new("elevation", 10));
+        let generator = GeneratorStep::new("index", 0, Generator2d::IndexGenerator(size));
+        let add = GenerationStep::GeneratorAdd(generator);
+        let generation = MapGeneration::new("map", size, vec![create, add]);
+
+        let map = generation
+            .generate_with_snapshots(dir.to_str().unwrap(), "elevation")
+            .unwrap();
+
+        let mut files: Vec<String> = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        files.sort();
+
+        assert!(map == generation.generate());
+        assert_eq!(files, vec!["000.png", "001.png"]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,16 +1,69 @@
+use crate::data::map::generation::attributes::arithmetic_scalar::{
+    ArithmeticScalar, ArithmeticScalarData,
+};
+use crate::data::map::generation::attributes::cellular_automata::{
+    CellularAutomataStep, CellularAutomataStepData,
+};
+use crate::data::map::generation::attributes::clamp::{ClampStep, ClampStepData};
+use crate::data::map::generation::attributes::coastal_erosion::{
+    CoastalErosionStep, CoastalErosionStepData,
+};
+use crate::data::map::generation::attributes::combine::{CombineAttributes, CombineAttributesData};
+use crate::data::map::generation::attributes::copy::{CopyAttributeStep, CopyAttributeStepData};
 use crate::data::map::generation::attributes::create::CreateAttribute;
+use crate::data::map::generation::attributes::distance_transform::{
+    DistanceTransformStep, DistanceTransformStepData,
+};
 use crate::data::map::generation::attributes::distortion1d::{Distortion1d, Distortion1dData};
 use crate::data::map::generation::attributes::distortion2d::{Distortion2d, Distortion2dData};
+use crate::data::map::generation::attributes::edge_distance::{
+    EdgeDistanceStep, EdgeDistanceStepData,
+};
+use crate::data::map::generation::attributes::erosion::{ErosionStep, ErosionStepData};
+use crate::data::map::generation::attributes::fault_lines::{FaultLinesStep, FaultLinesStepData};
+use crate::data::map::generation::attributes::fill_rect::{FillRectStep, FillRectStepData};
+use crate::data::map::generation::attributes::fill_sinks::{FillSinksStep, FillSinksStepData};
 use crate::data::map::generation::attributes::generator::{GeneratorStep, GeneratorStepData};
+use crate::data::map::generation::attributes::generator_in_circle::{
+    GeneratorInCircleStep, GeneratorInCircleStepData,
+};
+use crate::data::map::generation::attributes::hillshade::{HillshadeStep, HillshadeStepData};
+use crate::data::map::generation::attributes::island_falloff::{IslandFalloff, IslandFalloffData};
+use crate::data::map::generation::attributes::label_components::{
+    LabelComponentsStep, LabelComponentsStepData,
+};
+use crate::data::map::generation::attributes::masked::{MaskedStep, MaskedStepData};
+use crate::data::map::generation::attributes::maze::{MazeStep, MazeStepData};
 use crate::data::map::generation::attributes::modify::{
     ModifyWithAttribute, ModifyWithAttributeData,
 };
+use crate::data::map::generation::attributes::normalize::{NormalizeStep, NormalizeStepData};
+use crate::data::map::generation::attributes::paste::{PasteAttributeStep, PasteAttributeStepData};
+use crate::data::map::generation::attributes::place_points::{
+    PlacePointsStep, PlacePointsStepData,
+};
+use crate::data::map::generation::attributes::radial_stamp::{
+    RadialStampStep, RadialStampStepData,
+};
+use crate::data::map::generation::attributes::rescale::{RescaleStep, RescaleStepData};
+use crate::data::map::generation::attributes::set_border::{SetBorder, SetBorderData};
+use crate::data::map::generation::attributes::slope::{SlopeStep, SlopeStepData};
+use crate::data::map::generation::attributes::smooth::{SmoothStep, SmoothStepData};
+use crate::data::map::generation::attributes::snap::{SnapToValuesStep, SnapToValuesStepData};
 use crate::data::map::generation::attributes::transformer::{
-    TransformAttribute2d, TransformAttribute2dData,
+    TransformAttribute1d, TransformAttribute1dData, TransformAttribute2d, TransformAttribute2dData,
+    TransformAttribute3d, TransformAttribute3dData,
+};
+use crate::data::map::generation::attributes::warped_biome::{
+    WarpedBiomeStep, WarpedBiomeStepData,
 };
 use crate::data::map::Map2d;
 use crate::data::math::generator::generator1d::Generator1dError;
 use crate::data::math::generator::generator2d::Generator2dError;
+use crate::data::math::operation::CombineOp;
+use crate::data::math::transformer::clusterer2d::Clusterer2dError;
+use crate::data::math::transformer::clusterer3d::Clusterer3dError;
+use crate::data::math::transformer::transformer1d::Transformer1dError;
 use crate::data::math::transformer::transformer2d::Transformer2dError;
 use serde::{Deserialize, Serialize};
 use GenerationStep::*;
@@ -18,11 +71,29 @@ use GenerationStep::*;
 #[derive(Debug, Eq, PartialEq)]
 pub enum GenerationStepError {
     AttributeUnknown(String),
+    Clusterer2d(Clusterer2dError),
+    Clusterer3d(Clusterer3dError),
     Generator1d(Generator1dError),
     Generator2d(Generator2dError),
+    InvalidCombineOp(CombineOp),
+    MinBiggerThanMax(u8, u8),
+    NoAllowedValues,
+    Transformer1d(Transformer1dError),
     Transformer2d(Transformer2dError),
 }
 
+impl From<Clusterer2dError> for GenerationStepError {
+    fn from(error: Clusterer2dError) -> Self {
+        GenerationStepError::Clusterer2d(error)
+    }
+}
+
+impl From<Clusterer3dError> for GenerationStepError {
+    fn from(error: Clusterer3dError) -> Self {
+        GenerationStepError::Clusterer3d(error)
+    }
+}
+
 impl From<Generator1dError> for GenerationStepError {
     fn from(error: Generator1dError) -> Self {
         GenerationStepError::Generator1d(error)
@@ -35,94 +106,343 @@ impl From<Generator2dError> for GenerationStepError {
     }
 }
 
+impl From<Transformer1dError> for GenerationStepError {
+    fn from(error: Transformer1dError) -> Self {
+        GenerationStepError::Transformer1d(error)
+    }
+}
+
 impl From<Transformer2dError> for GenerationStepError {
     fn from(error: Transformer2dError) -> Self {
         GenerationStepError::Transformer2d(error)
     }
 }
 
+/// The names of the [`Attribute`]s a step reads & writes.
+#[derive(new, Debug, Default, PartialEq, Eq, Clone)]
+pub struct StepDependencies {
+    pub reads: Vec<String>,
+    pub writes: Vec<String>,
+}
+
 /// A step during [`MapGeneration`].
 pub enum GenerationStep {
+    ArithmeticScalar(ArithmeticScalar),
+    CellularAutomata(CellularAutomataStep),
+    Clamp(ClampStep),
+    CoastalErosion(CoastalErosionStep),
+    CombineAttributes(CombineAttributes),
+    CopyAttribute(CopyAttributeStep),
     CreateAttribute(CreateAttribute),
+    DistanceTransform(DistanceTransformStep),
     DistortAlongX(Distortion1d),
     DistortAlongY(Distortion1d),
     Distortion2d(Distortion2d),
+    EdgeDistance(EdgeDistanceStep),
+    Erosion(ErosionStep),
+    FaultLines(FaultLinesStep),
+    FillRect(FillRectStep),
+    FillSinks(FillSinksStep),
     GeneratorAdd(GeneratorStep),
+    GeneratorInCircle(GeneratorInCircleStep),
+    GeneratorMax(GeneratorStep),
+    GeneratorMin(GeneratorStep),
+    GeneratorSet(GeneratorStep),
     GeneratorSub(GeneratorStep),
+    Hillshade(HillshadeStep),
+    IslandFalloff(IslandFalloff),
+    LabelComponents(LabelComponentsStep),
+    Masked(MaskedStep),
+    Maze(MazeStep),
     ModifyWithAttribute(ModifyWithAttribute),
+    Normalize(NormalizeStep),
+    PasteAttribute(PasteAttributeStep),
+    PlacePoints(PlacePointsStep),
+    RadialStamp(RadialStampStep),
+    Rescale(RescaleStep),
+    SetBorder(SetBorder),
+    Slope(SlopeStep),
+    Smooth(SmoothStep),
+    SnapToValues(SnapToValuesStep),
+    TransformAttribute1d(TransformAttribute1d),
     TransformAttribute2d(TransformAttribute2d),
+    TransformAttribute3d(TransformAttribute3d),
+    WarpedBiome(WarpedBiomeStep),
 }
 
 impl GenerationStep {
     /// Runs the step.
     pub fn run(&self, map: &mut Map2d) {
         match self {
+            ArithmeticScalar(step) => step.run(map),
+            CellularAutomata(step) => step.run(map),
+            Clamp(step) => step.run(map),
+            CoastalErosion(step) => step.run(map),
+            CombineAttributes(step) => step.run(map),
+            CopyAttribute(step) => step.run(map),
             CreateAttribute(step) => step.run(map),
+            DistanceTransform(step) => step.run(map),
             DistortAlongX(step) => step.distort_along_x(map),
             DistortAlongY(step) => step.distort_along_y(map),
             Distortion2d(step) => step.run(map),
+            EdgeDistance(step) => step.run(map),
+            Erosion(step) => step.run(map),
+            FaultLines(step) => step.run(map),
+            FillRect(step) => step.run(map),
+            FillSinks(step) => step.run(map),
             GeneratorAdd(step) => step.add(map),
+            GeneratorInCircle(step) => step.run(map),
+            GeneratorMax(step) => step.max(map),
+            GeneratorMin(step) => step.min(map),
+            GeneratorSet(step) => step.set(map),
             GeneratorSub(step) => step.sub(map),
+            Hillshade(step) => step.run(map),
+            IslandFalloff(step) => step.run(map),
+            LabelComponents(step) => step.run(map),
+            Masked(step) => step.run(map),
+            Maze(step) => step.run(map),
             ModifyWithAttribute(step) => step.run(map),
+            Normalize(step) => step.run(map),
+            PasteAttribute(step) => step.run(map),
+            PlacePoints(step) => step.run(map),
+            RadialStamp(step) => step.run(map),
+            Rescale(step) => step.run(map),
+            SetBorder(step) => step.run(map),
+            Slope(step) => step.run(map),
+            Smooth(step) => step.run(map),
+            SnapToValues(step) => step.run(map),
+            TransformAttribute1d(step) => step.run(map),
             TransformAttribute2d(step) => step.run(map),
+            TransformAttribute3d(step) => step.run(map),
+            WarpedBiome(step) => step.run(map),
         }
     }
 }
 
 /// For serializing, deserializing & validating [`GenerationStep`].
-#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub enum GenerationStepData {
+    ArithmeticScalar(ArithmeticScalarData),
+    CellularAutomata(CellularAutomataStepData),
+    Clamp(ClampStepData),
+    CoastalErosion(CoastalErosionStepData),
+    CombineAttributes(CombineAttributesData),
+    CopyAttribute(CopyAttributeStepData),
     CreateAttribute(CreateAttribute),
+    DistanceTransform(DistanceTransformStepData),
     DistortAlongX(Distortion1dData),
     DistortAlongY(Distortion1dData),
     Distortion2d(Distortion2dData),
+    EdgeDistance(EdgeDistanceStepData),
+    Erosion(ErosionStepData),
+    FaultLines(FaultLinesStepData),
+    FillRect(FillRectStepData),
+    FillSinks(FillSinksStepData),
     GeneratorAdd(GeneratorStepData),
+    GeneratorInCircle(GeneratorInCircleStepData),
+    GeneratorMax(GeneratorStepData),
+    GeneratorMin(GeneratorStepData),
+    GeneratorSet(GeneratorStepData),
     GeneratorSub(GeneratorStepData),
+    Hillshade(HillshadeStepData),
+    IslandFalloff(IslandFalloffData),
+    LabelComponents(LabelComponentsStepData),
+    Masked(MaskedStepData),
+    Maze(MazeStepData),
     ModifyWithAttribute(ModifyWithAttributeData),
+    Normalize(NormalizeStepData),
+    PasteAttribute(PasteAttributeStepData),
+    PlacePoints(PlacePointsStepData),
+    RadialStamp(RadialStampStepData),
+    Rescale(RescaleStepData),
+    SetBorder(SetBorderData),
+    Slope(SlopeStepData),
+    Smooth(SmoothStepData),
+    SnapToValues(SnapToValuesStepData),
+    TransformAttribute1d(TransformAttribute1dData),
     TransformAttribute2d(TransformAttribute2dData),
+    TransformAttribute3d(TransformAttribute3dData),
+    WarpedBiome(WarpedBiomeStepData),
 }
 
 type Data = GenerationStepData;
 
 impl GenerationStepData {
+    /// Returns the [`Attribute`]s read & written by the step.
+    ///
+    ///```
+    ///# use ofws_core::data::map::generation::attributes::create::CreateAttribute;
+    ///# use ofws_core::data::map::generation::attributes::modify::ModifyWithAttributeData;
+    ///# use ofws_core::data::map::generation::step::{GenerationStepData, StepDependencies};
+    /// let create = GenerationStepData::CreateAttribute(CreateAttribute::new("a0", 42));
+    /// let modify = ModifyWithAttributeData::new("a0".to_string(), "a1".to_string(), 100, 10);
+    /// let modify = GenerationStepData::ModifyWithAttribute(modify);
+    ///
+    /// assert_eq!(create.dependencies(), StepDependencies::new(vec![], vec!["a0".to_string()]));
+    /// assert_eq!(modify.dependencies(), StepDependencies::new(vec!["a0".to_string(), "a1".to_string()], vec!["a1".to_string()]));
+    ///```
+    pub fn dependencies(&self) -> StepDependencies {
+        match self {
+            Data::ArithmeticScalar(step) => step.dependencies(),
+            Data::CellularAutomata(step) => step.dependencies(),
+            Data::Clamp(step) => step.dependencies(),
+            Data::CoastalErosion(step) => step.dependencies(),
+            Data::CombineAttributes(step) => step.dependencies(),
+            Data::CopyAttribute(step) => step.dependencies(),
+            Data::CreateAttribute(step) => {
+                StepDependencies::new(vec![], vec![step.get_attribute().to_string()])
+            }
+            Data::DistanceTransform(step) => step.dependencies(),
+            Data::DistortAlongX(step) => step.dependencies(),
+            Data::DistortAlongY(step) => step.dependencies(),
+            Data::Distortion2d(step) => step.dependencies(),
+            Data::EdgeDistance(step) => step.dependencies(),
+            Data::Erosion(step) => step.dependencies(),
+            Data::FaultLines(step) => step.dependencies(),
+            Data::FillRect(step) => step.dependencies(),
+            Data::FillSinks(step) => step.dependencies(),
+            Data::GeneratorAdd(step) => step.dependencies(),
+            Data::GeneratorInCircle(step) => step.dependencies(),
+            Data::GeneratorMax(step) => step.dependencies(),
+            Data::GeneratorMin(step) => step.dependencies(),
+            Data::GeneratorSet(step) => step.dependencies(),
+            Data::GeneratorSub(step) => step.dependencies(),
+            Data::Hillshade(step) => step.dependencies(),
+            Data::IslandFalloff(step) => step.dependencies(),
+            Data::LabelComponents(step) => step.dependencies(),
+            Data::Masked(step) => step.dependencies(),
+            Data::Maze(step) => step.dependencies(),
+            Data::ModifyWithAttribute(step) => step.dependencies(),
+            Data::Normalize(step) => step.dependencies(),
+            Data::PasteAttribute(step) => step.dependencies(),
+            Data::PlacePoints(step) => step.dependencies(),
+            Data::RadialStamp(step) => step.dependencies(),
+            Data::Rescale(step) => step.dependencies(),
+            Data::SetBorder(step) => step.dependencies(),
+            Data::Slope(step) => step.dependencies(),
+            Data::Smooth(step) => step.dependencies(),
+            Data::SnapToValues(step) => step.dependencies(),
+            Data::TransformAttribute1d(step) => step.dependencies(),
+            Data::TransformAttribute2d(step) => step.dependencies(),
+            Data::TransformAttribute3d(step) => step.dependencies(),
+            Data::WarpedBiome(step) => step.dependencies(),
+        }
+    }
+
     pub fn try_convert(
         self,
         attributes: &mut Vec<String>,
     ) -> Result<GenerationStep, GenerationStepError> {
         match self {
+            Data::ArithmeticScalar(step) => Ok(ArithmeticScalar(step.try_convert(attributes)?)),
+            Data::CellularAutomata(step) => Ok(CellularAutomata(step.try_convert(attributes)?)),
+            Data::Clamp(step) => Ok(Clamp(step.try_convert(attributes)?)),
+            Data::CoastalErosion(step) => Ok(CoastalErosion(step.try_convert(attributes)?)),
+            Data::CombineAttributes(step) => Ok(CombineAttributes(step.try_convert(attributes)?)),
+            Data::CopyAttribute(step) => Ok(CopyAttribute(step.try_convert(attributes)?)),
             Data::CreateAttribute(step) => {
                 attributes.push(step.get_attribute().to_string());
                 Ok(CreateAttribute(step))
             }
+            Data::DistanceTransform(step) => Ok(DistanceTransform(step.try_convert(attributes)?)),
             Data::DistortAlongX(step) => Ok(DistortAlongX(step.try_convert(attributes)?)),
             Data::DistortAlongY(step) => Ok(DistortAlongY(step.try_convert(attributes)?)),
             Data::Distortion2d(step) => Ok(Distortion2d(step.try_convert(attributes)?)),
+            Data::EdgeDistance(step) => Ok(EdgeDistance(step.try_convert(attributes)?)),
+            Data::Erosion(step) => Ok(Erosion(step.try_convert(attributes)?)),
+            Data::FaultLines(step) => Ok(FaultLines(step.try_convert(attributes)?)),
+            Data::FillRect(step) => Ok(FillRect(step.try_convert(attributes)?)),
+            Data::FillSinks(step) => Ok(FillSinks(step.try_convert(attributes)?)),
             Data::GeneratorAdd(step) => Ok(GeneratorAdd(step.try_convert(attributes)?)),
+            Data::GeneratorInCircle(step) => Ok(GeneratorInCircle(step.try_convert(attributes)?)),
+            Data::GeneratorMax(step) => Ok(GeneratorMax(step.try_convert(attributes)?)),
+            Data::GeneratorMin(step) => Ok(GeneratorMin(step.try_convert(attributes)?)),
+            Data::GeneratorSet(step) => Ok(GeneratorSet(step.try_convert(attributes)?)),
             Data::GeneratorSub(step) => Ok(GeneratorSub(step.try_convert(attributes)?)),
+            Data::Hillshade(step) => Ok(Hillshade(step.try_convert(attributes)?)),
+            Data::IslandFalloff(step) => Ok(IslandFalloff(step.try_convert(attributes)?)),
+            Data::LabelComponents(step) => Ok(LabelComponents(step.try_convert(attributes)?)),
+            Data::Masked(step) => Ok(Masked(step.try_convert(attributes)?)),
+            Data::Maze(step) => Ok(Maze(step.try_convert(attributes)?)),
             Data::ModifyWithAttribute(step) => {
                 Ok(ModifyWithAttribute(step.try_convert(attributes)?))
             }
+            Data::Normalize(step) => Ok(Normalize(step.try_convert(attributes)?)),
+            Data::PasteAttribute(step) => Ok(PasteAttribute(step.try_convert(attributes)?)),
+            Data::PlacePoints(step) => Ok(PlacePoints(step.try_convert(attributes)?)),
+            Data::RadialStamp(step) => Ok(RadialStamp(step.try_convert(attributes)?)),
+            Data::Rescale(step) => Ok(Rescale(step.try_convert(attributes)?)),
+            Data::SetBorder(step) => Ok(SetBorder(step.try_convert(attributes)?)),
+            Data::Slope(step) => Ok(Slope(step.try_convert(attributes)?)),
+            Data::Smooth(step) => Ok(Smooth(step.try_convert(attributes)?)),
+            Data::SnapToValues(step) => Ok(SnapToValues(step.try_convert(attributes)?)),
+            Data::TransformAttribute1d(step) => {
+                Ok(TransformAttribute1d(step.try_convert(attributes)?))
+            }
             Data::TransformAttribute2d(step) => {
                 Ok(TransformAttribute2d(step.try_convert(attributes)?))
             }
+            Data::TransformAttribute3d(step) => {
+                Ok(TransformAttribute3d(step.try_convert(attributes)?))
+            }
+            Data::WarpedBiome(step) => Ok(WarpedBiome(step.try_convert(attributes)?)),
         }
     }
 }
 
 impl GenerationStep {
+    /// Returns the [`Attribute`]s read & written by the step.
+    /// Needs the names of the attributes created by the previous steps like [`GenerationStep::convert`].
+    pub fn dependencies(&self, attributes: &mut Vec<String>) -> StepDependencies {
+        self.convert(attributes).dependencies()
+    }
+
     pub fn convert(&self, attributes: &mut Vec<String>) -> GenerationStepData {
         match self {
+            ArithmeticScalar(data) => Data::ArithmeticScalar(data.convert(attributes)),
+            CellularAutomata(data) => Data::CellularAutomata(data.convert(attributes)),
+            Clamp(data) => Data::Clamp(data.convert(attributes)),
+            CoastalErosion(data) => Data::CoastalErosion(data.convert(attributes)),
+            CombineAttributes(data) => Data::CombineAttributes(data.convert(attributes)),
+            CopyAttribute(data) => Data::CopyAttribute(data.convert(attributes)),
             CreateAttribute(data) => {
                 attributes.push(data.get_attribute().to_string());
                 Data::CreateAttribute(data.clone())
             }
+            DistanceTransform(data) => Data::DistanceTransform(data.convert(attributes)),
             DistortAlongX(data) => Data::DistortAlongX(data.convert(attributes)),
             DistortAlongY(data) => Data::DistortAlongY(data.convert(attributes)),
             Distortion2d(data) => Data::Distortion2d(data.convert(attributes)),
+            EdgeDistance(data) => Data::EdgeDistance(data.convert(attributes)),
+            Erosion(data) => Data::Erosion(data.convert(attributes)),
+            FaultLines(data) => Data::FaultLines(data.convert(attributes)),
+            FillRect(data) => Data::FillRect(data.convert(attributes)),
+            FillSinks(data) => Data::FillSinks(data.convert(attributes)),
             GeneratorAdd(data) => Data::GeneratorAdd(data.convert(attributes)),
+            GeneratorInCircle(data) => Data::GeneratorInCircle(data.convert(attributes)),
+            GeneratorMax(data) => Data::GeneratorMax(data.convert(attributes)),
+            GeneratorMin(data) => Data::GeneratorMin(data.convert(attributes)),
+            GeneratorSet(data) => Data::GeneratorSet(data.convert(attributes)),
             GeneratorSub(data) => Data::GeneratorSub(data.convert(attributes)),
+            Hillshade(data) => Data::Hillshade(data.convert(attributes)),
+            IslandFalloff(data) => Data::IslandFalloff(data.convert(attributes)),
+            LabelComponents(data) => Data::LabelComponents(data.convert(attributes)),
+            Masked(data) => Data::Masked(data.convert(attributes)),
+            Maze(data) => Data::Maze(data.convert(attributes)),
             ModifyWithAttribute(data) => Data::ModifyWithAttribute(data.convert(attributes)),
+            Normalize(data) => Data::Normalize(data.convert(attributes)),
+            PasteAttribute(data) => Data::PasteAttribute(data.convert(attributes)),
+            PlacePoints(data) => Data::PlacePoints(data.convert(attributes)),
+            RadialStamp(data) => Data::RadialStamp(data.convert(attributes)),
+            Rescale(data) => Data::Rescale(data.convert(attributes)),
+            SetBorder(data) => Data::SetBorder(data.convert(attributes)),
+            Slope(data) => Data::Slope(data.convert(attributes)),
+            Smooth(data) => Data::Smooth(data.convert(attributes)),
+            SnapToValues(data) => Data::SnapToValues(data.convert(attributes)),
+            TransformAttribute1d(data) => Data::TransformAttribute1d(data.convert(attributes)),
             TransformAttribute2d(data) => Data::TransformAttribute2d(data.convert(attributes)),
+            TransformAttribute3d(data) => Data::TransformAttribute3d(data.convert(attributes)),
+            WarpedBiome(data) => Data::WarpedBiome(data.convert(attributes)),
         }
     }
 }
@@ -0,0 +1,196 @@
+use crate::data::map::attribute::Attribute;
+use crate::data::map::generation::MapGenerationError;
+use crate::data::map::Map2d;
+use crate::data::math::size2d::Size2d;
+use std::collections::HashSet;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Writes an [`Attribute`] as a grayscale png.
+pub fn write_attribute_png(attribute: &Attribute, path: &Path) -> io::Result<()> {
+    let size = attribute.get_size();
+    let image =
+        image::GrayImage::from_raw(size.width(), size.height(), attribute.get_all().clone())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Invalid attribute size!"))?;
+
+    image.save(path).map_err(io::Error::other)
+}
+
+/// Writes each [`Attribute`] of a [`Map2d`] as a grayscale png into a directory,
+/// which is created if needed. The file names are the sanitized attribute names.
+///
+/// Fails without writing anything, if 2 attribute names have the same sanitized name.
+pub fn write_all_pngs(map: &Map2d, dir: &str) -> io::Result<()> {
+    let mut file_names = HashSet::new();
+
+    for attribute in &map.attributes {
+        let file_name = format!("{}.png", sanitize_file_name(attribute.get_name()));
+
+        if file_names.contains(&file_name) {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!(
+                    "Attribute '{}' would overwrite the file '{}'!",
+                    attribute.get_name(),
+                    file_name
+                ),
+            ));
+        }
+
+        file_names.insert(file_name);
+    }
+
+    let dir = Path::new(dir);
+    fs::create_dir_all(dir)?;
+
+    for attribute in &map.attributes {
+        let file_name = format!("{}.png", sanitize_file_name(attribute.get_name()));
+        write_attribute_png(attribute, &dir.join(file_name))?;
+    }
+
+    Ok(())
+}
+
+/// Reads the luminance of an image & samples it with nearest-neighbor to match the size.
+pub fn read_image_luminance(path: &Path, size: Size2d) -> Result<Vec<u8>, MapGenerationError> {
+    let image = image::open(path)?.to_luma8();
+    let width = image.width() as usize;
+    let height = image.height() as usize;
+
+    if width == 0 || height == 0 {
+        return Err(MapGenerationError::IoError(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "The image is empty!",
+        )));
+    }
+
+    let mut values = Vec::with_capacity(size.get_area());
+
+    for y in 0..size.height() as usize {
+        let image_y = y * height / size.height() as usize;
+
+        for x in 0..size.width() as usize {
+            let image_x = x * width / size.width() as usize;
+            values.push(image.get_pixel(image_x as u32, image_y as u32).0[0]);
+        }
+    }
+
+    Ok(values)
+}
+
+/// Replaces all characters, that are unsuitable for file names.
+///
+/// ```
+///# use ofws_core::data::map::io::sanitize_file_name;
+/// assert_eq!(sanitize_file_name("elevation"), "elevation");
+/// assert_eq!(sanitize_file_name("sea-level_2"), "sea-level_2");
+/// assert_eq!(sanitize_file_name("rain/fall: *"), "rain_fall___");
+/// assert_eq!(sanitize_file_name(".."), "__");
+/// assert_eq!(sanitize_file_name(""), "_");
+/// ```
+pub fn sanitize_file_name(name: &str) -> String {
+    if name.is_empty() {
+        return "_".to_string();
+    }
+
+    name.chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::math::size2d::Size2d;
+
+    #[test]
+    fn test_export_all_pngs() {
+        let dir = std::env::temp_dir().join("ofws_core_test_export_all_pngs");
+        let _ = fs::remove_dir_all(&dir);
+        let mut map = Map2d::new(Size2d::new(3, 2));
+        map.create_attribute("elevation", 10);
+        map.create_attribute("rain/fall", 20);
+
+        map.export_all_pngs(dir.to_str().unwrap()).unwrap();
+
+        let mut files: Vec<String> = fs::read_dir(&dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        files.sort();
+
+        assert_eq!(files, vec!["elevation.png", "rain_fall.png"]);
+
+        for file in files {
+            assert_eq!(image::image_dimensions(dir.join(file)).unwrap(), (3, 2));
+        }
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_export_all_pngs_with_colliding_names() {
+        let dir = std::env::temp_dir().join("ofws_core_test_export_colliding_pngs");
+        let _ = fs::remove_dir_all(&dir);
+        let mut map = Map2d::new(Size2d::new(3, 2));
+        map.create_attribute("rain/fall", 10);
+        map.create_attribute("rain_fall", 20);
+
+        let error = map.export_all_pngs(dir.to_str().unwrap()).unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::AlreadyExists);
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn test_create_attribute_from_image() {
+        let dir = std::env::temp_dir().join("ofws_core_test_create_attribute_from_image");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("heightmap.png");
+        let values = vec![0, 50, 100, 150, 200, 250];
+        let mut map = Map2d::new(Size2d::new(3, 2));
+        map.create_attribute_from("elevation", values.clone());
+        write_attribute_png(map.get_attribute(0), &path).unwrap();
+
+        assert_eq!(
+            map.create_attribute_from_image("copy", &path).unwrap(),
+            Some(1)
+        );
+        assert_eq!(map.get_attribute(1).get_all(), &values);
+
+        let mut larger = Map2d::new(Size2d::new(6, 4));
+        larger
+            .create_attribute_from_image("elevation", &path)
+            .unwrap();
+
+        assert_eq!(
+            larger.get_attribute(0).get_all(),
+            &vec![
+                0, 0, 50, 50, 100, 100, 0, 0, 50, 50, 100, 100, 150, 150, 200, 200, 250, 250, 150,
+                150, 200, 200, 250, 250,
+            ]
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_create_attribute_from_missing_image() {
+        let path = std::env::temp_dir().join("ofws_core_test_missing_image.png");
+        let mut map = Map2d::new(Size2d::new(3, 2));
+
+        assert!(matches!(
+            map.create_attribute_from_image("elevation", &path),
+            Err(MapGenerationError::ImageError(_))
+        ));
+        assert_eq!(map.attribute_count(), 0);
+    }
+}
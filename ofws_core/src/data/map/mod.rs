@@ -1,9 +1,19 @@
 use crate::data::map::attribute::Attribute;
+use crate::data::map::generation::MapGenerationError;
+use crate::data::math::connectivity::Connectivity;
 use crate::data::math::size2d::Size2d;
 use std::collections::HashMap;
+use std::path::Path;
 
 pub mod attribute;
 pub mod generation;
+pub mod io;
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Map2dError {
+    NameAlreadyUsed(String),
+    UnknownAttribute(usize),
+}
 
 /// Represents a 2d region or world map.
 pub struct Map2d {
@@ -11,6 +21,8 @@ pub struct Map2d {
     size: Size2d,
     attribute_lookup: HashMap<String, usize>,
     attributes: Vec<Attribute>,
+    /// A reusable buffer to avoid allocations, if a step replaces all values of an [`Attribute`].
+    scratch: Vec<u8>,
 }
 
 impl Map2d {
@@ -36,6 +48,7 @@ impl Map2d {
             size,
             attribute_lookup: HashMap::new(),
             attributes: Vec::new(),
+            scratch: Vec::new(),
         }
     }
 
@@ -47,6 +60,13 @@ impl Map2d {
         self.size
     }
 
+    /// Writes each [`Attribute`] as a grayscale png named after it into a directory.
+    ///
+    /// Fails, if the file names of 2 attributes would be the same.
+    pub fn export_all_pngs(&self, dir: &str) -> std::io::Result<()> {
+        io::write_all_pngs(self, dir)
+    }
+
     /// Adds a new [`Attribute`] to the map and resturns its id.
     ///
     /// ```
@@ -80,6 +100,19 @@ impl Map2d {
         self.add_attribute(Attribute::new(name, self.size, values))
     }
 
+    /// Adds a new [`Attribute`] with the luminance of an image, e.g. a hand-painted heightmap.
+    ///
+    /// The image is sampled with nearest-neighbor, if its size differs from the map.
+    /// Returns the id like [`Map2d::create_attribute`] or an error, if the image can't be read.
+    pub fn create_attribute_from_image<S: Into<String>>(
+        &mut self,
+        name: S,
+        path: &Path,
+    ) -> Result<Option<usize>, MapGenerationError> {
+        let values = io::read_image_luminance(path, self.size)?;
+        Ok(self.create_attribute_from(name, values))
+    }
+
     fn add_attribute(&mut self, attribute: Attribute) -> Option<usize> {
         let id = self.attributes.len();
 
@@ -93,6 +126,143 @@ impl Map2d {
         Some(id)
     }
 
+    /// Takes the reusable scratch buffer, which is empty, but keeps its capacity.
+    pub(crate) fn take_scratch(&mut self) -> Vec<u8> {
+        let mut scratch = std::mem::take(&mut self.scratch);
+        scratch.clear();
+        scratch
+    }
+
+    /// Replaces all values of an [`Attribute`] with the buffer from [`Map2d::take_scratch`].
+    /// The old values are kept as the next scratch buffer.
+    pub(crate) fn replace_all_from_scratch(&mut self, id: usize, mut scratch: Vec<u8>) {
+        self.get_attribute_mut(id).swap_all(&mut scratch);
+        self.scratch = scratch;
+    }
+
+    /// Removes the [`Attribute`] with the matching id & returns it.
+    ///
+    /// The ids of all later attributes are decreased by 1,
+    /// so ids stored before the removal can become invalid.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(2, 3));
+    /// map.create_attribute("elevation", 42);
+    /// map.create_attribute("scratch", 0);
+    /// map.create_attribute("rainfall", 100);
+    ///
+    /// assert_eq!(map.remove_attribute(1).unwrap().get_name(), "scratch");
+    /// assert_eq!(map.get_attribute_id("elevation"), Some(0));
+    /// assert_eq!(map.get_attribute_id("scratch"), None);
+    /// assert_eq!(map.get_attribute_id("rainfall"), Some(1));
+    /// assert_eq!(map.get_attribute(1).get_name(), "rainfall");
+    /// assert!(map.remove_attribute(2).is_none());
+    /// ```
+    pub fn remove_attribute(&mut self, id: usize) -> Option<Attribute> {
+        if id >= self.attributes.len() {
+            return None;
+        }
+
+        let attribute = self.attributes.remove(id);
+        self.attribute_lookup.remove(attribute.get_name());
+
+        for other_id in self.attribute_lookup.values_mut() {
+            if *other_id > id {
+                *other_id -= 1;
+            }
+        }
+
+        Some(attribute)
+    }
+
+    /// Renames the [`Attribute`] with the matching id. Its id stays the same.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(2, 3));
+    /// map.create_attribute("elevation", 42);
+    ///
+    /// assert_eq!(map.rename_attribute(0, "height"), Ok(()));
+    /// assert_eq!(map.get_attribute(0).get_name(), "height");
+    /// assert_eq!(map.get_attribute_id("height"), Some(0));
+    /// assert_eq!(map.get_attribute_id("elevation"), None);
+    /// ```
+    ///
+    /// Fails if another [`Attribute`] already uses the new name or the id is unknown.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::{Map2d, Map2dError};
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(2, 3));
+    /// map.create_attribute("elevation", 42);
+    /// map.create_attribute("rainfall", 100);
+    ///
+    /// assert_eq!(map.rename_attribute(0, "rainfall"), Err(Map2dError::NameAlreadyUsed("rainfall".to_string())));
+    /// assert_eq!(map.rename_attribute(2, "height"), Err(Map2dError::UnknownAttribute(2)));
+    /// assert_eq!(map.get_attribute(0).get_name(), "elevation");
+    /// ```
+    pub fn rename_attribute<S: Into<String>>(
+        &mut self,
+        id: usize,
+        name: S,
+    ) -> Result<(), Map2dError> {
+        let name = name.into();
+
+        if id >= self.attributes.len() {
+            return Err(Map2dError::UnknownAttribute(id));
+        }
+
+        match self.attribute_lookup.get(&name) {
+            Some(other_id) if *other_id == id => return Ok(()),
+            Some(_) => return Err(Map2dError::NameAlreadyUsed(name)),
+            None => {}
+        }
+
+        let attribute = &mut self.attributes[id];
+        self.attribute_lookup.remove(attribute.get_name());
+        self.attribute_lookup.insert(name.clone(), id);
+        attribute.set_name(name);
+
+        Ok(())
+    }
+
+    /// Returns the number of [`Attribute`]s.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(2, 3));
+    /// assert_eq!(map.attribute_count(), 0);
+    ///
+    /// map.create_attribute("elevation", 42);
+    /// assert_eq!(map.attribute_count(), 1);
+    /// ```
+    pub fn attribute_count(&self) -> usize {
+        self.attributes.len()
+    }
+
+    /// Returns the names of all [`Attribute`]s in the order of their ids.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let mut map = Map2d::new(Size2d::new(2, 3));
+    /// map.create_attribute("elevation", 42);
+    /// map.create_attribute("rainfall", 100);
+    /// map.create_attribute("temperature", 20);
+    ///
+    /// assert_eq!(map.attribute_names(), vec!["elevation", "rainfall", "temperature"]);
+    /// ```
+    pub fn attribute_names(&self) -> Vec<&str> {
+        self.attributes
+            .iter()
+            .map(|attribute| attribute.get_name())
+            .collect()
+    }
+
     /// Returns the id of the [`Attribute`] with the matching name.
     ///
     /// ```
@@ -166,4 +336,73 @@ impl Map2d {
     pub fn get_attribute_mut(&mut self, id: usize) -> &mut Attribute {
         unwrap!(self.attributes.get_mut(id), "Unknown attribute id {}!", id)
     }
+
+    /// Returns the indices of all neighbors of a cell inside the map.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::math::connectivity::Connectivity::{Eight, Four};
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let map = Map2d::new(Size2d::new(3, 3));
+    ///
+    /// // corner
+    /// assert_eq!(map.get_neighbors(8, Four), vec![5, 7]);
+    /// assert_eq!(map.get_neighbors(8, Eight), vec![4, 5, 7]);
+    /// // edge
+    /// assert_eq!(map.get_neighbors(1, Four), vec![0, 2, 4]);
+    /// assert_eq!(map.get_neighbors(1, Eight), vec![0, 2, 3, 4, 5]);
+    /// // center
+    /// assert_eq!(map.get_neighbors(4, Four), vec![1, 3, 5, 7]);
+    /// assert_eq!(map.get_neighbors(4, Eight), vec![0, 1, 2, 3, 5, 6, 7, 8]);
+    /// ```
+    pub fn get_neighbors(&self, index: usize, mode: Connectivity) -> Vec<usize> {
+        self.size.neighbors(index, mode)
+    }
+
+    /// Returns the indices of all neighbors of a cell, which wrap around at the edges of the map.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::math::connectivity::Connectivity::Four;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let map = Map2d::new(Size2d::new(3, 3));
+    ///
+    /// assert_eq!(map.get_wrapping_neighbors(8, Four), vec![5, 7, 6, 2]);
+    /// ```
+    pub fn get_wrapping_neighbors(&self, index: usize, mode: Connectivity) -> Vec<usize> {
+        self.size.wrapping_neighbors(index, mode)
+    }
+}
+
+impl PartialEq for Map2d {
+    /// Compares name, size & attributes. The order of the attributes is ignored.
+    ///
+    /// ```
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let size = Size2d::new(2, 3);
+    /// let mut map0 = Map2d::new(size);
+    /// map0.create_attribute("elevation", 42);
+    /// map0.create_attribute("rainfall", 100);
+    /// let mut map1 = Map2d::new(size);
+    /// map1.create_attribute("rainfall", 100);
+    /// map1.create_attribute("elevation", 42);
+    ///
+    /// assert!(map0 == map1);
+    ///
+    /// *map1.get_attribute_mut(1).get_mut(4) = 43;
+    ///
+    /// assert!(map0 != map1);
+    /// ```
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.size == other.size
+            && self.attributes.len() == other.attributes.len()
+            && self.attributes.iter().all(|attribute| {
+                other
+                    .get_attribute_id(attribute.get_name())
+                    .map(|id| attribute == other.get_attribute(id))
+                    .unwrap_or(false)
+            })
+    }
 }
@@ -0,0 +1,71 @@
+use crate::data::math::size2d::Size2d;
+use serde::{Deserialize, Serialize};
+
+const FOUR: [(i32, i32); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const EIGHT: [(i32, i32); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Defines which cells are neighbors, e.g. for region algorithms.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum Connectivity {
+    /// Only horizontal & vertical neighbors.
+    #[default]
+    Four,
+    /// Diagonal neighbors too.
+    Eight,
+}
+
+impl Connectivity {
+    /// Returns the offsets to all neighbors.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::connectivity::Connectivity;
+    /// assert_eq!(Connectivity::Four.offsets().len(), 4);
+    /// assert_eq!(Connectivity::Eight.offsets().len(), 8);
+    /// ```
+    pub fn offsets(&self) -> &'static [(i32, i32)] {
+        match self {
+            Connectivity::Four => &FOUR,
+            Connectivity::Eight => &EIGHT,
+        }
+    }
+
+    /// Returns the indices of all neighbors of a cell, that are inside.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::connectivity::Connectivity;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let size = Size2d::new(3, 3);
+    ///
+    /// assert_eq!(Connectivity::Four.neighbors(size, 0), vec![1, 3]);
+    /// assert_eq!(Connectivity::Eight.neighbors(size, 0), vec![1, 3, 4]);
+    /// assert_eq!(Connectivity::Four.neighbors(size, 4), vec![1, 3, 5, 7]);
+    /// assert_eq!(Connectivity::Eight.neighbors(size, 4), vec![0, 1, 2, 3, 5, 6, 7, 8]);
+    /// ```
+    pub fn neighbors(&self, size: Size2d, index: usize) -> Vec<usize> {
+        let x = size.to_x(index) as i32;
+        let y = size.to_y(index) as i32;
+
+        self.offsets()
+            .iter()
+            .filter_map(|(dx, dy)| {
+                let nx = x + dx;
+                let ny = y + dy;
+
+                if nx < 0 || ny < 0 {
+                    return None;
+                }
+
+                size.to_index(nx as u32, ny as u32)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+use Direction8::*;
+
+const DIRECTIONS: [Direction8; 8] = [N, NE, E, SE, S, SW, W, NW];
+
+/// The 8 directions of a compass, e.g. for the wind.
+///
+/// North is towards the top of the map, which is negative y.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Serialize, Deserialize)]
+pub enum Direction8 {
+    /// North
+    N,
+    /// North-east
+    NE,
+    /// East
+    E,
+    /// South-east
+    SE,
+    /// South
+    S,
+    /// South-west
+    SW,
+    /// West
+    W,
+    /// North-west
+    NW,
+}
+
+impl Direction8 {
+    /// Returns the direction closest to an angle in degrees.
+    /// North is 0 degrees & the angle increases clockwise.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::direction::Direction8::*;
+    ///# use ofws_core::data::math::direction::Direction8;
+    /// assert_eq!(Direction8::from_degrees(0.0), N);
+    /// assert_eq!(Direction8::from_degrees(20.0), N);
+    /// assert_eq!(Direction8::from_degrees(25.0), NE);
+    /// assert_eq!(Direction8::from_degrees(90.0), E);
+    /// assert_eq!(Direction8::from_degrees(180.0), S);
+    /// assert_eq!(Direction8::from_degrees(270.0), W);
+    /// assert_eq!(Direction8::from_degrees(350.0), N);
+    /// assert_eq!(Direction8::from_degrees(-90.0), W);
+    /// assert_eq!(Direction8::from_degrees(405.0), NE);
+    /// ```
+    pub fn from_degrees(degrees: f32) -> Direction8 {
+        let index = ((degrees.rem_euclid(360.0) + 22.5) / 45.0) as usize;
+        DIRECTIONS[index % DIRECTIONS.len()]
+    }
+
+    /// Returns the offset of a neighboring cell in this direction.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::direction::Direction8::*;
+    /// assert_eq!(N.offset(), (0, -1));
+    /// assert_eq!(NE.offset(), (1, -1));
+    /// assert_eq!(E.offset(), (1, 0));
+    /// assert_eq!(SE.offset(), (1, 1));
+    /// assert_eq!(S.offset(), (0, 1));
+    /// assert_eq!(SW.offset(), (-1, 1));
+    /// assert_eq!(W.offset(), (-1, 0));
+    /// assert_eq!(NW.offset(), (-1, -1));
+    /// ```
+    pub fn offset(&self) -> (i32, i32) {
+        match self {
+            N => (0, -1),
+            NE => (1, -1),
+            E => (1, 0),
+            SE => (1, 1),
+            S => (0, 1),
+            SW => (-1, 1),
+            W => (-1, 0),
+            NW => (-1, -1),
+        }
+    }
+
+    /// Returns the opposite direction.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::direction::Direction8::*;
+    /// assert_eq!(N.opposite(), S);
+    /// assert_eq!(NE.opposite(), SW);
+    /// assert_eq!(E.opposite(), W);
+    /// assert_eq!(SW.opposite(), NE);
+    /// ```
+    pub fn opposite(&self) -> Direction8 {
+        match self {
+            N => S,
+            NE => SW,
+            E => W,
+            SE => NW,
+            S => N,
+            SW => NE,
+            W => E,
+            NW => SE,
+        }
+    }
+}
@@ -1,4 +1,35 @@
 use num_integer::Roots;
+use serde::{Deserialize, Serialize};
+
+/// Defines how the distance between 2 points is measured.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum DistanceMetric {
+    /// The distance along the direct line. Creates circles.
+    #[default]
+    Euclidean,
+    /// The sum of the distances along both axes. Creates diamonds.
+    Manhattan,
+    /// The maximum of the distances along both axes. Creates squares.
+    Chebyshev,
+}
+
+impl DistanceMetric {
+    /// Returns distance between 2 points in 2d space.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::distance::DistanceMetric::*;
+    /// assert_eq!(Euclidean.calculate(10, 10, 13, 14), 5);
+    /// assert_eq!(Manhattan.calculate(10, 10, 13, 14), 7);
+    /// assert_eq!(Chebyshev.calculate(10, 10, 13, 14), 4);
+    /// ```
+    pub fn calculate(&self, x0: u32, y0: u32, x1: u32, y1: u32) -> u32 {
+        match self {
+            DistanceMetric::Euclidean => calculate_distance(x0, y0, x1, y1),
+            DistanceMetric::Manhattan => abs_diff(x0, x1) + abs_diff(y0, y1),
+            DistanceMetric::Chebyshev => abs_diff(x0, x1).max(abs_diff(y0, y1)),
+        }
+    }
+}
 
 /// Returns distance between 2 points in 2d space.
 ///
@@ -25,6 +56,34 @@ pub fn calculate_distance(x0: u32, y0: u32, x1: u32, y1: u32) -> u32 {
     (diff_x.pow(2) + diff_y.pow(2)).sqrt()
 }
 
+/// Returns distance between 2 points in 2d space after normalizing each axis by its radius.
+/// The result is scaled by the larger radius, so the border of the ellipse has that distance.
+///
+/// ```
+///# use ofws_core::data::math::distance::calculate_ellipse_distance;
+///
+/// assert_eq!(calculate_ellipse_distance(10, 10, 8, 4, 10, 10), 0);
+/// assert_eq!(calculate_ellipse_distance(10, 10, 8, 4, 18, 10), 8);
+/// assert_eq!(calculate_ellipse_distance(10, 10, 8, 4, 10, 14), 8);
+/// assert_eq!(calculate_ellipse_distance(10, 10, 8, 4, 10, 12), 4);
+/// assert_eq!(calculate_ellipse_distance(0, 0, 5, 5, 3, 4), 5);
+/// ```
+pub fn calculate_ellipse_distance(
+    center_x: u32,
+    center_y: u32,
+    radius_x: u32,
+    radius_y: u32,
+    x: u32,
+    y: u32,
+) -> u32 {
+    let diff_x = abs_diff(center_x, x) as u128 * radius_y as u128;
+    let diff_y = abs_diff(center_y, y) as u128 * radius_x as u128;
+    let max_radius = radius_x.max(radius_y) as u128;
+    let distance =
+        (diff_x.pow(2) + diff_y.pow(2)).sqrt() * max_radius / (radius_x as u128 * radius_y as u128);
+    distance.min(u32::MAX as u128) as u32
+}
+
 /// Returns the absolute difference between 2 unsigned integers.
 ///
 /// ```
@@ -1,5 +1,7 @@
+use crate::data::math::generator::checked_cast;
 use crate::data::math::generator::gradient::Gradient;
 use crate::data::math::generator::noise::{Noise, NoiseData, NoiseError};
+use crate::data::math::generator::worley::{Worley, WorleyData};
 use crate::data::math::interpolation::vector::VectorInterpolation;
 use serde::{Deserialize, Serialize};
 use std::convert::{TryFrom, TryInto};
@@ -7,7 +9,10 @@ use Generator1d::*;
 
 #[derive(Debug, Eq, PartialEq)]
 pub enum Generator1dError {
+    EmptyLookupTable,
     Noise(NoiseError),
+    TooFewSteps(u8),
+    ZeroPeriod,
 }
 
 impl From<NoiseError> for Generator1dError {
@@ -138,11 +143,149 @@ pub enum Generator1d {
     /// assert_eq!(generator.generate(125), 175);
     /// ```
     InterpolateVector(VectorInterpolation<u32, u8>),
+    /// Inverts the values of another generator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use ofws_core::data::math::generator::generator1d::Generator1d;
+    /// let generator = Generator1d::new_invert(Generator1d::InputAsOutput);
+    /// let twice = Generator1d::new_invert(Generator1d::new_invert(Generator1d::InputAsOutput));
+    ///
+    /// assert_eq!(generator.generate(10), 245);
+    /// assert_eq!(twice.generate(10), 10);
+    /// ```
+    Invert(Box<Generator1d>),
+    /// Looks the input up in a table. Inputs beyond the end return the last value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use ofws_core::data::math::generator::generator1d::Generator1d;
+    /// let generator = Generator1d::new_lookup_table(vec![10, 20, 30]).unwrap();
+    ///
+    /// assert_eq!(generator.generate(0), 10);
+    /// assert_eq!(generator.generate(1), 20);
+    /// assert_eq!(generator.generate(2), 30);
+    /// assert_eq!(generator.generate(5), 30);
+    /// ```
+    LookupTable { table: Vec<u8> },
     /// Generates values with [`Noise`].
     Noise(Noise),
+    /// Rounds the values of another generator down to evenly spaced levels, e.g. for terraces.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use ofws_core::data::math::generator::generator1d::Generator1d;
+    /// let generator = Generator1d::new_quantize(Generator1d::InputAsOutput, 4).unwrap();
+    ///
+    /// assert_eq!(generator.generate(  0),   0);
+    /// assert_eq!(generator.generate( 84),   0);
+    /// assert_eq!(generator.generate( 85),  85);
+    /// assert_eq!(generator.generate(169),  85);
+    /// assert_eq!(generator.generate(170), 170);
+    /// assert_eq!(generator.generate(254), 170);
+    /// assert_eq!(generator.generate(255), 255);
+    /// ```
+    Quantize { source: Box<Generator1d>, steps: u8 },
+    /// Generates a sine wave between a min & a max value.
+    ///
+    /// # Example
+    ///
+    /// ```
+    ///# use ofws_core::data::math::generator::generator1d::Generator1d;
+    /// let generator = Generator1d::new_sine(100, 50, 150, 0).unwrap();
+    ///
+    /// assert_eq!(generator.generate(  0), 100);
+    /// assert_eq!(generator.generate( 25), 150);
+    /// assert_eq!(generator.generate( 50), 100);
+    /// assert_eq!(generator.generate( 75),  50);
+    /// assert_eq!(generator.generate(100), 100);
+    /// assert_eq!(generator.generate(125), 150);
+    /// ```
+    ///
+    /// The phase shifts the wave:
+    ///
+    /// ```
+    ///# use ofws_core::data::math::generator::generator1d::Generator1d;
+    /// let generator = Generator1d::new_sine(100, 50, 150, 25).unwrap();
+    ///
+    /// assert_eq!(generator.generate( 0), 150);
+    /// assert_eq!(generator.generate(50),  50);
+    /// ```
+    Sine {
+        period: u32,
+        min_value: u8,
+        max_value: u8,
+        phase: u32,
+    },
+    /// Generates values with [`Worley`] noise.
+    Worley(Worley),
 }
 
 impl Generator1d {
+    pub fn new_invert(source: Generator1d) -> Generator1d {
+        Invert(Box::new(source))
+    }
+
+    /// Returns a generator looking up the input in a table, if the table isn't empty.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::generator::generator1d::{Generator1d, Generator1dError};
+    /// assert_eq!(Generator1d::new_lookup_table(vec![]).err(), Some(Generator1dError::EmptyLookupTable));
+    /// ```
+    pub fn new_lookup_table(table: Vec<u8>) -> Result<Generator1d, Generator1dError> {
+        if table.is_empty() {
+            return Err(Generator1dError::EmptyLookupTable);
+        }
+
+        Ok(LookupTable { table })
+    }
+
+    /// Returns a generator rounding down to evenly spaced levels, if there are at least 2 levels.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::generator::generator1d::{Generator1d, Generator1dError};
+    /// let result = Generator1d::new_quantize(Generator1d::InputAsOutput, 1);
+    ///
+    /// assert_eq!(result.err(), Some(Generator1dError::TooFewSteps(1)));
+    /// ```
+    pub fn new_quantize(source: Generator1d, steps: u8) -> Result<Generator1d, Generator1dError> {
+        if steps < 2 {
+            return Err(Generator1dError::TooFewSteps(steps));
+        }
+
+        Ok(Quantize {
+            source: Box::new(source),
+            steps,
+        })
+    }
+
+    /// Returns a generator of a sine wave, if the period isn't 0.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::generator::generator1d::{Generator1d, Generator1dError};
+    /// assert_eq!(Generator1d::new_sine(0, 0, 255, 0).err(), Some(Generator1dError::ZeroPeriod));
+    /// ```
+    pub fn new_sine(
+        period: u32,
+        min_value: u8,
+        max_value: u8,
+        phase: u32,
+    ) -> Result<Generator1d, Generator1dError> {
+        if period == 0 {
+            return Err(Generator1dError::ZeroPeriod);
+        }
+
+        Ok(Sine {
+            period,
+            min_value,
+            max_value,
+            phase,
+        })
+    }
+
     /// Generates an output for an input.
     pub fn generate(&self, input: u32) -> u8 {
         match self {
@@ -150,9 +293,54 @@ impl Generator1d {
             Gradient(gradient) => gradient.generate(input),
             InputAsOutput => input as u8,
             InterpolateVector(interpolator) => interpolator.interpolate(input),
+            Invert(source) => 255 - source.generate(input),
+            LookupTable { table } => table[(input as usize).min(table.len() - 1)],
             Noise(noise) => noise.generate1d(input),
+            Quantize { source, steps } => quantize(source.generate(input), *steps),
+            Sine {
+                period,
+                min_value,
+                max_value,
+                phase,
+            } => {
+                let position = (input as u64 + *phase as u64) % *period as u64;
+                let angle = position as f64 / *period as f64 * std::f64::consts::TAU;
+                let factor = (angle.sin() + 1.0) / 2.0;
+                let range = *max_value as f64 - *min_value as f64;
+                (*min_value as f64 + factor * range).round() as u8
+            }
+            Worley(worley) => worley.generate1d(input),
         }
     }
+
+    /// Generates an output for an input like [`Generator1d::generate`],
+    /// but asserts in debug builds that the value doesn't wrap around during the cast to u8.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::generator::generator1d::Generator1d::InputAsOutput;
+    /// assert_eq!(InputAsOutput.generate_checked(255), 255);
+    /// ```
+    pub fn generate_checked(&self, input: u32) -> u8 {
+        match self {
+            InputAsOutput => checked_cast(input as f64, "Generator1d::InputAsOutput"),
+            Invert(source) => 255 - source.generate_checked(input),
+            Noise(noise) => checked_cast(noise.calculate1d(input), "Generator1d::Noise"),
+            Quantize { source, steps } => quantize(source.generate_checked(input), *steps),
+            AbsoluteGradient(_)
+            | Gradient(_)
+            | InterpolateVector(_)
+            | LookupTable { .. }
+            | Sine { .. }
+            | Worley(_) => self.generate(input),
+        }
+    }
+}
+
+/// Rounds a value down to the nearest of evenly spaced levels.
+fn quantize(value: u8, steps: u8) -> u8 {
+    let intervals = steps as u32 - 1;
+    let level = value as u32 * intervals / 255;
+    (level * 255 / intervals) as u8
 }
 
 /// For serializing, deserializing & validating [`Generator1d`].
@@ -161,24 +349,46 @@ impl Generator1d {
 ///# use ofws_core::data::math::generator::generator1d::{Generator1dData, assert_eq};
 ///# use ofws_core::data::math::generator::gradient::Gradient;
 ///# use ofws_core::data::math::generator::noise::NoiseData;
+///# use ofws_core::data::math::generator::worley::{WorleyData, WorleyDistance};
 ///# use ofws_core::data::math::interpolation::vector::VectorInterpolation;
 /// let gradient = Gradient::new(0, 255, 1000, 500);
 /// let interpolator = VectorInterpolation::new(vec![(100,150), (150,200), (200, 100)]). unwrap();
-/// let noise_data = NoiseData { seed: 300, scale: 5, min_value: 10, max_value: 128 };
+/// let noise_data = NoiseData::new(300, 5.0, 10, 128);
+/// let worley_data = WorleyData { seed: 300, scale: 5.0, distance: WorleyDistance::F2MinusF1, min_value: 10, max_value: 128 };
 ///
 /// assert_eq(Generator1dData::AbsoluteGradient(gradient));
 /// assert_eq(Generator1dData::Gradient(gradient));
 /// assert_eq(Generator1dData::InputAsOutput);
 /// assert_eq(Generator1dData::InterpolateVector(interpolator));
+/// assert_eq(Generator1dData::Invert(Box::new(Generator1dData::InputAsOutput)));
+/// assert_eq(Generator1dData::LookupTable { table: vec![10, 20, 30] });
 /// assert_eq(Generator1dData::Noise(noise_data));
+/// assert_eq(Generator1dData::Quantize { source: Box::new(Generator1dData::InputAsOutput), steps: 5 });
+/// assert_eq(Generator1dData::Sine { period: 100, min_value: 10, max_value: 200, phase: 30 });
+/// assert_eq(Generator1dData::Worley(worley_data));
 ///```
-#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub enum Generator1dData {
     AbsoluteGradient(Gradient),
     Gradient(Gradient),
     InputAsOutput,
     InterpolateVector(VectorInterpolation<u32, u8>),
+    Invert(Box<Generator1dData>),
+    LookupTable {
+        table: Vec<u8>,
+    },
     Noise(NoiseData),
+    Quantize {
+        source: Box<Generator1dData>,
+        steps: u8,
+    },
+    Sine {
+        period: u32,
+        min_value: u8,
+        max_value: u8,
+        phase: u32,
+    },
+    Worley(WorleyData),
 }
 
 type Data = Generator1dData;
@@ -192,10 +402,22 @@ impl TryFrom<Generator1dData> for Generator1d {
             Data::Gradient(gradient) => Ok(Gradient(gradient)),
             Data::InputAsOutput => Ok(InputAsOutput),
             Data::InterpolateVector(interpolator) => Ok(InterpolateVector(interpolator)),
+            Data::Invert(source) => Ok(Generator1d::new_invert((*source).try_into()?)),
+            Data::LookupTable { table } => Generator1d::new_lookup_table(table),
             Data::Noise(noise_data) => {
                 let noise: Noise = noise_data.try_into()?;
                 Ok(Noise(noise))
             }
+            Data::Quantize { source, steps } => {
+                Generator1d::new_quantize((*source).try_into()?, steps)
+            }
+            Data::Sine {
+                period,
+                min_value,
+                max_value,
+                phase,
+            } => Generator1d::new_sine(period, min_value, max_value, phase),
+            Data::Worley(data) => Ok(Worley(data.try_into()?)),
         }
     }
 }
@@ -207,7 +429,27 @@ impl From<&Generator1d> for Generator1dData {
             Gradient(gradient) => Data::Gradient(*gradient),
             InputAsOutput => Data::InputAsOutput,
             InterpolateVector(interpolator) => Data::InterpolateVector(interpolator.clone()),
+            Invert(source) => Data::Invert(Box::new(source.as_ref().into())),
+            LookupTable { table } => Data::LookupTable {
+                table: table.clone(),
+            },
             Noise(noise) => Data::Noise(noise.into()),
+            Quantize { source, steps } => Data::Quantize {
+                source: Box::new(source.as_ref().into()),
+                steps: *steps,
+            },
+            Sine {
+                period,
+                min_value,
+                max_value,
+                phase,
+            } => Data::Sine {
+                period: *period,
+                min_value: *min_value,
+                max_value: *max_value,
+                phase: *phase,
+            },
+            Worley(worley) => Data::Worley(worley.into()),
         }
     }
 }
@@ -1,15 +1,25 @@
-use crate::data::math::distance::calculate_distance;
+use crate::data::math::distance::{calculate_ellipse_distance, DistanceMetric};
+use crate::data::math::generator::checked_cast;
 use crate::data::math::generator::generator1d::{Generator1d, Generator1dData, Generator1dError};
 use crate::data::math::generator::noise::{Noise, NoiseData, NoiseError};
+use crate::data::math::generator::turbulence::{Turbulence, TurbulenceData};
+use crate::data::math::generator::worley::{Worley, WorleyData};
+use crate::data::math::operation::CombineOp;
+use crate::data::math::random::hash2d;
 use crate::data::math::size2d::Size2d;
 use serde::{Deserialize, Serialize};
 use std::convert::{TryFrom, TryInto};
+use std::sync::OnceLock;
 use Generator2d::*;
 
 #[derive(Debug, Eq, PartialEq)]
 pub enum Generator2dError {
     Generator1d(Generator1dError),
     Noise(NoiseError),
+    ZeroCellSize,
+    ZeroPeriod,
+    ZeroRadius,
+    ZeroRegions,
 }
 
 impl From<NoiseError> for Generator2dError {
@@ -24,6 +34,13 @@ impl From<Generator1dError> for Generator2dError {
     }
 }
 
+/// The axes of the 2d space.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum Axis {
+    X,
+    Y,
+}
+
 #[svgbobdoc::transform]
 /// Generate values for 2d points.
 /// Used for the procedural generation of 2d maps.
@@ -77,10 +94,122 @@ pub enum Generator2d {
     /// assert_eq!(generator.generate( 5,  5), 5);
     /// assert_eq!(generator.generate(15,  5), 5);
     /// ```
+    ///
+    /// Other metrics change the shape:
+    ///
+    /// ```
+    ///# use ofws_core::data::math::distance::DistanceMetric;
+    ///# use ofws_core::data::math::generator::generator1d::Generator1d;
+    ///# use ofws_core::data::math::generator::generator2d::Generator2d;
+    /// let generator = |metric| {
+    ///     Generator2d::new_apply_to_metric_distance(Generator1d::InputAsOutput, 10, 5, metric)
+    /// };
+    ///
+    /// assert_eq!(generator(DistanceMetric::Euclidean).generate(16, 13), 10);
+    /// assert_eq!(generator(DistanceMetric::Manhattan).generate(16, 13), 14);
+    /// assert_eq!(generator(DistanceMetric::Chebyshev).generate(16, 13), 8);
+    /// ```
     ApplyToDistance {
         generator: Generator1d,
         center_x: u32,
         center_y: u32,
+        metric: DistanceMetric,
+    },
+    /// Feeds the distance from a point to a [`Generator1d`], but stretches it into an ellipse.
+    /// The distance along each axis is normalized by its radius & then scaled by the larger one.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::generator::generator1d::Generator1d;
+    ///# use ofws_core::data::math::generator::generator2d::Generator2d;
+    /// let generator =
+    ///     Generator2d::new_apply_to_ellipse_distance(Generator1d::InputAsOutput, 10, 10, 8, 4)
+    ///         .unwrap();
+    ///
+    /// assert_eq!(generator.generate(10, 10), 0);
+    /// assert_eq!(generator.generate(18, 10), 8);
+    /// assert_eq!(generator.generate( 2, 10), 8);
+    /// assert_eq!(generator.generate(10, 14), 8);
+    /// assert_eq!(generator.generate(10,  6), 8);
+    /// ```
+    ///
+    /// Both radii must be greater than 0:
+    ///
+    /// ```
+    ///# use ofws_core::data::math::generator::generator1d::Generator1d;
+    ///# use ofws_core::data::math::generator::generator2d::{Generator2d, Generator2dError};
+    /// let generator = |x, y| {
+    ///     Generator2d::new_apply_to_ellipse_distance(Generator1d::InputAsOutput, 10, 10, x, y)
+    /// };
+    ///
+    /// assert_eq!(generator(0, 4).err(), Some(Generator2dError::ZeroRadius));
+    /// assert_eq!(generator(8, 0).err(), Some(Generator2dError::ZeroRadius));
+    /// ```
+    ApplyToEllipseDistance {
+        generator: Generator1d,
+        center_x: u32,
+        center_y: u32,
+        radius_x: u32,
+        radius_y: u32,
+    },
+    /// Generates all values of an inner generator for an area on first use & returns them afterwards.
+    /// Points outside the area are passed to the inner generator.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::generator::generator2d::Generator2d;
+    /// let generator = Generator2d::new_cached(2, 3, Generator2d::new_index(2, 3));
+    ///
+    /// assert_eq!(generator.generate(0, 0), 0);
+    /// assert_eq!(generator.generate(1, 0), 1);
+    /// assert_eq!(generator.generate(0, 1), 2);
+    /// assert_eq!(generator.generate(1, 1), 3);
+    /// assert_eq!(generator.generate(0, 2), 4);
+    /// assert_eq!(generator.generate(1, 2), 5);
+    /// ```
+    Cached {
+        size: Size2d,
+        inner: Box<Generator2d>,
+        cache: OnceLock<Vec<u8>>,
+    },
+    /// Generates a checkerboard pattern of square cells, which alternate between low & high.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::generator::generator2d::Generator2d;
+    /// let generator = Generator2d::new_checkerboard(2, 10, 200).unwrap();
+    ///
+    /// assert_eq!(generator.generate(0, 0), 10);
+    /// assert_eq!(generator.generate(1, 1), 10);
+    /// assert_eq!(generator.generate(2, 0), 200);
+    /// assert_eq!(generator.generate(0, 2), 200);
+    /// assert_eq!(generator.generate(3, 3), 10);
+    /// assert_eq!(generator.generate(4, 3), 200);
+    /// assert!(Generator2d::new_checkerboard(0, 10, 200).is_err());
+    /// ```
+    Checkerboard { cell_size: u32, low: u8, high: u8 },
+    /// Combines the values of 2 generators.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::generator::generator1d::Generator1d;
+    ///# use ofws_core::data::math::generator::generator2d::Generator2d;
+    ///# use ofws_core::data::math::operation::CombineOp;
+    /// let combine = |op| {
+    ///     let a = Generator2d::new_apply_to_x(Generator1d::InputAsOutput);
+    ///     let b = Generator2d::new_apply_to_y(Generator1d::InputAsOutput);
+    ///     Generator2d::new_combine(a, b, op)
+    /// };
+    ///
+    /// assert_eq!(combine(CombineOp::Add).generate(200, 50), 250);
+    /// assert_eq!(combine(CombineOp::Add).generate(200, 100), 255);
+    /// assert_eq!(combine(CombineOp::Sub).generate(200, 50), 150);
+    /// assert_eq!(combine(CombineOp::Sub).generate(50, 200), 0);
+    /// assert_eq!(combine(CombineOp::Min).generate(200, 50), 50);
+    /// assert_eq!(combine(CombineOp::Max).generate(200, 50), 200);
+    /// assert_eq!(combine(CombineOp::Average).generate(200, 50), 125);
+    /// assert_eq!(combine(CombineOp::Set).generate(200, 50), 50);
+    /// ```
+    Combine {
+        a: Box<Generator2d>,
+        b: Box<Generator2d>,
+        op: CombineOp,
     },
     /// Generates the index of each 2d point.
     ///
@@ -98,6 +227,101 @@ pub enum Generator2d {
     IndexGenerator(Size2d),
     /// Generates noise for each 2d point.
     Noise(Noise),
+    /// Generates stripes orthogonal to an axis, which alternate between low & high.
+    /// Each stripe is period values wide.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::generator::generator2d::{Axis, Generator2d};
+    /// let generator = Generator2d::new_stripes(3, Axis::X, 10, 200).unwrap();
+    ///
+    /// assert_eq!(generator.generate(0, 0), 10);
+    /// assert_eq!(generator.generate(2, 7), 10);
+    /// assert_eq!(generator.generate(3, 0), 200);
+    /// assert_eq!(generator.generate(5, 7), 200);
+    /// assert_eq!(generator.generate(6, 0), 10);
+    ///
+    /// let generator = Generator2d::new_stripes(3, Axis::Y, 10, 200).unwrap();
+    ///
+    /// assert_eq!(generator.generate(7, 2), 10);
+    /// assert_eq!(generator.generate(7, 3), 200);
+    /// assert!(Generator2d::new_stripes(0, Axis::Y, 10, 200).is_err());
+    /// ```
+    Stripes {
+        period: u32,
+        axis: Axis,
+        low: u8,
+        high: u8,
+    },
+    /// Generates turbulence for each 2d point.
+    Turbulence(Turbulence),
+    /// Generates the id of the region of each 2d point.
+    ///
+    /// Each cell of a grid contains 1 feature point, which is placed deterministically based on the seed.
+    /// The points closest to the same feature point form a region,
+    /// whose id is hashed from its cell & is in the range [0, num_regions).
+    ///
+    /// ```
+    ///# use ofws_core::data::math::generator::generator2d::Generator2d;
+    /// let generator = Generator2d::new_voronoi_regions(42, 10, 5).unwrap();
+    ///
+    /// assert_eq!(generator.generate(12, 15), generator.generate(13, 15));
+    /// assert_eq!(generator.generate(12, 15), generator.generate(12, 16));
+    /// assert_ne!(generator.generate(5, 15), generator.generate(20, 15));
+    ///
+    /// for y in 0..50 {
+    ///     for x in 0..50 {
+    ///         assert!(generator.generate(x, y) < 5);
+    ///     }
+    /// }
+    /// ```
+    VoronoiRegions {
+        seed: u32,
+        cell_size: u32,
+        num_regions: u8,
+    },
+    /// Warps the point with 2 generators before passing it to the source generator.
+    /// The warp generators return 128 for no change.
+    /// Other values move the point up to strength cells in either direction.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::generator::generator1d::Generator1d;
+    ///# use ofws_core::data::math::generator::generator2d::Generator2d;
+    /// let source = || Generator2d::new_index(10, 10);
+    /// let warp = |x| Generator2d::new_apply_to_x(Generator1d::new_lookup_table(vec![x]).unwrap());
+    /// let unwarped = Generator2d::new_warp(source(), warp(255), warp(0), 0);
+    /// let warped = Generator2d::new_warp(source(), warp(255), warp(0), 2);
+    ///
+    /// for y in 0..10 {
+    ///     for x in 0..10 {
+    ///         assert_eq!(unwarped.generate(x, y), source().generate(x, y));
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(warped.generate(3, 5), source().generate(5, 3));
+    /// assert_eq!(warped.generate(0, 0), source().generate(2, 0));
+    /// ```
+    ///
+    /// The maximum offset is the same in both directions:
+    ///
+    /// ```
+    ///# use ofws_core::data::math::generator::generator1d::Generator1d;
+    ///# use ofws_core::data::math::generator::generator2d::Generator2d;
+    /// let source = || Generator2d::new_index(20, 20);
+    /// let warp = |x| Generator2d::new_apply_to_x(Generator1d::new_lookup_table(vec![x]).unwrap());
+    /// let positive = Generator2d::new_warp(source(), warp(255), warp(255), 7);
+    /// let negative = Generator2d::new_warp(source(), warp(0), warp(0), 7);
+    ///
+    /// assert_eq!(positive.generate(10, 10), source().generate(17, 17));
+    /// assert_eq!(negative.generate(10, 10), source().generate(3, 3));
+    /// ```
+    Warp {
+        source: Box<Generator2d>,
+        warp_x: Box<Generator2d>,
+        warp_y: Box<Generator2d>,
+        strength: u32,
+    },
+    /// Generates cellular noise for each 2d point.
+    Worley(Worley),
 }
 
 impl Generator2d {
@@ -110,10 +334,72 @@ impl Generator2d {
     }
 
     pub fn new_apply_to_distance(generator: Generator1d, x: u32, y: u32) -> Generator2d {
+        Generator2d::new_apply_to_metric_distance(generator, x, y, DistanceMetric::Euclidean)
+    }
+
+    pub fn new_apply_to_metric_distance(
+        generator: Generator1d,
+        x: u32,
+        y: u32,
+        metric: DistanceMetric,
+    ) -> Generator2d {
         ApplyToDistance {
             generator,
             center_x: x,
             center_y: y,
+            metric,
+        }
+    }
+
+    pub fn new_apply_to_ellipse_distance(
+        generator: Generator1d,
+        center_x: u32,
+        center_y: u32,
+        radius_x: u32,
+        radius_y: u32,
+    ) -> Result<Generator2d, Generator2dError> {
+        if radius_x == 0 || radius_y == 0 {
+            return Err(Generator2dError::ZeroRadius);
+        }
+
+        Ok(ApplyToEllipseDistance {
+            generator,
+            center_x,
+            center_y,
+            radius_x,
+            radius_y,
+        })
+    }
+
+    pub fn new_cached(width: u32, height: u32, inner: Generator2d) -> Generator2d {
+        Cached {
+            size: Size2d::new(width, height),
+            inner: Box::new(inner),
+            cache: OnceLock::new(),
+        }
+    }
+
+    pub fn new_checkerboard(
+        cell_size: u32,
+        low: u8,
+        high: u8,
+    ) -> Result<Generator2d, Generator2dError> {
+        if cell_size == 0 {
+            return Err(Generator2dError::ZeroCellSize);
+        }
+
+        Ok(Checkerboard {
+            cell_size,
+            low,
+            high,
+        })
+    }
+
+    pub fn new_combine(a: Generator2d, b: Generator2d, op: CombineOp) -> Generator2d {
+        Combine {
+            a: Box::new(a),
+            b: Box::new(b),
+            op,
         }
     }
 
@@ -121,6 +407,56 @@ impl Generator2d {
         IndexGenerator(Size2d::new(width, height))
     }
 
+    pub fn new_stripes(
+        period: u32,
+        axis: Axis,
+        low: u8,
+        high: u8,
+    ) -> Result<Generator2d, Generator2dError> {
+        if period == 0 {
+            return Err(Generator2dError::ZeroPeriod);
+        }
+
+        Ok(Stripes {
+            period,
+            axis,
+            low,
+            high,
+        })
+    }
+
+    pub fn new_voronoi_regions(
+        seed: u32,
+        cell_size: u32,
+        num_regions: u8,
+    ) -> Result<Generator2d, Generator2dError> {
+        if cell_size == 0 {
+            return Err(Generator2dError::ZeroCellSize);
+        } else if num_regions == 0 {
+            return Err(Generator2dError::ZeroRegions);
+        }
+
+        Ok(VoronoiRegions {
+            seed,
+            cell_size,
+            num_regions,
+        })
+    }
+
+    pub fn new_warp(
+        source: Generator2d,
+        warp_x: Generator2d,
+        warp_y: Generator2d,
+        strength: u32,
+    ) -> Generator2d {
+        Warp {
+            source: Box::new(source),
+            warp_x: Box::new(warp_x),
+            warp_y: Box::new(warp_y),
+            strength,
+        }
+    }
+
     /// Generates a value for a 2d point (x,y).
     pub fn generate(&self, x: u32, y: u32) -> u8 {
         match self {
@@ -130,33 +466,246 @@ impl Generator2d {
                 generator,
                 center_x,
                 center_y,
+                metric,
+            } => {
+                let distance = metric.calculate(*center_x, *center_y, x, y);
+                generator.generate(distance)
+            }
+            ApplyToEllipseDistance {
+                generator,
+                center_x,
+                center_y,
+                radius_x,
+                radius_y,
             } => {
-                let distance = calculate_distance(*center_x, *center_y, x, y);
+                let distance =
+                    calculate_ellipse_distance(*center_x, *center_y, *radius_x, *radius_y, x, y);
                 generator.generate(distance)
             }
+            Cached { size, inner, cache } => match size.to_index(x, y) {
+                Some(index) => cache.get_or_init(|| generate_all(size, inner))[index],
+                None => inner.generate(x, y),
+            },
+            Checkerboard {
+                cell_size,
+                low,
+                high,
+            } => alternate(x / cell_size + y / cell_size, *low, *high),
+            Combine { a, b, op } => op.combine(a.generate(x, y), b.generate(x, y)),
             IndexGenerator(size) => size.saturating_to_index(x, y) as u8,
             Noise(noise) => noise.generate2d(x, y),
+            Stripes {
+                period,
+                axis,
+                low,
+                high,
+            } => alternate(select(*axis, x, y) / period, *low, *high),
+            Turbulence(turbulence) => turbulence.generate2d(x, y),
+            VoronoiRegions {
+                seed,
+                cell_size,
+                num_regions,
+            } => calculate_region(*seed, *cell_size, *num_regions, x, y),
+            Warp {
+                source,
+                warp_x,
+                warp_y,
+                strength,
+            } => {
+                let warped_x = warp(x, warp_x.generate(x, y), *strength);
+                let warped_y = warp(y, warp_y.generate(x, y), *strength);
+                source.generate(warped_x, warped_y)
+            }
+            Worley(worley) => worley.generate2d(x, y),
+        }
+    }
+
+    /// Generates a value for a 2d point (x,y) like [`Generator2d::generate`],
+    /// but asserts in debug builds that no value wraps around during the cast to u8.
+    /// The panic message contains the offending generator.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::generator::generator2d::Generator2d;
+    /// let generator = Generator2d::new_index(2, 3);
+    ///
+    /// assert_eq!(generator.generate_checked(1, 2), 5);
+    /// ```
+    pub fn generate_checked(&self, x: u32, y: u32) -> u8 {
+        match self {
+            ApplyToX(generator) => generator.generate_checked(x),
+            ApplyToY(generator) => generator.generate_checked(y),
+            ApplyToDistance {
+                generator,
+                center_x,
+                center_y,
+                metric,
+            } => {
+                let distance = metric.calculate(*center_x, *center_y, x, y);
+                generator.generate_checked(distance)
+            }
+            ApplyToEllipseDistance {
+                generator,
+                center_x,
+                center_y,
+                radius_x,
+                radius_y,
+            } => {
+                let distance =
+                    calculate_ellipse_distance(*center_x, *center_y, *radius_x, *radius_y, x, y);
+                generator.generate_checked(distance)
+            }
+            Cached { inner, .. } => inner.generate_checked(x, y),
+            Checkerboard {
+                cell_size,
+                low,
+                high,
+            } => alternate(x / cell_size + y / cell_size, *low, *high),
+            Combine { a, b, op } => op.combine(a.generate_checked(x, y), b.generate_checked(x, y)),
+            IndexGenerator(size) => checked_cast(
+                size.saturating_to_index(x, y) as f64,
+                "Generator2d::IndexGenerator",
+            ),
+            Noise(noise) => checked_cast(noise.calculate2d(x, y), "Generator2d::Noise"),
+            Stripes {
+                period,
+                axis,
+                low,
+                high,
+            } => alternate(select(*axis, x, y) / period, *low, *high),
+            Turbulence(turbulence) => {
+                checked_cast(turbulence.calculate2d(x, y), "Generator2d::Turbulence")
+            }
+            VoronoiRegions {
+                seed,
+                cell_size,
+                num_regions,
+            } => calculate_region(*seed, *cell_size, *num_regions, x, y),
+            Warp {
+                source,
+                warp_x,
+                warp_y,
+                strength,
+            } => {
+                let warped_x = warp(x, warp_x.generate_checked(x, y), *strength);
+                let warped_y = warp(y, warp_y.generate_checked(x, y), *strength);
+                source.generate_checked(warped_x, warped_y)
+            }
+            Worley(worley) => worley.generate2d(x, y),
         }
     }
 }
 
+/// Returns low for even indices & high for odd ones.
+fn alternate(index: u32, low: u8, high: u8) -> u8 {
+    if index % 2 == 1 {
+        high
+    } else {
+        low
+    }
+}
+
+fn select(axis: Axis, x: u32, y: u32) -> u32 {
+    match axis {
+        Axis::X => x,
+        Axis::Y => y,
+    }
+}
+
+/// Returns the region id of the nearest feature point.
+fn calculate_region(seed: u32, cell_size: u32, num_regions: u8, x: u32, y: u32) -> u8 {
+    let cell_size = cell_size as i64;
+    let x = x as i64;
+    let y = y as i64;
+    let cell_x = x / cell_size;
+    let cell_y = y / cell_size;
+    let mut min_distance = i64::MAX;
+    let mut nearest = (cell_x, cell_y);
+
+    for dy in -1..=1 {
+        for dx in -1..=1 {
+            let (neighbor_x, neighbor_y) = (cell_x + dx, cell_y + dy);
+            let hash = hash2d(seed, neighbor_x as i32, neighbor_y as i32) as i64;
+            let point_x = neighbor_x * cell_size + (hash & 0xFFFF) % cell_size;
+            let point_y = neighbor_y * cell_size + (hash >> 16) % cell_size;
+            let distance = (point_x - x).pow(2) + (point_y - y).pow(2);
+
+            if distance < min_distance {
+                min_distance = distance;
+                nearest = (neighbor_x, neighbor_y);
+            }
+        }
+    }
+
+    let hash = hash2d(seed.wrapping_add(1), nearest.0 as i32, nearest.1 as i32);
+    (hash % num_regions as u32) as u8
+}
+
+/// Moves a coordinate by up to strength in either direction. Stops at 0.
+///
+/// 0 moves by -strength & 255 by +strength, so positive offsets are scaled by 127 instead of 128.
+fn warp(value: u32, warp: u8, strength: u32) -> u32 {
+    let difference = warp as i64 - 128;
+    let offset = if difference >= 0 {
+        difference * strength as i64 / 127
+    } else {
+        difference * strength as i64 / 128
+    };
+    (value as i64 + offset).max(0) as u32
+}
+
+fn generate_all(size: &Size2d, generator: &Generator2d) -> Vec<u8> {
+    let mut values = Vec::with_capacity(size.get_area());
+
+    for y in 0..size.height() {
+        for x in 0..size.width() {
+            values.push(generator.generate(x, y));
+        }
+    }
+
+    values
+}
+
 /// For serializing, deserializing & validating [`Generator2d`].
 ///
 ///```
+///# use ofws_core::data::math::distance::DistanceMetric;
 ///# use ofws_core::data::math::generator::generator1d::Generator1dData::InputAsOutput;
-///# use ofws_core::data::math::generator::generator2d::{Generator2dData, assert_eq};
+///# use ofws_core::data::math::generator::generator2d::{Axis, Generator2dData, assert_eq};
 ///# use ofws_core::data::math::generator::gradient::Gradient;
 ///# use ofws_core::data::math::generator::noise::NoiseData;
+///# use ofws_core::data::math::generator::turbulence::TurbulenceData;
+///# use ofws_core::data::math::generator::worley::{WorleyData, WorleyDistance};
+///# use ofws_core::data::math::operation::CombineOp;
 ///# use ofws_core::data::math::size2d::Size2d;
-/// let noise_data = NoiseData { seed: 300, scale: 5, min_value: 10, max_value: 128 };
+/// let noise_data = NoiseData::new(300, 5.0, 10, 128);
+/// let turbulence_data = TurbulenceData { seed: 300, scale: 5.0, octaves: 4, min_value: 10, max_value: 128 };
+/// let worley_data = WorleyData { seed: 300, scale: 5.0, distance: WorleyDistance::F1, min_value: 10, max_value: 128 };
 ///
 /// assert_eq(Generator2dData::ApplyToX(InputAsOutput));
 /// assert_eq(Generator2dData::ApplyToY(InputAsOutput));
-/// assert_eq(Generator2dData::ApplyToDistance { generator: InputAsOutput, center_x: 10, center_y: 20});
+/// assert_eq(Generator2dData::ApplyToDistance { generator: InputAsOutput, center_x: 10, center_y: 20, metric: DistanceMetric::Manhattan });
+/// assert_eq(Generator2dData::ApplyToEllipseDistance { generator: InputAsOutput, center_x: 10, center_y: 20, radius_x: 8, radius_y: 4 });
+/// assert_eq(Generator2dData::Cached { size: Size2d::new(3, 5), inner: Box::new(Generator2dData::ApplyToX(InputAsOutput)) });
+/// assert_eq(Generator2dData::Checkerboard { cell_size: 4, low: 10, high: 200 });
+/// assert_eq(Generator2dData::Combine {
+///     a: Box::new(Generator2dData::ApplyToX(InputAsOutput)),
+///     b: Box::new(Generator2dData::ApplyToY(InputAsOutput)),
+///     op: CombineOp::Average,
+/// });
 /// assert_eq(Generator2dData::IndexGenerator(Size2d::new(3, 5)));
 /// assert_eq(Generator2dData::Noise(noise_data));
+/// assert_eq(Generator2dData::Stripes { period: 4, axis: Axis::Y, low: 10, high: 200 });
+/// assert_eq(Generator2dData::Turbulence(turbulence_data));
+/// assert_eq(Generator2dData::VoronoiRegions { seed: 300, cell_size: 5, num_regions: 7 });
+/// assert_eq(Generator2dData::Warp {
+///     source: Box::new(Generator2dData::IndexGenerator(Size2d::new(3, 5))),
+///     warp_x: Box::new(Generator2dData::ApplyToX(InputAsOutput)),
+///     warp_y: Box::new(Generator2dData::ApplyToY(InputAsOutput)),
+///     strength: 7,
+/// });
+/// assert_eq(Generator2dData::Worley(worley_data));
 ///```
-#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub enum Generator2dData {
     ApplyToX(Generator1dData),
     ApplyToY(Generator1dData),
@@ -164,9 +713,51 @@ pub enum Generator2dData {
         generator: Generator1dData,
         center_x: u32,
         center_y: u32,
+        #[serde(default)]
+        metric: DistanceMetric,
+    },
+    ApplyToEllipseDistance {
+        generator: Generator1dData,
+        center_x: u32,
+        center_y: u32,
+        radius_x: u32,
+        radius_y: u32,
+    },
+    Cached {
+        size: Size2d,
+        inner: Box<Generator2dData>,
+    },
+    Checkerboard {
+        cell_size: u32,
+        low: u8,
+        high: u8,
+    },
+    Combine {
+        a: Box<Generator2dData>,
+        b: Box<Generator2dData>,
+        op: CombineOp,
     },
     IndexGenerator(Size2d),
     Noise(NoiseData),
+    Stripes {
+        period: u32,
+        axis: Axis,
+        low: u8,
+        high: u8,
+    },
+    Turbulence(TurbulenceData),
+    VoronoiRegions {
+        seed: u32,
+        cell_size: u32,
+        num_regions: u8,
+    },
+    Warp {
+        source: Box<Generator2dData>,
+        warp_x: Box<Generator2dData>,
+        warp_y: Box<Generator2dData>,
+        strength: u32,
+    },
+    Worley(WorleyData),
 }
 
 type Data = Generator2dData;
@@ -182,14 +773,66 @@ impl TryFrom<Generator2dData> for Generator2d {
                 generator,
                 center_x,
                 center_y,
+                metric,
             } => {
                 let generator: Generator1d = generator.try_into()?;
-                Ok(Generator2d::new_apply_to_distance(
-                    generator, center_x, center_y,
+                Ok(Generator2d::new_apply_to_metric_distance(
+                    generator, center_x, center_y, metric,
                 ))
             }
+            Data::ApplyToEllipseDistance {
+                generator,
+                center_x,
+                center_y,
+                radius_x,
+                radius_y,
+            } => Generator2d::new_apply_to_ellipse_distance(
+                generator.try_into()?,
+                center_x,
+                center_y,
+                radius_x,
+                radius_y,
+            ),
+            Data::Cached { size, inner } => {
+                let inner: Generator2d = (*inner).try_into()?;
+                Ok(Generator2d::new_cached(size.width(), size.height(), inner))
+            }
+            Data::Checkerboard {
+                cell_size,
+                low,
+                high,
+            } => Generator2d::new_checkerboard(cell_size, low, high),
+            Data::Combine { a, b, op } => Ok(Generator2d::new_combine(
+                (*a).try_into()?,
+                (*b).try_into()?,
+                op,
+            )),
             Data::IndexGenerator(size) => Ok(IndexGenerator(size)),
             Data::Noise(data) => Ok(Noise(data.try_into()?)),
+            Data::Stripes {
+                period,
+                axis,
+                low,
+                high,
+            } => Generator2d::new_stripes(period, axis, low, high),
+            Data::Turbulence(data) => Ok(Turbulence(data.try_into()?)),
+            Data::VoronoiRegions {
+                seed,
+                cell_size,
+                num_regions,
+            } => Generator2d::new_voronoi_regions(seed, cell_size, num_regions),
+            Data::Warp {
+                source,
+                warp_x,
+                warp_y,
+                strength,
+            } => Ok(Generator2d::new_warp(
+                (*source).try_into()?,
+                (*warp_x).try_into()?,
+                (*warp_y).try_into()?,
+                strength,
+            )),
+            Data::Worley(data) => Ok(Worley(data.try_into()?)),
         }
     }
 }
@@ -203,13 +846,79 @@ impl From<&Generator2d> for Generator2dData {
                 generator,
                 center_x,
                 center_y,
+                metric,
             } => Data::ApplyToDistance {
                 generator: generator.into(),
                 center_x: *center_x,
                 center_y: *center_y,
+                metric: *metric,
+            },
+            ApplyToEllipseDistance {
+                generator,
+                center_x,
+                center_y,
+                radius_x,
+                radius_y,
+            } => Data::ApplyToEllipseDistance {
+                generator: generator.into(),
+                center_x: *center_x,
+                center_y: *center_y,
+                radius_x: *radius_x,
+                radius_y: *radius_y,
+            },
+            Cached { size, inner, .. } => Data::Cached {
+                size: *size,
+                inner: Box::new(inner.as_ref().into()),
+            },
+            Checkerboard {
+                cell_size,
+                low,
+                high,
+            } => Data::Checkerboard {
+                cell_size: *cell_size,
+                low: *low,
+                high: *high,
+            },
+            Combine { a, b, op } => Data::Combine {
+                a: Box::new(a.as_ref().into()),
+                b: Box::new(b.as_ref().into()),
+                op: *op,
             },
             IndexGenerator(size) => Data::IndexGenerator(*size),
             Noise(noise) => Data::Noise(noise.into()),
+            Stripes {
+                period,
+                axis,
+                low,
+                high,
+            } => Data::Stripes {
+                period: *period,
+                axis: *axis,
+                low: *low,
+                high: *high,
+            },
+            Turbulence(turbulence) => Data::Turbulence(turbulence.into()),
+            VoronoiRegions {
+                seed,
+                cell_size,
+                num_regions,
+            } => Data::VoronoiRegions {
+                seed: *seed,
+                cell_size: *cell_size,
+                num_regions: *num_regions,
+            },
+            Warp {
+                source,
+                warp_x,
+                warp_y,
+                strength,
+            } => Data::Warp {
+                source: Box::new(source.as_ref().into()),
+                warp_x: Box::new(warp_x.as_ref().into()),
+                warp_y: Box::new(warp_y.as_ref().into()),
+                strength: *strength,
+            },
+            Worley(worley) => Data::Worley(worley.into()),
         }
     }
 }
@@ -219,3 +928,48 @@ pub fn assert_eq(data: Generator2dData) {
     let result: Generator2dData = (&generator).into();
     assert_eq!(result, data)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_cache(generator: &mut Generator2d) -> &mut OnceLock<Vec<u8>> {
+        match generator {
+            Cached { cache, .. } => cache,
+            _ => panic!("No cached generator!"),
+        }
+    }
+
+    #[test]
+    fn test_cached_values_are_reused() {
+        let mut cached = Generator2d::new_cached(2, 3, Generator2d::new_index(2, 3));
+
+        assert!(get_cache(&mut cached).get().is_none());
+        assert_eq!(cached.generate(1, 2), 5);
+
+        get_cache(&mut cached).get_mut().unwrap()[5] = 42;
+
+        assert_eq!(cached.generate(1, 2), 42);
+    }
+
+    #[test]
+    fn test_cached_passes_outside_points_to_inner() {
+        let mut cached = Generator2d::new_cached(2, 3, Generator2d::new_index(2, 3));
+
+        let expected = Generator2d::new_index(2, 3).generate(5, 1);
+
+        assert_eq!(cached.generate(5, 1), expected);
+        assert!(get_cache(&mut cached).get().is_none());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Generator1d::InputAsOutput generated 300 outside of [0, 255.999]!")]
+    fn test_generate_checked_with_overflowing_child() {
+        let generator = Generator2d::new_apply_to_distance(Generator1d::InputAsOutput, 0, 0);
+
+        assert_eq!(generator.generate(300, 0), 44);
+
+        generator.generate_checked(300, 0);
+    }
+}
@@ -12,19 +12,47 @@ pub struct Gradient {
 
 impl Gradient {
     /// Generates the gradient.
+    ///
+    /// A length of 0 returns the end value for any input after the start:
+    ///
+    /// ```
+    ///# use ofws_core::data::math::generator::gradient::Gradient;
+    /// let gradient = Gradient::new(10, 200, 100, 0);
+    ///
+    /// assert_eq!(gradient.generate(100), 10);
+    /// assert_eq!(gradient.generate(101), 200);
+    /// ```
     pub fn generate(&self, input: u32) -> u8 {
         if input <= self.start {
             return self.value_start;
         }
-        let distance = (input - self.start) as f32;
-        let factor = distance / self.length as f32;
-        lerp(self.value_start, self.value_end, factor)
+        self.interpolate(input - self.start)
     }
 
     /// Generates the absolute gradient.
+    ///
+    /// A length of 0 returns the end value for any input except the start:
+    ///
+    /// ```
+    ///# use ofws_core::data::math::generator::gradient::Gradient;
+    /// let gradient = Gradient::new(10, 200, 100, 0);
+    ///
+    /// assert_eq!(gradient.generate_absolute(99), 200);
+    /// assert_eq!(gradient.generate_absolute(100), 10);
+    /// assert_eq!(gradient.generate_absolute(101), 200);
+    /// ```
     pub fn generate_absolute(&self, input: u32) -> u8 {
-        let distance = abs_diff(self.start, input) as f32;
-        let factor = distance / self.length as f32;
+        self.interpolate(abs_diff(self.start, input))
+    }
+
+    fn interpolate(&self, distance: u32) -> u8 {
+        if distance == 0 {
+            return self.value_start;
+        } else if self.length == 0 {
+            return self.value_end;
+        }
+
+        let factor = distance as f32 / self.length as f32;
         lerp(self.value_start, self.value_end, factor)
     }
 }
@@ -2,3 +2,16 @@ pub mod generator1d;
 pub mod generator2d;
 pub mod gradient;
 pub mod noise;
+pub mod turbulence;
+pub mod worley;
+
+/// Casts a generated value to u8, but asserts in debug builds that it doesn't wrap around.
+pub(crate) fn checked_cast(value: f64, generator: &str) -> u8 {
+    debug_assert!(
+        (0.0..256.0).contains(&value),
+        "{} generated {} outside of [0, 255.999]!",
+        generator,
+        value
+    );
+    value as u8
+}
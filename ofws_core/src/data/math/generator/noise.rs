@@ -1,4 +1,4 @@
-use noise::{NoiseFn, Seedable, SuperSimplex};
+use noise::{NoiseFn, OpenSimplex, Perlin, Seedable, SuperSimplex};
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 
@@ -6,96 +6,448 @@ use std::convert::TryFrom;
 pub enum NoiseError {
     NegativeScale,
     MinBiggerThanMax(u8, u8),
+    NoOctaves,
+    NegativePersistence,
+    NegativeLacunarity,
+}
+
+/// The algorithms of [`noise`], that can be used by [`Noise`].
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum NoiseAlgorithm {
+    OpenSimplex,
+    Perlin,
+    #[default]
+    SuperSimplex,
+}
+
+/// How each octave of [`Noise`] is transformed before summing them up.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum NoiseStyle {
+    /// Uses the octaves unchanged.
+    #[default]
+    Standard,
+    /// Uses `1 - abs(value)` for sharp crests along the zero-crossings, e.g. for mountain ridges.
+    Ridged,
+    /// Uses `abs(value)` for puffy shapes, e.g. for clouds.
+    Billow,
+}
+
+impl NoiseStyle {
+    /// Transforms a value in the range [-1, 1] & keeps it in that range.
+    fn transform(&self, value: f64) -> f64 {
+        match self {
+            NoiseStyle::Standard => value,
+            NoiseStyle::Ridged => renormalize(1.0 - value.abs()),
+            NoiseStyle::Billow => renormalize(value.abs()),
+        }
+    }
+}
+
+/// Maps the range [0, 1] of the ridged & billow octaves back to the range [-1, 1] of the standard ones.
+fn renormalize(value: f64) -> f64 {
+    2.0 * value - 1.0
+}
+
+/// The seeded noise functions of the different algorithms.
+enum Algo {
+    OpenSimplex(OpenSimplex),
+    Perlin(Perlin),
+    SuperSimplex(SuperSimplex),
+}
+
+impl Algo {
+    fn new(algorithm: NoiseAlgorithm, seed: u32) -> Algo {
+        match algorithm {
+            NoiseAlgorithm::OpenSimplex => Algo::OpenSimplex(OpenSimplex::new().set_seed(seed)),
+            NoiseAlgorithm::Perlin => Algo::Perlin(Perlin::new().set_seed(seed)),
+            NoiseAlgorithm::SuperSimplex => Algo::SuperSimplex(SuperSimplex::new().set_seed(seed)),
+        }
+    }
+
+    fn get(&self, point: [f64; 2]) -> f64 {
+        match self {
+            Algo::OpenSimplex(algo) => algo.get(point),
+            Algo::Perlin(algo) => algo.get(point),
+            Algo::SuperSimplex(algo) => algo.get(point),
+        }
+    }
 }
 
 /// Hide the noise functions from [`noise`].
+///
+/// Sums multiple octaves of noise (fractal Brownian motion).
+/// Each octave multiplies the amplitude by the persistence & the frequency by the lacunarity.
 pub struct Noise {
-    algo: Box<SuperSimplex>,
+    algorithm: NoiseAlgorithm,
+    algo: Algo,
+    style: NoiseStyle,
+    seed: u32,
     scale: f64,
+    min_value: u8,
+    max_value: u8,
+    offset_x: f64,
+    offset_y: f64,
     base: f64,
     factor: f64,
+    octaves: u8,
+    persistence: f64,
+    lacunarity: f64,
 }
 
 impl Noise {
-    /// Try to create a Noise. Fails if scale is negative:
+    /// Try to create a Noise. Fails if scale is not positive & finite:
     ///
     ///```
     ///# use ofws_core::data::math::generator::noise::Noise;
-    /// assert!(Noise::new(0, -1.0, 0, 255).is_err())
+    /// assert!(Noise::new(0, -1.0, 0, 255, 1, 0.5, 2.0).is_err());
+    /// assert!(Noise::new(0, f64::NAN, 0, 255, 1, 0.5, 2.0).is_err());
+    /// assert!(Noise::new(0, f64::INFINITY, 0, 255, 1, 0.5, 2.0).is_err());
     ///```
     /// Also fails if min_value >= max_value:
     ///
     ///```
     ///# use ofws_core::data::math::generator::noise::Noise;
-    /// assert!(Noise::new(0, 5.0, 200, 105).is_err())
+    /// assert!(Noise::new(0, 5.0, 200, 105, 1, 0.5, 2.0).is_err())
+    ///```
+    /// Also fails without octaves:
+    ///
+    ///```
+    ///# use ofws_core::data::math::generator::noise::{Noise, NoiseError};
+    /// assert_eq!(Noise::new(0, 5.0, 0, 255, 0, 0.5, 2.0).err(), Some(NoiseError::NoOctaves))
     ///```
+    /// Also fails if persistence is not positive & finite:
     ///
-    pub fn new(seed: u32, scale: f64, min_value: u8, max_value: u8) -> Result<Noise, NoiseError> {
-        if scale <= 0.0 {
+    ///```
+    ///# use ofws_core::data::math::generator::noise::{Noise, NoiseError};
+    /// for persistence in [0.0, -0.5, f64::NAN, f64::INFINITY] {
+    ///     assert_eq!(Noise::new(0, 5.0, 0, 255, 2, persistence, 2.0).err(), Some(NoiseError::NegativePersistence))
+    /// }
+    ///```
+    /// Also fails if lacunarity is not positive & finite:
+    ///
+    ///```
+    ///# use ofws_core::data::math::generator::noise::{Noise, NoiseError};
+    /// for lacunarity in [0.0, -2.0, f64::NAN, f64::INFINITY] {
+    ///     assert_eq!(Noise::new(0, 5.0, 0, 255, 2, 0.5, lacunarity).err(), Some(NoiseError::NegativeLacunarity))
+    /// }
+    ///```
+    ///
+    pub fn new(
+        seed: u32,
+        scale: f64,
+        min_value: u8,
+        max_value: u8,
+        octaves: u8,
+        persistence: f64,
+        lacunarity: f64,
+    ) -> Result<Noise, NoiseError> {
+        if !scale.is_finite() || scale <= 0.0 {
             return Err(NoiseError::NegativeScale);
         } else if min_value >= max_value {
             return Err(NoiseError::MinBiggerThanMax(min_value, max_value));
+        } else if octaves == 0 {
+            return Err(NoiseError::NoOctaves);
+        } else if !persistence.is_finite() || persistence <= 0.0 {
+            return Err(NoiseError::NegativePersistence);
+        } else if !lacunarity.is_finite() || lacunarity <= 0.0 {
+            return Err(NoiseError::NegativeLacunarity);
         }
 
         Ok(Noise {
-            algo: Box::new(SuperSimplex::new().set_seed(seed)),
+            algorithm: NoiseAlgorithm::default(),
+            algo: Algo::new(NoiseAlgorithm::default(), seed),
+            style: NoiseStyle::default(),
+            seed,
             scale,
+            min_value,
+            max_value,
+            offset_x: 0.0,
+            offset_y: 0.0,
             base: 1.0 + min_value as f64 / 255.0,
             factor: (max_value - min_value) as f64 / 2.0,
+            octaves,
+            persistence,
+            lacunarity,
         })
     }
 
+    /// Returns the same noise, but with another algorithm. Uses [`SuperSimplex`] by default.
+    ///
+    ///```
+    ///# use ofws_core::data::math::generator::noise::{Noise, NoiseAlgorithm};
+    /// let noise = Noise::new(42, 10.0, 0, 255, 1, 0.5, 2.0).unwrap();
+    /// let perlin = Noise::new(42, 10.0, 0, 255, 1, 0.5, 2.0).unwrap().with_algorithm(NoiseAlgorithm::Perlin);
+    ///
+    /// assert!((0..20).any(|x| noise.generate2d(x, 3) != perlin.generate2d(x, 3)));
+    ///```
+    pub fn with_algorithm(self, algorithm: NoiseAlgorithm) -> Noise {
+        Noise {
+            algorithm,
+            algo: Algo::new(algorithm, self.seed),
+            ..self
+        }
+    }
+
+    /// Returns the same noise, but with another style. Uses [`NoiseStyle::Standard`] by default.
+    ///
+    /// Ridged noise has its highest values along the zero-crossings of the standard noise:
+    ///
+    ///```
+    ///# use ofws_core::data::math::generator::noise::{Noise, NoiseStyle};
+    /// let standard = Noise::new(42, 5.0, 0, 255, 1, 0.5, 2.0).unwrap();
+    /// let ridged = Noise::new(42, 5.0, 0, 255, 1, 0.5, 2.0).unwrap().with_style(NoiseStyle::Ridged);
+    /// let mut zero_crossings = 0;
+    ///
+    /// for y in 0..20 {
+    ///     for x in 0..20 {
+    ///         let value = standard.generate2d(x, y);
+    ///
+    ///         if value >= 122 && value <= 132 {
+    ///             assert!(ridged.generate2d(x, y) > 240);
+    ///             zero_crossings += 1;
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// assert!(zero_crossings > 0);
+    ///```
+    pub fn with_style(self, style: NoiseStyle) -> Noise {
+        Noise { style, ..self }
+    }
+
+    /// Returns the same noise, but with an offset added to the scaled coordinates.
+    /// So maps at different positions of the world can sample the same noise.
+    ///
+    ///```
+    ///# use ofws_core::data::math::generator::noise::Noise;
+    /// let left = Noise::new(42, 5.0, 0, 255, 1, 0.5, 2.0).unwrap();
+    /// let right = Noise::new(42, 5.0, 0, 255, 1, 0.5, 2.0).unwrap().with_offset(2.0, 0.0);
+    ///
+    /// for y in 0..10 {
+    ///     assert_eq!(left.generate2d(10, y), right.generate2d(0, y));
+    /// }
+    ///```
+    pub fn with_offset(self, offset_x: f64, offset_y: f64) -> Noise {
+        Noise {
+            offset_x,
+            offset_y,
+            ..self
+        }
+    }
+
     /// Generates noise for an input.
     pub fn generate1d(&self, input: u32) -> u8 {
-        let input = input as f64 / self.scale;
-        let positive_value = self.algo.get([input, 0.0]) + self.base;
-        (positive_value * self.factor) as u8
+        self.calculate1d(input) as u8
     }
 
     /// Generates noise for a 2d point (x,y).
     pub fn generate2d(&self, x: u32, y: u32) -> u8 {
-        let x = x as f64 / self.scale;
-        let y = y as f64 / self.scale;
-        let positive_value = self.algo.get([x, y]) + self.base;
-        (positive_value * self.factor) as u8
+        self.calculate2d(x, y) as u8
+    }
+
+    /// Calculates the noise for an input before the cast to u8.
+    pub(crate) fn calculate1d(&self, input: u32) -> f64 {
+        let input = input as f64 / self.scale + self.offset_x;
+        let positive_value = self.sum_octaves(input, self.offset_y) + self.base;
+        positive_value * self.factor
+    }
+
+    /// Calculates the noise for a 2d point (x,y) before the cast to u8.
+    pub(crate) fn calculate2d(&self, x: u32, y: u32) -> f64 {
+        let x = x as f64 / self.scale + self.offset_x;
+        let y = y as f64 / self.scale + self.offset_y;
+        let positive_value = self.sum_octaves(x, y) + self.base;
+        positive_value * self.factor
+    }
+
+    /// Sums the octaves & normalizes the result back to the range of a single octave.
+    fn sum_octaves(&self, x: f64, y: f64) -> f64 {
+        let mut frequency = 1.0;
+        let mut amplitude = 1.0;
+        let mut sum = 0.0;
+        let mut max_sum = 0.0;
+
+        for _ in 0..self.octaves {
+            let value = self.algo.get([x * frequency, y * frequency]);
+            sum += self.style.transform(value) * amplitude;
+            max_sum += amplitude;
+            frequency *= self.lacunarity;
+            amplitude *= self.persistence;
+        }
+
+        sum / max_sum
     }
 }
 
 /// For serializing, deserializing & validating [`Noise`].
 ///
 ///```
-///# use ofws_core::data::math::generator::noise::{NoiseData, Noise};
+///# use ofws_core::data::math::generator::noise::{NoiseAlgorithm, NoiseData, NoiseStyle, Noise};
 ///# use std::convert::TryInto;
 ///
-/// let data = NoiseData { seed: 300, scale: 5, min_value: 10, max_value: 128 };
+/// let data = NoiseData {
+///     seed: 300,
+///     scale: 5.0,
+///     min_value: 10,
+///     max_value: 128,
+///     octaves: 4,
+///     persistence: 0.4,
+///     lacunarity: 2.5,
+///     algorithm: NoiseAlgorithm::OpenSimplex,
+///     style: NoiseStyle::Ridged,
+///     offset_x: 1.5,
+///     offset_y: -20.0,
+/// };
 /// let noise: Noise = data.clone().try_into().unwrap();
 /// let result: NoiseData = (&noise).into();
 /// assert_eq!(data, result)
 ///```
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
 pub struct NoiseData {
     pub seed: u32,
-    pub scale: u32,
+    pub scale: f64,
     pub min_value: u8,
     pub max_value: u8,
+    #[serde(default = "default_octaves")]
+    pub octaves: u8,
+    #[serde(default = "default_persistence")]
+    pub persistence: f64,
+    #[serde(default = "default_lacunarity")]
+    pub lacunarity: f64,
+    #[serde(default)]
+    pub algorithm: NoiseAlgorithm,
+    #[serde(default)]
+    pub style: NoiseStyle,
+    #[serde(default)]
+    pub offset_x: f64,
+    #[serde(default)]
+    pub offset_y: f64,
+}
+
+impl NoiseData {
+    /// Returns the data of a noise with a single octave, the default algorithm & style and no offset.
+    pub fn new(seed: u32, scale: f64, min_value: u8, max_value: u8) -> NoiseData {
+        NoiseData {
+            seed,
+            scale,
+            min_value,
+            max_value,
+            octaves: default_octaves(),
+            persistence: default_persistence(),
+            lacunarity: default_lacunarity(),
+            algorithm: NoiseAlgorithm::default(),
+            style: NoiseStyle::default(),
+            offset_x: 0.0,
+            offset_y: 0.0,
+        }
+    }
+}
+
+fn default_octaves() -> u8 {
+    1
+}
+
+fn default_persistence() -> f64 {
+    0.5
+}
+
+fn default_lacunarity() -> f64 {
+    2.0
 }
 
 impl TryFrom<NoiseData> for Noise {
     type Error = NoiseError;
 
     fn try_from(data: NoiseData) -> Result<Self, Self::Error> {
-        Noise::new(data.seed, data.scale as f64, data.min_value, data.max_value)
+        Noise::new(
+            data.seed,
+            data.scale,
+            data.min_value,
+            data.max_value,
+            data.octaves,
+            data.persistence,
+            data.lacunarity,
+        )
+        .map(|noise| {
+            noise
+                .with_algorithm(data.algorithm)
+                .with_style(data.style)
+                .with_offset(data.offset_x, data.offset_y)
+        })
     }
 }
 
 impl From<&Noise> for NoiseData {
     fn from(noise: &Noise) -> Self {
-        let min_value = ((noise.base - 1.0) * 255.0) as u8;
         NoiseData {
-            seed: noise.algo.seed(),
-            scale: noise.scale as u32,
-            min_value,
-            max_value: (noise.factor * 2.0) as u8 + min_value,
+            seed: noise.seed,
+            scale: noise.scale,
+            min_value: noise.min_value,
+            max_value: noise.max_value,
+            octaves: noise.octaves,
+            persistence: noise.persistence,
+            lacunarity: noise.lacunarity,
+            algorithm: noise.algorithm,
+            style: noise.style,
+            offset_x: noise.offset_x,
+            offset_y: noise.offset_y,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryInto;
+
+    #[test]
+    fn test_double_conversion_is_identity() {
+        for seed in [0, 1, 42, u32::MAX] {
+            for scale in [1.0, 2.5, 3.0, 10.0, 1000.0] {
+                for min_value in [0, 1, 3, 7, 10, 33, 100, 128, 200, 254] {
+                    for max_value in [1, 2, 13, 77, 128, 199, 201, 254, 255] {
+                        if min_value >= max_value {
+                            continue;
+                        }
+
+                        let data = NoiseData::new(seed, scale, min_value, max_value);
+                        let noise: Noise = data.try_into().unwrap();
+                        let result: NoiseData = (&noise).into();
+                        let noise: Noise = result.try_into().unwrap();
+                        let result2: NoiseData = (&noise).into();
+
+                        assert_eq!(data, result);
+                        assert_eq!(result, result2);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_fractional_scale_round_trip() {
+        let data = NoiseData::new(42, 2.5, 10, 200);
+        let noise: Noise = data.try_into().unwrap();
+        let string = serde_yaml::to_string(&NoiseData::from(&noise)).unwrap();
+        let result: NoiseData = serde_yaml::from_str(&string).unwrap();
+
+        assert_eq!(result.scale, 2.5);
+        assert_eq!(result, data);
+    }
+
+    #[test]
+    fn test_deserialize_integer_scale() {
+        let result: NoiseData =
+            serde_yaml::from_str("seed: 42\nscale: 10\nmin_value: 0\nmax_value: 255").unwrap();
+
+        assert_eq!(result, NoiseData::new(42, 10.0, 0, 255));
+    }
+
+    #[test]
+    fn test_invalid_scale() {
+        for scale in [0.0, -2.5, f64::NAN, f64::INFINITY] {
+            let result: Result<Noise, NoiseError> = NoiseData::new(0, scale, 0, 255).try_into();
+            assert_eq!(result.err(), Some(NoiseError::NegativeScale));
         }
     }
 }
@@ -0,0 +1,162 @@
+use crate::data::math::generator::noise::NoiseError;
+use noise::{NoiseFn, Seedable, SuperSimplex};
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+
+/// Sums the absolute values of multiple octaves of noise.
+/// Creates marbled or fire-like patterns.
+pub struct Turbulence {
+    algo: Box<SuperSimplex>,
+    scale: f64,
+    octaves: u32,
+    min_value: u8,
+    max_value: u8,
+}
+
+impl Turbulence {
+    /// Try to create a Turbulence. Fails if scale is not positive & finite:
+    ///
+    ///```
+    ///# use ofws_core::data::math::generator::turbulence::Turbulence;
+    /// assert!(Turbulence::new(0, -1.0, 3, 0, 255).is_err());
+    /// assert!(Turbulence::new(0, f64::NAN, 3, 0, 255).is_err());
+    /// assert!(Turbulence::new(0, f64::INFINITY, 3, 0, 255).is_err());
+    ///```
+    /// Also fails without octaves:
+    ///
+    ///```
+    ///# use ofws_core::data::math::generator::turbulence::Turbulence;
+    /// assert!(Turbulence::new(0, 5.0, 0, 0, 255).is_err())
+    ///```
+    /// Also fails if min_value >= max_value:
+    ///
+    ///```
+    ///# use ofws_core::data::math::generator::turbulence::Turbulence;
+    /// assert!(Turbulence::new(0, 5.0, 3, 200, 105).is_err())
+    ///```
+    pub fn new(
+        seed: u32,
+        scale: f64,
+        octaves: u32,
+        min_value: u8,
+        max_value: u8,
+    ) -> Result<Turbulence, NoiseError> {
+        if !scale.is_finite() || scale <= 0.0 {
+            return Err(NoiseError::NegativeScale);
+        } else if octaves == 0 {
+            return Err(NoiseError::NoOctaves);
+        } else if min_value >= max_value {
+            return Err(NoiseError::MinBiggerThanMax(min_value, max_value));
+        }
+
+        Ok(Turbulence {
+            algo: Box::new(SuperSimplex::new().set_seed(seed)),
+            scale,
+            octaves,
+            min_value,
+            max_value,
+        })
+    }
+
+    /// Generates turbulence for a 2d point (x,y).
+    ///
+    ///```
+    ///# use ofws_core::data::math::generator::noise::Noise;
+    ///# use ofws_core::data::math::generator::turbulence::Turbulence;
+    /// let turbulence = Turbulence::new(42, 10.0, 4, 50, 150).unwrap();
+    /// let noise = Noise::new(42, 10.0, 50, 150, 1, 0.5, 2.0).unwrap();
+    /// let mut is_different = false;
+    ///
+    /// for y in 0..20 {
+    ///     for x in 0..20 {
+    ///         let value = turbulence.generate2d(x, y);
+    ///         assert!(value >= 50 && value <= 150);
+    ///         is_different |= value != noise.generate2d(x, y);
+    ///     }
+    /// }
+    ///
+    /// assert!(is_different);
+    ///```
+    pub fn generate2d(&self, x: u32, y: u32) -> u8 {
+        self.calculate2d(x, y) as u8
+    }
+
+    /// Calculates the turbulence for a 2d point (x,y) before the cast to u8.
+    pub(crate) fn calculate2d(&self, x: u32, y: u32) -> f64 {
+        let x = x as f64 / self.scale;
+        let y = y as f64 / self.scale;
+        let mut frequency = 1.0;
+        let mut amplitude = 1.0;
+        let mut sum = 0.0;
+        let mut max_sum = 0.0;
+
+        for _ in 0..self.octaves {
+            sum += self.algo.get([x * frequency, y * frequency]).abs() * amplitude;
+            max_sum += amplitude;
+            frequency *= 2.0;
+            amplitude *= 0.5;
+        }
+
+        let value = (sum / max_sum).min(1.0);
+        let range = (self.max_value - self.min_value) as f64;
+        self.min_value as f64 + value * range
+    }
+}
+
+/// For serializing, deserializing & validating [`Turbulence`].
+///
+///```
+///# use ofws_core::data::math::generator::turbulence::{TurbulenceData, Turbulence};
+///# use std::convert::TryInto;
+///
+/// let data = TurbulenceData { seed: 300, scale: 5.0, octaves: 3, min_value: 10, max_value: 128 };
+/// let turbulence: Turbulence = data.clone().try_into().unwrap();
+/// let result: TurbulenceData = (&turbulence).into();
+/// assert_eq!(data, result)
+///```
+///
+/// A fractional scale is kept:
+///
+///```
+///# use ofws_core::data::math::generator::turbulence::{TurbulenceData, Turbulence};
+///# use std::convert::TryInto;
+///
+/// let data = TurbulenceData { seed: 300, scale: 2.5, octaves: 3, min_value: 10, max_value: 128 };
+/// let turbulence: Turbulence = data.clone().try_into().unwrap();
+/// let result: TurbulenceData = (&turbulence).into();
+/// assert_eq!(data, result)
+///```
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub struct TurbulenceData {
+    pub seed: u32,
+    pub scale: f64,
+    pub octaves: u32,
+    pub min_value: u8,
+    pub max_value: u8,
+}
+
+impl TryFrom<TurbulenceData> for Turbulence {
+    type Error = NoiseError;
+
+    fn try_from(data: TurbulenceData) -> Result<Self, Self::Error> {
+        Turbulence::new(
+            data.seed,
+            data.scale,
+            data.octaves,
+            data.min_value,
+            data.max_value,
+        )
+    }
+}
+
+impl From<&Turbulence> for TurbulenceData {
+    fn from(turbulence: &Turbulence) -> Self {
+        TurbulenceData {
+            seed: turbulence.algo.seed(),
+            scale: turbulence.scale,
+            octaves: turbulence.octaves,
+            min_value: turbulence.min_value,
+            max_value: turbulence.max_value,
+        }
+    }
+}
@@ -0,0 +1,192 @@
+use crate::data::math::generator::noise::NoiseError;
+use crate::data::math::random::hash2d;
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+
+/// Which distances to the feature points define the value of [`Worley`] noise.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum WorleyDistance {
+    /// The distance to the nearest feature point. Creates round cells.
+    F1,
+    /// The distance to the 2nd nearest feature point.
+    F2,
+    /// The difference between both distances. Creates cracks along the borders of the cells.
+    F2MinusF1,
+}
+
+/// Generates cellular noise based on the distances to random feature points.
+///
+/// Each cell of a grid contains 1 feature point, which is placed deterministically based on the seed.
+pub struct Worley {
+    seed: u32,
+    scale: f64,
+    distance: WorleyDistance,
+    min_value: u8,
+    max_value: u8,
+}
+
+impl Worley {
+    /// Try to create a Worley noise. Fails if scale is not positive & finite:
+    ///
+    ///```
+    ///# use ofws_core::data::math::generator::worley::{Worley, WorleyDistance};
+    /// assert!(Worley::new(0, -1.0, WorleyDistance::F1, 0, 255).is_err());
+    /// assert!(Worley::new(0, f64::NAN, WorleyDistance::F1, 0, 255).is_err());
+    /// assert!(Worley::new(0, f64::INFINITY, WorleyDistance::F1, 0, 255).is_err());
+    ///```
+    /// Also fails if min_value >= max_value:
+    ///
+    ///```
+    ///# use ofws_core::data::math::generator::worley::{Worley, WorleyDistance};
+    /// assert!(Worley::new(0, 5.0, WorleyDistance::F1, 200, 105).is_err())
+    ///```
+    pub fn new(
+        seed: u32,
+        scale: f64,
+        distance: WorleyDistance,
+        min_value: u8,
+        max_value: u8,
+    ) -> Result<Worley, NoiseError> {
+        if !scale.is_finite() || scale <= 0.0 {
+            return Err(NoiseError::NegativeScale);
+        } else if min_value >= max_value {
+            return Err(NoiseError::MinBiggerThanMax(min_value, max_value));
+        }
+
+        Ok(Worley {
+            seed,
+            scale,
+            distance,
+            min_value,
+            max_value,
+        })
+    }
+
+    /// Generates worley noise for an input.
+    pub fn generate1d(&self, input: u32) -> u8 {
+        self.generate2d(input, 0)
+    }
+
+    /// Generates worley noise for a 2d point (x,y).
+    ///
+    ///```
+    ///# use ofws_core::data::math::generator::worley::{Worley, WorleyDistance};
+    /// let worley = Worley::new(42, 10.0, WorleyDistance::F1, 0, 255).unwrap();
+    ///
+    /// assert_eq!(worley.generate2d(0, 0), 149);
+    /// assert_eq!(worley.generate2d(17, 33), 29);
+    ///```
+    ///
+    /// The same seed generates the same values:
+    ///
+    ///```
+    ///# use ofws_core::data::math::generator::worley::{Worley, WorleyDistance};
+    /// let worley0 = Worley::new(42, 10.0, WorleyDistance::F2MinusF1, 50, 150).unwrap();
+    /// let worley1 = Worley::new(42, 10.0, WorleyDistance::F2MinusF1, 50, 150).unwrap();
+    ///
+    /// for y in 0..20 {
+    ///     for x in 0..20 {
+    ///         let value = worley0.generate2d(x, y);
+    ///         assert!(value >= 50 && value <= 150);
+    ///         assert_eq!(value, worley1.generate2d(x, y));
+    ///     }
+    /// }
+    ///```
+    pub fn generate2d(&self, x: u32, y: u32) -> u8 {
+        let x = x as f64 / self.scale;
+        let y = y as f64 / self.scale;
+        let cell_x = x.floor() as i32;
+        let cell_y = y.floor() as i32;
+        let mut f1 = f64::MAX;
+        let mut f2 = f64::MAX;
+
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                let (point_x, point_y) = self.calculate_feature_point(cell_x + dx, cell_y + dy);
+                let distance = ((point_x - x).powi(2) + (point_y - y).powi(2)).sqrt();
+
+                if distance < f1 {
+                    f2 = f1;
+                    f1 = distance;
+                } else if distance < f2 {
+                    f2 = distance;
+                }
+            }
+        }
+
+        let value = match self.distance {
+            WorleyDistance::F1 => f1,
+            WorleyDistance::F2 => f2,
+            WorleyDistance::F2MinusF1 => f2 - f1,
+        };
+        let range = (self.max_value - self.min_value) as f64;
+
+        self.min_value + (value.min(1.0) * range) as u8
+    }
+
+    fn calculate_feature_point(&self, cell_x: i32, cell_y: i32) -> (f64, f64) {
+        let hash = hash2d(self.seed, cell_x, cell_y);
+        let offset_x = (hash & 0xFFFF) as f64 / 65536.0;
+        let offset_y = (hash >> 16) as f64 / 65536.0;
+
+        (cell_x as f64 + offset_x, cell_y as f64 + offset_y)
+    }
+}
+
+/// For serializing, deserializing & validating [`Worley`].
+///
+///```
+///# use ofws_core::data::math::generator::worley::{WorleyData, Worley, WorleyDistance};
+///# use std::convert::TryInto;
+///
+/// let data = WorleyData { seed: 300, scale: 5.0, distance: WorleyDistance::F2, min_value: 10, max_value: 128 };
+/// let worley: Worley = data.clone().try_into().unwrap();
+/// let result: WorleyData = (&worley).into();
+/// assert_eq!(data, result)
+///```
+///
+/// A fractional scale is kept:
+///
+///```
+///# use ofws_core::data::math::generator::worley::{WorleyData, Worley, WorleyDistance};
+///# use std::convert::TryInto;
+///
+/// let data = WorleyData { seed: 300, scale: 2.5, distance: WorleyDistance::F1, min_value: 10, max_value: 128 };
+/// let worley: Worley = data.clone().try_into().unwrap();
+/// let result: WorleyData = (&worley).into();
+/// assert_eq!(data, result)
+///```
+#[derive(Debug, PartialEq, Clone, Copy, Serialize, Deserialize)]
+pub struct WorleyData {
+    pub seed: u32,
+    pub scale: f64,
+    pub distance: WorleyDistance,
+    pub min_value: u8,
+    pub max_value: u8,
+}
+
+impl TryFrom<WorleyData> for Worley {
+    type Error = NoiseError;
+
+    fn try_from(data: WorleyData) -> Result<Self, Self::Error> {
+        Worley::new(
+            data.seed,
+            data.scale,
+            data.distance,
+            data.min_value,
+            data.max_value,
+        )
+    }
+}
+
+impl From<&Worley> for WorleyData {
+    fn from(worley: &Worley) -> Self {
+        WorleyData {
+            seed: worley.seed,
+            scale: worley.scale,
+            distance: worley.distance,
+            min_value: worley.min_value,
+            max_value: worley.max_value,
+        }
+    }
+}
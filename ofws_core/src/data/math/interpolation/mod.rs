@@ -19,7 +19,7 @@ impl Interpolate for u8 {
     }
 }
 
-/// Interpolates between 2 u8 linearly.
+/// Interpolates between 2 u8 linearly & rounds to the nearest value.
 ///
 /// ```
 ///# use ofws_core::data::math::interpolation::lerp;
@@ -27,6 +27,8 @@ impl Interpolate for u8 {
 /// assert_eq!(lerp(100, 200, 0.0), 100);
 /// assert_eq!(lerp(100, 200, 0.5), 150);
 /// assert_eq!(lerp(100, 200, 1.0), 200);
+/// assert_eq!(lerp(0, 255, 0.5), 128);
+/// assert_eq!(lerp(0, 3, 0.5), 2);
 /// ```
 pub fn lerp(start: u8, end: u8, factor: f32) -> u8 {
     if factor > 1.0 {
@@ -35,12 +37,12 @@ pub fn lerp(start: u8, end: u8, factor: f32) -> u8 {
 
     if end >= start {
         let diff = (end - start) as f32;
-        return start + (diff * factor) as u8;
+        return start + (diff * factor).round() as u8;
     }
 
     let diff = (start - end) as f32;
 
-    start - (diff * factor) as u8
+    start - (diff * factor).round() as u8
 }
 
 #[cfg(test)]
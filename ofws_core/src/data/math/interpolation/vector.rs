@@ -14,9 +14,54 @@ pub struct InterpolationEntry<T: Threshold, V: Interpolate> {
     value: V,
 }
 
+/// Defines how the factor between 2 thresholds is eased.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum InterpolationCurve {
+    /// Ease in & out with a cubic curve.
+    Cubic,
+    #[default]
+    Linear,
+    /// Ease in & out with the smoothstep curve.
+    Smoothstep,
+}
+
+impl InterpolationCurve {
+    /// Eases a factor in the range [0,1].
+    ///
+    /// ```
+    ///# use ofws_core::data::math::interpolation::vector::InterpolationCurve::*;
+    /// assert_eq!(Cubic.ease(0.0), 0.0);
+    /// assert_eq!(Cubic.ease(0.25), 0.0625);
+    /// assert_eq!(Cubic.ease(0.5), 0.5);
+    /// assert_eq!(Cubic.ease(1.0), 1.0);
+    /// assert_eq!(Linear.ease(0.25), 0.25);
+    /// assert_eq!(Smoothstep.ease(0.0), 0.0);
+    /// assert_eq!(Smoothstep.ease(0.25), 0.15625);
+    /// assert_eq!(Smoothstep.ease(0.5), 0.5);
+    /// assert_eq!(Smoothstep.ease(1.0), 1.0);
+    /// ```
+    pub fn ease(&self, factor: f32) -> f32 {
+        match self {
+            InterpolationCurve::Cubic => {
+                if factor < 0.5 {
+                    4.0 * factor * factor * factor
+                } else {
+                    1.0 - (2.0 - 2.0 * factor).powi(3) / 2.0
+                }
+            }
+            InterpolationCurve::Linear => factor,
+            InterpolationCurve::Smoothstep => factor * factor * (3.0 - 2.0 * factor),
+        }
+    }
+}
+
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
 pub struct VectorInterpolation<T: Threshold, V: Interpolate> {
     vector: Vec<InterpolationEntry<T, V>>,
+    #[serde(default)]
+    wrap: bool,
+    #[serde(default)]
+    curve: InterpolationCurve,
 }
 
 impl<T: Threshold, V: Interpolate> VectorInterpolation<T, V> {
@@ -34,6 +79,32 @@ impl<T: Threshold, V: Interpolate> VectorInterpolation<T, V> {
     /// assert!(VectorInterpolation::new(vec![(50u32,50),(0,200)]).is_err());
     /// ```
     pub fn new(vector: Vec<(T, V)>) -> Result<VectorInterpolation<T, V>, &'static str> {
+        VectorInterpolation::with_wrap(vector, false)
+    }
+
+    /// Returns a VectorInterpolation, that wraps around at the ends.
+    ///
+    /// Inputs outside the thresholds are interpolated between the last & the first entry,
+    /// which is useful for cyclic data like hue or wind direction.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::interpolation::vector::VectorInterpolation;
+    /// let vector = vec![(64u8, 100u8), (192, 200)];
+    /// let clamping = VectorInterpolation::new(vector.clone()).unwrap();
+    /// let wrapping = VectorInterpolation::with_wrap(vector, true).unwrap();
+    ///
+    /// assert_eq!(clamping.interpolate(224), 200);
+    /// assert_eq!(wrapping.interpolate(128), 150);
+    /// assert_eq!(wrapping.interpolate(192), 200);
+    /// assert_eq!(wrapping.interpolate(224), 175);
+    /// assert_eq!(wrapping.interpolate(  0), 150);
+    /// assert_eq!(wrapping.interpolate( 32), 125);
+    /// assert_eq!(wrapping.interpolate( 64), 100);
+    /// ```
+    pub fn with_wrap(
+        vector: Vec<(T, V)>,
+        wrap: bool,
+    ) -> Result<VectorInterpolation<T, V>, &'static str> {
         if vector.len() < 2 {
             return Err("The vector needs at least 2 elements!");
         }
@@ -55,9 +126,80 @@ impl<T: Threshold, V: Interpolate> VectorInterpolation<T, V> {
                     value: e.1,
                 })
                 .collect::<Vec<_>>(),
+            wrap,
+            curve: InterpolationCurve::Linear,
         })
     }
 
+    /// Returns the VectorInterpolation with another [`InterpolationCurve`].
+    ///
+    /// The midpoint between 2 thresholds stays the same, but the rest is eased:
+    ///
+    /// ```
+    ///# use ofws_core::data::math::interpolation::vector::{InterpolationCurve, VectorInterpolation};
+    /// let vector = vec![(0u8, 0u8), (100, 200)];
+    /// let linear = VectorInterpolation::new(vector.clone()).unwrap();
+    /// let smooth = VectorInterpolation::new(vector).unwrap().with_curve(InterpolationCurve::Smoothstep);
+    ///
+    /// assert_eq!(linear.interpolate(50), 100);
+    /// assert_eq!(smooth.interpolate(50), 100);
+    /// assert_eq!(linear.interpolate(25), 50);
+    /// assert_eq!(smooth.interpolate(25), 31);
+    /// ```
+    pub fn with_curve(mut self, curve: InterpolationCurve) -> VectorInterpolation<T, V> {
+        self.curve = curve;
+        self
+    }
+
+    /// Returns the threshold & value of each entry in order.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::interpolation::vector::VectorInterpolation;
+    /// let interpolator = VectorInterpolation::new(vec![(100u32,150), (150,200), (200, 100)]).unwrap();
+    /// let entries: Vec<(u32, &u8)> = interpolator.entries().collect();
+    ///
+    /// assert_eq!(entries, vec![(100, &150), (150, &200), (200, &100)]);
+    /// ```
+    pub fn entries(&self) -> impl Iterator<Item = (T, &V)> {
+        self.vector
+            .iter()
+            .map(|entry| (entry.threshold, &entry.value))
+    }
+
+    /// Replaces an entry, if the thresholds stay ordered.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::interpolation::vector::VectorInterpolation;
+    /// let mut interpolator = VectorInterpolation::new(vec![(100u32,150), (150,200), (200, 100)]).unwrap();
+    ///
+    /// assert!(interpolator.set_entry(1, 120, 50).is_ok());
+    /// assert!(interpolator.set_entry(1, 220, 50).is_err());
+    /// assert!(interpolator.set_entry(3, 250, 50).is_err());
+    ///
+    /// let entries: Vec<(u32, &u8)> = interpolator.entries().collect();
+    /// assert_eq!(entries, vec![(100, &150), (120, &50), (200, &100)]);
+    /// ```
+    pub fn set_entry(&mut self, index: usize, threshold: T, value: V) -> Result<(), &'static str> {
+        if index >= self.vector.len() {
+            return Err("The index is outside the vector!");
+        }
+
+        let is_after_previous = index == 0 || self.vector[index - 1].threshold <= threshold;
+        let is_before_next = self
+            .vector
+            .get(index + 1)
+            .map(|next| threshold <= next.threshold)
+            .unwrap_or(true);
+
+        if !is_after_previous || !is_before_next {
+            return Err("The elements of vector would not be ordered!");
+        }
+
+        self.vector[index] = InterpolationEntry { threshold, value };
+
+        Ok(())
+    }
+
     /// Interpolates between the values of a vector of [`InterpolationEntry`] based on the input and their thresholds.
     ///
     /// ```
@@ -74,8 +216,26 @@ impl<T: Threshold, V: Interpolate> VectorInterpolation<T, V> {
     /// assert_eq!(interpolator.interpolate(255), 100);
     /// ```
     pub fn interpolate(&self, input: T) -> V {
+        self.interpolate_with(input, V::lerp)
+    }
+
+    /// Interpolates like [`VectorInterpolation::interpolate`], but with another interpolation function.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::interpolation::vector::VectorInterpolation;
+    /// let interpolator = VectorInterpolation::new(vec![(100u32,150u8), (200, 250)]).unwrap();
+    ///
+    /// assert_eq!(interpolator.interpolate_with(150, |start, _end, _factor| *start), 150);
+    /// ```
+    pub fn interpolate_with<F: Fn(&V, &V, f32) -> V>(&self, input: T, lerp: F) -> V {
         let mut last_entry = self.vector.get(0).unwrap();
 
+        if self.wrap {
+            if let Some(value) = self.interpolate_wrap_around(input, &lerp) {
+                return value;
+            }
+        }
+
         if input <= last_entry.threshold {
             return last_entry.value.clone();
         }
@@ -84,7 +244,11 @@ impl<T: Threshold, V: Interpolate> VectorInterpolation<T, V> {
             if input <= entry.threshold {
                 let factor_in_interval = (input - last_entry.threshold).as_()
                     / (entry.threshold - last_entry.threshold).as_();
-                return last_entry.value.lerp(&entry.value, factor_in_interval);
+                return lerp(
+                    &last_entry.value,
+                    &entry.value,
+                    self.curve.ease(factor_in_interval),
+                );
             }
 
             last_entry = entry;
@@ -92,4 +256,27 @@ impl<T: Threshold, V: Interpolate> VectorInterpolation<T, V> {
 
         last_entry.value.clone()
     }
+
+    /// Interpolates between the last & the first entry, if the input is outside the thresholds.
+    fn interpolate_wrap_around<F: Fn(&V, &V, f32) -> V>(&self, input: T, lerp: &F) -> Option<V> {
+        let first = self.vector.first()?;
+        let last = self.vector.last()?;
+
+        if input >= first.threshold && input <= last.threshold {
+            return None;
+        }
+
+        let period = T::max_value().as_() + 1.0;
+        let start = last.threshold.as_();
+        let end = first.threshold.as_() + period;
+        let mut position = input.as_();
+
+        if input < first.threshold {
+            position += period;
+        }
+
+        let factor = (position - start) / (end - start);
+
+        Some(lerp(&last.value, &first.value, self.curve.ease(factor)))
+    }
 }
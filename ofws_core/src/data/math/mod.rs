@@ -1,6 +1,10 @@
+pub mod connectivity;
+pub mod direction;
 pub mod distance;
 pub mod generator;
 pub mod interpolation;
+pub mod operation;
+pub mod random;
 pub mod selector;
 pub mod size2d;
 pub mod transformer;
@@ -0,0 +1,123 @@
+use serde::{Deserialize, Serialize};
+
+/// Defines how a new value is combined with an existing one.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum CombineOp {
+    /// Adds the new value, saturating at the maximum.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::operation::CombineOp;
+    /// assert_eq!(CombineOp::Add.combine(100, 50), 150);
+    /// assert_eq!(CombineOp::Add.combine(200, 100), 255);
+    /// ```
+    Add,
+    /// Returns the average of both values, rounded down.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::operation::CombineOp;
+    /// assert_eq!(CombineOp::Average.combine(100, 50), 75);
+    /// assert_eq!(CombineOp::Average.combine(255, 254), 254);
+    /// ```
+    Average,
+    /// Keeps the bigger value.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::operation::CombineOp;
+    /// assert_eq!(CombineOp::Max.combine(100, 50), 100);
+    /// assert_eq!(CombineOp::Max.combine(50, 100), 100);
+    /// ```
+    Max,
+    /// Keeps the smaller value.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::operation::CombineOp;
+    /// assert_eq!(CombineOp::Min.combine(100, 50), 50);
+    /// assert_eq!(CombineOp::Min.combine(50, 100), 50);
+    /// ```
+    Min,
+    /// Overwrites the existing value.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::operation::CombineOp;
+    /// assert_eq!(CombineOp::Set.combine(100, 50), 50);
+    /// assert_eq!(CombineOp::Set.combine(50, 100), 100);
+    /// ```
+    Set,
+    /// Subtracts the new value, saturating at 0.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::operation::CombineOp;
+    /// assert_eq!(CombineOp::Sub.combine(100, 50), 50);
+    /// assert_eq!(CombineOp::Sub.combine(50, 100), 0);
+    /// ```
+    Sub,
+}
+
+impl CombineOp {
+    /// Combines the existing value with the new one.
+    pub fn combine(&self, existing: u8, value: u8) -> u8 {
+        match self {
+            CombineOp::Add => existing.saturating_add(value),
+            CombineOp::Average => ((existing as u16 + value as u16) / 2) as u8,
+            CombineOp::Max => existing.max(value),
+            CombineOp::Min => existing.min(value),
+            CombineOp::Set => value,
+            CombineOp::Sub => existing.saturating_sub(value),
+        }
+    }
+}
+
+/// Defines how a constant is applied to an existing value.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub enum ScalarOp {
+    /// Adds the operand, saturating at the maximum.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::operation::ScalarOp;
+    /// assert_eq!(ScalarOp::Add.apply(100, 50), 150);
+    /// assert_eq!(ScalarOp::Add.apply(200, 100), 255);
+    /// ```
+    Add,
+    /// Divides by the operand. Division by 0 saturates at the maximum, except for 0 itself.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::operation::ScalarOp;
+    /// assert_eq!(ScalarOp::Div.apply(100, 3), 33);
+    /// assert_eq!(ScalarOp::Div.apply(100, 0), 255);
+    /// assert_eq!(ScalarOp::Div.apply(0, 0), 0);
+    /// ```
+    Div,
+    /// Multiplies with the operand, saturating at the maximum.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::operation::ScalarOp;
+    /// assert_eq!(ScalarOp::Mul.apply(100, 2), 200);
+    /// assert_eq!(ScalarOp::Mul.apply(100, 3), 255);
+    /// assert_eq!(ScalarOp::Mul.apply(100, 0), 0);
+    /// ```
+    Mul,
+    /// Subtracts the operand, saturating at 0.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::operation::ScalarOp;
+    /// assert_eq!(ScalarOp::Sub.apply(100, 50), 50);
+    /// assert_eq!(ScalarOp::Sub.apply(50, 100), 0);
+    /// ```
+    Sub,
+}
+
+impl ScalarOp {
+    /// Applies the operand to the existing value.
+    pub fn apply(&self, existing: u8, operand: u8) -> u8 {
+        match self {
+            ScalarOp::Add => existing.saturating_add(operand),
+            ScalarOp::Div => match existing.checked_div(operand) {
+                Some(value) => value,
+                None if existing == 0 => 0,
+                None => u8::MAX,
+            },
+            ScalarOp::Mul => existing.saturating_mul(operand),
+            ScalarOp::Sub => existing.saturating_sub(operand),
+        }
+    }
+}
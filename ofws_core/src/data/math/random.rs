@@ -0,0 +1,76 @@
+/// A small & deterministic pseudo random number generator.
+///
+/// The same seed always produces the same sequence of numbers,
+/// which keeps the procedural generation reproducible.
+///
+/// ```
+///# use ofws_core::data::math::random::Random;
+/// let mut random0 = Random::new(42);
+/// let mut random1 = Random::new(42);
+///
+/// for _ in 0..10 {
+///     assert_eq!(random0.next_u32(), random1.next_u32());
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct Random {
+    state: u64,
+}
+
+impl Random {
+    pub fn new(seed: u32) -> Random {
+        Random { state: seed as u64 }
+    }
+
+    /// Returns the next random number.
+    pub fn next_u32(&mut self) -> u32 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        (mix(self.state) >> 32) as u32
+    }
+
+    /// Returns the next random number in the range [0, max).
+    ///
+    /// ```
+    ///# use ofws_core::data::math::random::Random;
+    /// let mut random = Random::new(42);
+    ///
+    /// for _ in 0..100 {
+    ///     assert!(random.next_range(5) < 5);
+    /// }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if max is 0.
+    ///
+    /// ```should_panic
+    ///# use ofws_core::data::math::random::Random;
+    /// let mut random = Random::new(42);
+    ///
+    /// random.next_range(0);
+    /// ```
+    pub fn next_range(&mut self, max: u32) -> u32 {
+        assert!(max > 0, "The range must not be empty!");
+        self.next_u32() % max
+    }
+}
+
+fn mix(value: u64) -> u64 {
+    let mut z = value;
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Hashes a 2d point together with a seed, e.g. to place something in a grid cell.
+///
+/// ```
+///# use ofws_core::data::math::random::hash2d;
+/// assert_eq!(hash2d(42, 3, -5), hash2d(42, 3, -5));
+/// assert_ne!(hash2d(42, 3, -5), hash2d(43, 3, -5));
+/// assert_ne!(hash2d(42, 3, -5), hash2d(42, -5, 3));
+/// ```
+pub fn hash2d(seed: u32, x: i32, y: i32) -> u32 {
+    let value = ((x as u32 as u64) << 32) | y as u32 as u64;
+    (mix(mix(seed as u64) ^ value) >> 32) as u32
+}
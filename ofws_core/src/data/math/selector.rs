@@ -1,15 +1,37 @@
-use crate::data::math::interpolation::vector::VectorInterpolation;
+use crate::data::color::{Color, Palette};
+use crate::data::math::interpolation::vector::{InterpolationCurve, VectorInterpolation};
 use crate::data::math::interpolation::Interpolate;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-pub trait Selection: Interpolate + Copy {}
+pub trait Selection: Interpolate + Copy {
+    /// Interpolates in the HSV color model, if supported. Otherwise it interpolates linearly.
+    fn lerp_hsv(&self, other: &Self, factor: f32) -> Self {
+        self.lerp(other, factor)
+    }
+}
 
 impl Selection for u8 {}
 
 /// Selects an object of type T based on the input.
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Selector<T: Selection> {
+    /// Feeds the output of the first selector into the second one.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::selector::Selector;
+    /// let first = Selector::new_interpolate_pair(0, 100);
+    /// let second = Selector::new_interpolate_vector(vec![(0, 0), (100, 200)]).unwrap();
+    /// let selector = first.then(second);
+    ///
+    /// assert_eq!(selector.get(0), 0);
+    /// assert_eq!(selector.get(51), 40);
+    /// assert_eq!(selector.get(255), 200);
+    /// ```
+    Compose {
+        first: Box<Selector<u8>>,
+        second: Box<Selector<T>>,
+    },
     /// Returns a specific element.
     ///
     /// ```
@@ -35,6 +57,10 @@ pub enum Selector<T: Selection> {
     /// assert_eq!(interpolator.get(125), 175);
     /// ```
     InterpolateVector(VectorInterpolation<u8, T>),
+    /// Interpolates multiple elements in the HSV color model.
+    ///
+    /// See [`Selector::new_interpolate_hsv_vector`].
+    InterpolateHsvVector(VectorInterpolation<u8, T>),
     /// Looks the input up in a hashmap or returns the default value.
     ///
     /// ```
@@ -62,19 +88,179 @@ impl<T: Selection> Selector<T> {
         Ok(Selector::InterpolateVector(interpolation))
     }
 
+    /// Returns a selector interpolating multiple elements with an eased [`InterpolationCurve`].
+    ///
+    /// ```
+    ///# use ofws_core::data::math::interpolation::vector::InterpolationCurve;
+    ///# use ofws_core::data::math::selector::Selector;
+    /// let linear = Selector::new_interpolate_vector(vec![(0, 0), (100, 200)]).unwrap();
+    /// let cubic = Selector::new_interpolate_vector_with_curve(vec![(0, 0), (100, 200)], InterpolationCurve::Cubic).unwrap();
+    ///
+    /// assert_eq!(linear.get(50), 100);
+    /// assert_eq!(cubic.get(50), 100);
+    /// assert_eq!(linear.get(25), 50);
+    /// assert_eq!(cubic.get(25), 13);
+    /// ```
+    pub fn new_interpolate_vector_with_curve(
+        vector: Vec<(u8, T)>,
+        curve: InterpolationCurve,
+    ) -> Result<Selector<T>, &'static str> {
+        let interpolation = VectorInterpolation::new(vector)?.with_curve(curve);
+
+        Ok(Selector::InterpolateVector(interpolation))
+    }
+
+    /// Returns a selector interpolating multiple elements, that wraps around at the ends.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::selector::Selector;
+    /// let selector = Selector::new_wrapping_interpolate_vector(vec![(64,100), (192,200)]).unwrap();
+    ///
+    /// assert_eq!(selector.get(224), 175);
+    /// assert_eq!(selector.get(0), 150);
+    /// ```
+    pub fn new_wrapping_interpolate_vector(
+        vector: Vec<(u8, T)>,
+    ) -> Result<Selector<T>, &'static str> {
+        let interpolation = VectorInterpolation::with_wrap(vector, true)?;
+
+        Ok(Selector::InterpolateVector(interpolation))
+    }
+
     pub fn new_lookup(lookup: HashMap<u8, T>, default: T) -> Selector<T> {
         Selector::Lookup { lookup, default }
     }
 
+    /// Returns the threshold & value of each entry, if the selector interpolates multiple elements.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::selector::Selector;
+    /// let selector = Selector::new_interpolate_vector(vec![(100,150), (150,200)]).unwrap();
+    ///
+    /// assert_eq!(selector.entries(), Some(vec![(100, 150), (150, 200)]));
+    /// assert_eq!(Selector::Const(99).entries(), None);
+    /// ```
+    pub fn entries(&self) -> Option<Vec<(u8, T)>> {
+        match self {
+            Selector::InterpolateVector(interpolation)
+            | Selector::InterpolateHsvVector(interpolation) => Some(
+                interpolation
+                    .entries()
+                    .map(|(threshold, value)| (threshold, *value))
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
+    /// Replaces an entry, if the selector interpolates multiple elements & the thresholds stay ordered.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::selector::Selector;
+    /// let mut selector = Selector::new_interpolate_vector(vec![(100,150), (150,200)]).unwrap();
+    ///
+    /// assert!(selector.set_entry(0, 50, 250).is_ok());
+    /// assert!(selector.set_entry(0, 160, 250).is_err());
+    /// assert!(Selector::Const(99).set_entry(0, 50, 250).is_err());
+    /// assert_eq!(selector.entries(), Some(vec![(50, 250), (150, 200)]));
+    /// ```
+    pub fn set_entry(&mut self, index: usize, threshold: u8, value: T) -> Result<(), &'static str> {
+        match self {
+            Selector::InterpolateVector(interpolation)
+            | Selector::InterpolateHsvVector(interpolation) => {
+                interpolation.set_entry(index, threshold, value)
+            }
+            _ => Err("The selector doesn't interpolate multiple elements!"),
+        }
+    }
+
     /// Selects an object of type T based on the input.
     pub fn get(&self, input: u8) -> T {
         match self {
+            Selector::Compose { first, second } => second.get(first.get(input)),
             Selector::Const(value) => *value,
             Selector::InterpolatePair { first, second } => {
                 first.lerp(&second, input as f32 / 255.0)
             }
             Selector::InterpolateVector(interpolation) => interpolation.interpolate(input),
+            Selector::InterpolateHsvVector(interpolation) => {
+                interpolation.interpolate_with(input, T::lerp_hsv)
+            }
             Selector::Lookup { lookup, default } => lookup.get(&input).copied().unwrap_or(*default),
         }
     }
 }
+
+impl Selector<u8> {
+    /// Returns a selector, that feeds the output of this selector into another one.
+    pub fn then<T: Selection>(self, other: Selector<T>) -> Selector<T> {
+        Selector::Compose {
+            first: Box::new(self),
+            second: Box::new(other),
+        }
+    }
+}
+
+impl Selector<Color> {
+    /// Returns a selector interpolating multiple colors in the HSV color model,
+    /// which avoids the muddy colors of interpolating in RGB.
+    ///
+    /// ```
+    ///# use ofws_core::data::color::{Color, GREEN, RED, YELLOW};
+    ///# use ofws_core::data::math::selector::Selector;
+    /// let rgb = Selector::new_interpolate_vector(vec![(0, RED), (100, GREEN)]).unwrap();
+    /// let hsv = Selector::new_interpolate_hsv_vector(vec![(0, RED), (100, GREEN)]).unwrap();
+    ///
+    /// assert_eq!(rgb.get(50), Color::new(127, 128, 0));
+    /// assert_eq!(hsv.get(50), YELLOW);
+    /// assert_eq!(hsv.get(0), RED);
+    /// assert_eq!(hsv.get(100), GREEN);
+    /// ```
+    pub fn new_interpolate_hsv_vector(
+        vector: Vec<(u8, Color)>,
+    ) -> Result<Selector<Color>, &'static str> {
+        let interpolation = VectorInterpolation::new(vector)?;
+
+        Ok(Selector::InterpolateHsvVector(interpolation))
+    }
+
+    /// Returns a selector interpolating between the colors of a palette at the thresholds.
+    ///
+    /// ```
+    ///# use ofws_core::data::color::{Color, Palette, BLUE, RED, WHITE};
+    ///# use ofws_core::data::math::selector::Selector;
+    /// let palette = Palette::new(vec![BLUE, WHITE, RED]);
+    /// let selector = Selector::from_palette_ramp(&[0, 100, 200], &palette).unwrap();
+    ///
+    /// assert_eq!(selector.get(0), BLUE);
+    /// assert_eq!(selector.get(50), Color::new(128, 128, 255));
+    /// assert_eq!(selector.get(100), WHITE);
+    /// assert_eq!(selector.get(255), RED);
+    /// ```
+    ///
+    /// Fails if the number of thresholds & colors differ:
+    ///
+    /// ```
+    ///# use ofws_core::data::color::{Palette, BLUE, RED, WHITE};
+    ///# use ofws_core::data::math::selector::Selector;
+    /// let palette = Palette::new(vec![BLUE, WHITE, RED]);
+    ///
+    /// assert!(Selector::from_palette_ramp(&[0, 100], &palette).is_err());
+    /// ```
+    pub fn from_palette_ramp(
+        thresholds: &[u8],
+        palette: &Palette,
+    ) -> Result<Selector<Color>, &'static str> {
+        if thresholds.len() != palette.len() {
+            return Err("The number of thresholds & colors must be equal!");
+        }
+
+        let vector = thresholds
+            .iter()
+            .copied()
+            .zip(palette.get_colors().iter().copied())
+            .collect();
+
+        Selector::new_interpolate_vector(vector)
+    }
+}
@@ -1,3 +1,4 @@
+use crate::data::math::connectivity::Connectivity;
 use serde::{Deserialize, Serialize};
 use std::ops::{Add, Mul};
 
@@ -37,8 +38,16 @@ impl Size2d {
     /// let size = Size2d::new(2, 3);
     /// assert_eq!(size.get_area(), 6);
     /// ```
+    ///
+    /// Large sizes don't overflow:
+    ///
+    /// ```
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let size = Size2d::new(70000, 70000);
+    /// assert_eq!(size.get_area(), 4_900_000_000);
+    /// ```
     pub fn get_area(&self) -> usize {
-        (self.width * self.height) as usize
+        self.width as usize * self.height as usize
     }
 
     /// Returns the size along the x-axis
@@ -88,7 +97,7 @@ impl Size2d {
     /// assert_eq!(size.to_x(5), 1);
     /// ```
     pub fn to_x(&self, index: usize) -> u32 {
-        index as u32 % self.width
+        (index % self.width as usize) as u32
     }
 
     /// Converts an index to the y-coordinate of the equivalent point
@@ -99,7 +108,7 @@ impl Size2d {
     /// assert_eq!(size.to_y(5), 2);
     /// ```
     pub fn to_y(&self, index: usize) -> u32 {
-        index as u32 / self.width
+        (index / self.width as usize) as u32
     }
 
     /// Converts an index to the equivalent point
@@ -138,8 +147,19 @@ impl Size2d {
     /// let size = Size2d::new(2, 3);
     /// assert_eq!(size.to_index_risky(1, 2), 5);
     /// ```
+    ///
+    /// Large sizes don't overflow:
+    ///
+    /// ```
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let size = Size2d::new(70000, 70000);
+    /// assert_eq!(size.to_index_risky(69999, 69999), 4_899_999_999);
+    /// assert_eq!(size.to_index(69999, 69999), Some(4_899_999_999));
+    /// assert_eq!(size.saturating_to_index(70000, 70000), 4_899_999_999);
+    /// assert_eq!(size.to_x_and_y(4_899_999_999), [69999, 69999]);
+    /// ```
     pub fn to_index_risky(&self, x: u32, y: u32) -> usize {
-        (y * self.width + x) as usize
+        y as usize * self.width as usize + x as usize
     }
 
     /// Converts a point to the equivalent index.
@@ -161,10 +181,99 @@ impl Size2d {
     /// assert_eq!(size.saturating_to_index(0, 3), 4);
     /// assert_eq!(size.saturating_to_index(0, 4), 4);
     /// ```
+    ///
+    /// An empty size always returns 0:
+    ///
+    /// ```
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// assert_eq!(Size2d::new(0, 0).saturating_to_index(2, 3), 0);
+    /// assert_eq!(Size2d::new(0, 3).saturating_to_index(2, 3), 0);
+    /// assert_eq!(Size2d::new(2, 0).saturating_to_index(2, 3), 0);
+    /// assert_eq!(Size2d::default().saturating_to_index(0, 0), 0);
+    /// ```
     pub fn saturating_to_index(&self, x: u32, y: u32) -> usize {
+        if self.width == 0 || self.height == 0 {
+            return 0;
+        }
+
         let x = x.min(self.width - 1);
         let y = y.min(self.height - 1);
-        (y * self.width + x) as usize
+        self.to_index_risky(x, y)
+    }
+
+    /// Returns the index of a point, which wraps around at the edges, e.g. for tileable maps.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let size = Size2d::new(2, 3);
+    ///
+    /// assert_eq!(size.wrapping_to_index(1, 2), 5);
+    /// assert_eq!(size.wrapping_to_index(-1, 0), 1);
+    /// assert_eq!(size.wrapping_to_index(2, 0), 0);
+    /// assert_eq!(size.wrapping_to_index(0, -1), 4);
+    /// assert_eq!(size.wrapping_to_index(0, 3), 0);
+    /// assert_eq!(size.wrapping_to_index(-5, 7), 3);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the width or the height is 0, because there is no index to wrap to.
+    ///
+    /// ```should_panic
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// Size2d::new(0, 3).wrapping_to_index(1, 2);
+    /// ```
+    ///
+    /// ```should_panic
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// Size2d::new(2, 0).wrapping_to_index(1, 2);
+    /// ```
+    pub fn wrapping_to_index(&self, x: i64, y: i64) -> usize {
+        let x = x.rem_euclid(self.width as i64) as usize;
+        let y = y.rem_euclid(self.height as i64) as usize;
+        y * self.width as usize + x
+    }
+
+    /// Returns the indices of all neighbors of a cell. Neighbors outside are omitted.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::connectivity::Connectivity::{Eight, Four};
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let size = Size2d::new(3, 3);
+    ///
+    /// // corner
+    /// assert_eq!(size.neighbors(0, Four), vec![1, 3]);
+    /// assert_eq!(size.neighbors(0, Eight), vec![1, 3, 4]);
+    /// // edge
+    /// assert_eq!(size.neighbors(5, Four), vec![2, 4, 8]);
+    /// assert_eq!(size.neighbors(5, Eight), vec![1, 2, 4, 7, 8]);
+    /// // center
+    /// assert_eq!(size.neighbors(4, Four), vec![1, 3, 5, 7]);
+    /// assert_eq!(size.neighbors(4, Eight), vec![0, 1, 2, 3, 5, 6, 7, 8]);
+    /// ```
+    pub fn neighbors(&self, index: usize, mode: Connectivity) -> Vec<usize> {
+        mode.neighbors(*self, index)
+    }
+
+    /// Returns the indices of all neighbors of a cell, which wrap around at the edges.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::connectivity::Connectivity::{Eight, Four};
+    ///# use ofws_core::data::math::size2d::Size2d;
+    /// let size = Size2d::new(3, 3);
+    ///
+    /// assert_eq!(size.wrapping_neighbors(0, Four), vec![6, 2, 1, 3]);
+    /// assert_eq!(size.wrapping_neighbors(0, Eight), vec![8, 6, 7, 2, 1, 5, 3, 4]);
+    /// assert_eq!(size.wrapping_neighbors(4, Four), size.neighbors(4, Four));
+    /// ```
+    pub fn wrapping_neighbors(&self, index: usize, mode: Connectivity) -> Vec<usize> {
+        let x = self.to_x(index) as i64;
+        let y = self.to_y(index) as i64;
+
+        mode.offsets()
+            .iter()
+            .map(|(dx, dy)| self.wrapping_to_index(x + *dx as i64, y + *dy as i64))
+            .collect()
     }
 }
 
@@ -26,6 +26,8 @@ impl Clusterer2d {
     /// assert_eq!(Clusterer2d::new(Size2d::new(2,  2), vec![10, 20]), Err(SizeMismatch(4, 2)));
     /// assert_eq!(Clusterer2d::new(Size2d::new(0,  0), vec![10, 20]), Err(SizeMismatch(0, 2)));
     /// assert_eq!(Clusterer2d::new(Size2d::new(0,  0), vec![]), Err(TooFewClusters(0)));
+    /// assert_eq!(Clusterer2d::new(Size2d::new(0,  2), vec![10, 20]), Err(SizeMismatch(0, 2)));
+    /// assert_eq!(Clusterer2d::new(Size2d::new(2,  0), vec![10, 20]), Err(SizeMismatch(0, 2)));
     /// ```
     pub fn new(size: Size2d, cluster_id_lookup: Vec<u8>) -> Result<Clusterer2d, Clusterer2dError> {
         if size.get_area() != cluster_id_lookup.len() {
@@ -49,6 +51,8 @@ impl Clusterer2d {
 
     /// Calculates the cluster of 2 inputs.
     ///
+    /// It never panics, because the index is clamped to the lookup table.
+    ///
     /// ```
     ///# use ofws_core::data::math::size2d::Size2d;
     ///# use ofws_core::data::math::transformer::clusterer2d::Clusterer2d;
@@ -61,18 +65,56 @@ impl Clusterer2d {
     /// assert_eq!(clusterer.cluster(170, 200), 50);
     /// assert_eq!(clusterer.cluster(255, 255), 60);
     /// ```
+    ///
+    /// The whole input range is assigned to the clusters in order:
+    ///
+    /// ```
+    ///# use ofws_core::data::math::size2d::Size2d;
+    ///# use ofws_core::data::math::transformer::clusterer2d::Clusterer2d;
+    /// for width in 2..=16 {
+    ///     let clusterer = Clusterer2d::new(Size2d::new(width, 1), (0..width as u8).collect()).unwrap();
+    ///     let mut last_cluster = 0;
+    ///
+    ///     for input in 0..=255 {
+    ///         let cluster = clusterer.cluster(input, 0);
+    ///         assert!(cluster == last_cluster || cluster == last_cluster + 1);
+    ///         last_cluster = cluster;
+    ///     }
+    ///
+    ///     assert_eq!(last_cluster, width as u8 - 1);
+    /// }
+    /// ```
     pub fn cluster(&self, input0: u8, input1: u8) -> u8 {
+        self.cluster_id_lookup[self.calculate_index(input0, input1)]
+    }
+
+    /// Calculates the cluster of 2 inputs as an [`Option`].
+    ///
+    /// Like [`Clusterer2d::cluster`] it is total, because the index is clamped to the lookup table,
+    /// so it always returns Some.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::size2d::Size2d;
+    ///# use ofws_core::data::math::transformer::clusterer2d::Clusterer2d;
+    /// let clusterer = Clusterer2d::new(Size2d::new(3, 2), vec![10, 20, 30, 40, 50, 60]).unwrap();
+    ///
+    /// assert_eq!(clusterer.cluster_checked(  0,   0), Some(10));
+    /// assert_eq!(clusterer.cluster_checked(254, 255), Some(60));
+    /// assert_eq!(clusterer.cluster_checked(255, 255), Some(60));
+    /// ```
+    pub fn cluster_checked(&self, input0: u8, input1: u8) -> Option<u8> {
+        self.cluster_id_lookup
+            .get(self.calculate_index(input0, input1))
+            .copied()
+    }
+
+    /// Calculates the index of the cluster, which is clamped to the lookup table.
+    fn calculate_index(&self, input0: u8, input1: u8) -> usize {
         let x = input0 as u32 / self.cluster_size.width();
         let y = input1 as u32 / self.cluster_size.height();
-        let index = self.lookup_table_size.to_index_risky(x, y);
-
-        *self.cluster_id_lookup.get(index).unwrap_or_else(|| {
-            panic!(
-                "Index {} is too large for {} clusters!",
-                index,
-                self.cluster_id_lookup.len()
-            )
-        })
+        let x = x.min(self.lookup_table_size.width() - 1);
+        let y = y.min(self.lookup_table_size.height() - 1);
+        self.lookup_table_size.to_index_risky(x, y)
     }
 }
 
@@ -131,3 +173,29 @@ pub fn is_error(
 ) {
     assert_eq!(data.try_into(), Err(error));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inputs_near_255() {
+        for width in 1..=255u32 {
+            for height in [1, 2, 3, 7, 100, 255] {
+                let area = (width * height) as usize;
+                if area < 2 {
+                    continue;
+                }
+                let lookup = (0..area).map(|i| (i % 256) as u8).collect();
+                let clusterer = Clusterer2d::new(Size2d::new(width, height), lookup).unwrap();
+
+                for input0 in 250..=255 {
+                    for input1 in 250..=255 {
+                        let cluster = clusterer.cluster(input0, input1);
+                        assert_eq!(clusterer.cluster_checked(input0, input1), Some(cluster));
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,168 @@
+use serde::{Deserialize, Serialize};
+use std::convert::{TryFrom, TryInto};
+
+#[derive(Debug, Eq, PartialEq)]
+pub enum Clusterer3dError {
+    TooFewClusters(usize),
+    SizeMismatch(usize, usize),
+}
+
+/// Determines a cluster id from 3 inputs. E.g. biome from elevation, rainfall & temperature.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Clusterer3d {
+    width: u32,
+    height: u32,
+    depth: u32,
+    cluster_width: u32,
+    cluster_height: u32,
+    cluster_depth: u32,
+    cluster_id_lookup: Vec<u8>,
+}
+
+impl Clusterer3d {
+    /// Returns a clusterer, if valid:
+    ///
+    /// ```
+    ///# use ofws_core::data::math::transformer::clusterer3d::Clusterer3d;
+    ///# use ofws_core::data::math::transformer::clusterer3d::Clusterer3dError::{TooFewClusters, SizeMismatch};
+    /// assert_eq!(Clusterer3d::new(2, 2, 2, vec![10, 20]), Err(SizeMismatch(8, 2)));
+    /// assert_eq!(Clusterer3d::new(0, 0, 0, vec![10, 20]), Err(SizeMismatch(0, 2)));
+    /// assert_eq!(Clusterer3d::new(0, 0, 0, vec![]), Err(TooFewClusters(0)));
+    /// assert_eq!(Clusterer3d::new(0, 2, 1, vec![10, 20]), Err(SizeMismatch(0, 2)));
+    /// assert_eq!(Clusterer3d::new(2, 0, 1, vec![10, 20]), Err(SizeMismatch(0, 2)));
+    /// assert_eq!(Clusterer3d::new(2, 1, 0, vec![10, 20]), Err(SizeMismatch(0, 2)));
+    /// ```
+    pub fn new(
+        width: u32,
+        height: u32,
+        depth: u32,
+        cluster_id_lookup: Vec<u8>,
+    ) -> Result<Clusterer3d, Clusterer3dError> {
+        let size = width as usize * height as usize * depth as usize;
+
+        if size != cluster_id_lookup.len() {
+            return Err(Clusterer3dError::SizeMismatch(
+                size,
+                cluster_id_lookup.len(),
+            ));
+        } else if cluster_id_lookup.len() < 2 {
+            return Err(Clusterer3dError::TooFewClusters(cluster_id_lookup.len()));
+        }
+
+        Ok(Clusterer3d {
+            width,
+            height,
+            depth,
+            cluster_width: calculate_cluster_size(width),
+            cluster_height: calculate_cluster_size(height),
+            cluster_depth: calculate_cluster_size(depth),
+            cluster_id_lookup,
+        })
+    }
+
+    /// Calculates the cluster of 3 inputs.
+    ///
+    /// It never panics, because the index is clamped to the lookup table.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::transformer::clusterer3d::Clusterer3d;
+    /// let clusterer = Clusterer3d::new(2, 2, 2, vec![10, 20, 30, 40, 50, 60, 70, 80]).unwrap();
+    ///
+    /// assert_eq!(clusterer.cluster(  0,   0,   0), 10);
+    /// assert_eq!(clusterer.cluster(200, 100,   0), 20);
+    /// assert_eq!(clusterer.cluster(100, 200,  50), 30);
+    /// assert_eq!(clusterer.cluster(130, 170, 120), 40);
+    /// assert_eq!(clusterer.cluster( 60,  20, 200), 50);
+    /// assert_eq!(clusterer.cluster(170,   0, 150), 60);
+    /// assert_eq!(clusterer.cluster(  0, 255, 128), 70);
+    /// assert_eq!(clusterer.cluster(255, 255, 255), 80);
+    /// ```
+    ///
+    /// The whole input range is assigned to the clusters in order:
+    ///
+    /// ```
+    ///# use ofws_core::data::math::transformer::clusterer3d::Clusterer3d;
+    /// for depth in 2..=16 {
+    ///     let clusterer = Clusterer3d::new(1, 1, depth, (0..depth as u8).collect()).unwrap();
+    ///     let mut last_cluster = 0;
+    ///
+    ///     for input in 0..=255 {
+    ///         let cluster = clusterer.cluster(0, 0, input);
+    ///         assert!(cluster == last_cluster || cluster == last_cluster + 1);
+    ///         last_cluster = cluster;
+    ///     }
+    ///
+    ///     assert_eq!(last_cluster, depth as u8 - 1);
+    /// }
+    /// ```
+    pub fn cluster(&self, input0: u8, input1: u8, input2: u8) -> u8 {
+        self.cluster_id_lookup[self.calculate_index(input0, input1, input2)]
+    }
+
+    /// Calculates the index of the cluster, which is clamped to the lookup table.
+    fn calculate_index(&self, input0: u8, input1: u8, input2: u8) -> usize {
+        let x = (input0 as u32 / self.cluster_width).min(self.width - 1) as usize;
+        let y = (input1 as u32 / self.cluster_height).min(self.height - 1) as usize;
+        let z = (input2 as u32 / self.cluster_depth).min(self.depth - 1) as usize;
+        (z * self.height as usize + y) * self.width as usize + x
+    }
+}
+
+fn calculate_cluster_size(number_of_clusters: u32) -> u32 {
+    (256.0 / number_of_clusters as f32).ceil() as u32
+}
+
+/// For serializing, deserializing & validating [`Clusterer3d`].
+///
+///```
+///# use ofws_core::data::math::transformer::clusterer3d::{Clusterer3dData, can_convert};
+/// can_convert(Clusterer3dData::new(1, 2, 1, vec![3, 4]))
+///```
+///
+/// It can fail:
+///
+/// ```
+///# use ofws_core::data::math::transformer::clusterer3d::{Clusterer3dData, is_error};
+///# use ofws_core::data::math::transformer::clusterer3d::Clusterer3dError::{SizeMismatch, TooFewClusters};
+/// is_error(Clusterer3dData::new(2, 10, 3, vec![10, 20]), SizeMismatch(60, 2));
+/// is_error(Clusterer3dData::new(1, 1, 1, vec![10]), TooFewClusters(1));
+/// ```
+#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+pub struct Clusterer3dData {
+    width: u32,
+    height: u32,
+    depth: u32,
+    cluster_id_lookup: Vec<u8>,
+}
+
+impl TryFrom<Clusterer3dData> for Clusterer3d {
+    type Error = Clusterer3dError;
+
+    fn try_from(data: Clusterer3dData) -> Result<Self, Self::Error> {
+        Clusterer3d::new(data.width, data.height, data.depth, data.cluster_id_lookup)
+    }
+}
+
+impl From<&Clusterer3d> for Clusterer3dData {
+    fn from(clusterer: &Clusterer3d) -> Self {
+        Clusterer3dData {
+            width: clusterer.width,
+            height: clusterer.height,
+            depth: clusterer.depth,
+            cluster_id_lookup: clusterer.cluster_id_lookup.clone(),
+        }
+    }
+}
+
+pub fn can_convert(data: Clusterer3dData) {
+    let generator: Clusterer3d = data.clone().try_into().unwrap();
+    let result: Clusterer3dData = (&generator).into();
+    assert_eq!(result, data)
+}
+
+pub fn is_error(
+    data: impl TryInto<Clusterer3d, Error = Clusterer3dError>,
+    error: Clusterer3dError,
+) {
+    assert_eq!(data.try_into(), Err(error));
+}
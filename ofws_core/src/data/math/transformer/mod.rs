@@ -1,6 +1,7 @@
 use std::hash::Hash;
 
 pub mod clusterer2d;
+pub mod clusterer3d;
 pub mod threshold;
 pub mod transformer1d;
 pub mod transformer2d;
@@ -89,3 +89,67 @@ impl<T: Transformed> OverwriteWithThreshold<T> {
         }
     }
 }
+
+/// Overwrites the input if inside a range.
+#[derive(new, Debug, PartialEq, Eq, Clone, Copy, Serialize, Deserialize)]
+pub struct OverwriteWithRange<T: Transformed> {
+    value: T,
+    lower: T,
+    upper: T,
+}
+
+impl<T: Transformed> OverwriteWithRange<T> {
+    fn is_inside(&self, input: T) -> bool {
+        self.lower <= input && input <= self.upper
+    }
+
+    /// Overwrites the input if inside the range [lower,upper].
+    ///
+    /// ```
+    ///# use ofws_core::data::math::transformer::threshold::OverwriteWithRange;
+    /// let overwrite = OverwriteWithRange::new(42, 100, 150);
+    ///
+    /// assert_eq!(overwrite.overwrite_if_between(  0),   0);
+    /// assert_eq!(overwrite.overwrite_if_between( 99),  99);
+    /// assert_eq!(overwrite.overwrite_if_between(100),  42);
+    /// assert_eq!(overwrite.overwrite_if_between(125),  42);
+    /// assert_eq!(overwrite.overwrite_if_between(150),  42);
+    /// assert_eq!(overwrite.overwrite_if_between(151), 151);
+    /// assert_eq!(overwrite.overwrite_if_between(255), 255);
+    /// ```
+    ///
+    /// The range is empty, if lower is above upper:
+    ///
+    /// ```
+    ///# use ofws_core::data::math::transformer::threshold::OverwriteWithRange;
+    /// let overwrite = OverwriteWithRange::new(42, 150, 100);
+    ///
+    /// assert_eq!(overwrite.overwrite_if_between( 99),  99);
+    /// assert_eq!(overwrite.overwrite_if_between(100), 100);
+    /// assert_eq!(overwrite.overwrite_if_between(125), 125);
+    /// assert_eq!(overwrite.overwrite_if_between(150), 150);
+    /// ```
+    pub fn overwrite_if_between(&self, input: T) -> T {
+        self.overwrite_output_if_between(input, input)
+    }
+
+    /// Overwrites the output, if the input is inside the range [lower,upper].
+    ///
+    /// ```
+    ///# use ofws_core::data::math::transformer::threshold::OverwriteWithRange;
+    /// let overwrite = OverwriteWithRange::new(42, 100, 150);
+    ///
+    /// assert_eq!(overwrite.overwrite_output_if_between(  0, 200), 200);
+    /// assert_eq!(overwrite.overwrite_output_if_between( 99, 199), 199);
+    /// assert_eq!(overwrite.overwrite_output_if_between(100, 198),  42);
+    /// assert_eq!(overwrite.overwrite_output_if_between(150, 197),  42);
+    /// assert_eq!(overwrite.overwrite_output_if_between(151, 196), 196);
+    /// ```
+    pub fn overwrite_output_if_between(&self, input: T, output: T) -> T {
+        if self.is_inside(input) {
+            self.value
+        } else {
+            output
+        }
+    }
+}
@@ -1,15 +1,71 @@
+use crate::data::math::selector::Selector;
 use crate::data::math::transformer::threshold::OverwriteWithThreshold;
-use crate::data::math::transformer::Transformed;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::convert::TryFrom;
+use Transformer1d::*;
 
-/// Transforms the input into an output of the same type.
+#[derive(Debug, Eq, PartialEq)]
+pub enum Transformer1dError {
+    MinBiggerThanMax(u8, u8),
+}
+
+/// Transforms the input into an output.
 #[derive(Debug, Serialize, Deserialize)]
-pub enum Transformer1d<T: Transformed> {
+pub enum Transformer1d {
+    /// Feeds the input to a [`Selector`].
+    ///
+    /// ```
+    ///# use ofws_core::data::math::selector::Selector;
+    ///# use ofws_core::data::math::transformer::transformer1d::Transformer1d;
+    /// let transformer = Transformer1d::ApplySelector(Selector::new_interpolate_pair(100, 200));
+    ///
+    /// assert_eq!(transformer.get(0), 100);
+    /// assert_eq!(transformer.get(255), 200);
+    /// ```
+    ApplySelector(Selector<u8>),
+    /// Limits the input to the range [min,max].
+    ///
+    /// ```
+    ///# use ofws_core::data::math::transformer::transformer1d::{Transformer1d, Transformer1dError};
+    /// let transformer = Transformer1d::new_clamp(50, 100).unwrap();
+    ///
+    /// assert_eq!(transformer.get(0), 50);
+    /// assert_eq!(transformer.get(75), 75);
+    /// assert_eq!(transformer.get(255), 100);
+    /// assert_eq!(Transformer1d::new_clamp(100, 50).unwrap_err(), Transformer1dError::MinBiggerThanMax(100, 50));
+    /// ```
+    Clamp { min: u8, max: u8 },
+    /// Returns a const value.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::transformer::transformer1d::Transformer1d;
+    /// let transformer = Transformer1d::Const(42);
+    ///
+    /// assert_eq!(transformer.get(0), 42);
+    /// assert_eq!(transformer.get(255), 42);
+    /// ```
+    Const(u8),
     /// Overwrites the input, if it is above a threshold.
-    OverwriteIfAbove(OverwriteWithThreshold<T>),
+    ///
+    /// ```
+    ///# use ofws_core::data::math::transformer::transformer1d::Transformer1d;
+    /// let transformer = Transformer1d::new_overwrite_if_above(42, 100);
+    ///
+    /// assert_eq!(transformer.get(99), 99);
+    /// assert_eq!(transformer.get(100), 42);
+    /// ```
+    OverwriteIfAbove(OverwriteWithThreshold<u8>),
     /// Overwrites the input, if it is below a threshold.
-    OverwriteIfBelow(OverwriteWithThreshold<T>),
+    ///
+    /// ```
+    ///# use ofws_core::data::math::transformer::transformer1d::Transformer1d;
+    /// let transformer = Transformer1d::new_overwrite_if_below(42, 100);
+    ///
+    /// assert_eq!(transformer.get(100), 42);
+    /// assert_eq!(transformer.get(101), 101);
+    /// ```
+    OverwriteIfBelow(OverwriteWithThreshold<u8>),
     /// Overwrites the input, if the map containes a value for it.
     ///
     /// ```
@@ -23,26 +79,100 @@ pub enum Transformer1d<T: Transformed> {
     /// assert_eq!(selector.get(3), 100);
     /// assert_eq!(selector.get(4), 4);
     /// ```
-    OverwriteWithMap(HashMap<T, T>),
+    OverwriteWithMap(HashMap<u8, u8>),
 }
 
-impl<T: Transformed> Transformer1d<T> {
-    pub fn new_overwrite_if_above(value: T, threshold: T) -> Transformer1d<T> {
-        Transformer1d::OverwriteIfAbove(OverwriteWithThreshold::new(value, threshold))
+impl Transformer1d {
+    pub fn new_clamp(min: u8, max: u8) -> Result<Transformer1d, Transformer1dError> {
+        if min > max {
+            return Err(Transformer1dError::MinBiggerThanMax(min, max));
+        }
+
+        Ok(Clamp { min, max })
     }
 
-    pub fn new_overwrite_if_below(value: T, threshold: T) -> Transformer1d<T> {
-        Transformer1d::OverwriteIfBelow(OverwriteWithThreshold::new(value, threshold))
+    pub fn new_overwrite_if_above(value: u8, threshold: u8) -> Transformer1d {
+        OverwriteIfAbove(OverwriteWithThreshold::new(value, threshold))
     }
 
-    /// Selects an object of type T based on the input.
-    pub fn get(&self, input: T) -> T {
+    pub fn new_overwrite_if_below(value: u8, threshold: u8) -> Transformer1d {
+        OverwriteIfBelow(OverwriteWithThreshold::new(value, threshold))
+    }
+
+    /// Transforms the input into an output.
+    pub fn get(&self, input: u8) -> u8 {
         match self {
-            Transformer1d::OverwriteIfAbove(data) => data.overwrite_if_above(input),
-            Transformer1d::OverwriteIfBelow(data) => data.overwrite_if_below(input),
-            Transformer1d::OverwriteWithMap(hashmap) => {
-                hashmap.get(&input).copied().unwrap_or(input)
-            }
+            ApplySelector(selector) => selector.get(input),
+            Clamp { min, max } => input.clamp(*min, *max),
+            Const(value) => *value,
+            OverwriteIfAbove(data) => data.overwrite_if_above(input),
+            OverwriteIfBelow(data) => data.overwrite_if_below(input),
+            OverwriteWithMap(hashmap) => hashmap.get(&input).copied().unwrap_or(input),
+        }
+    }
+}
+
+/// For serializing, deserializing & validating [`Transformer1d`].
+///
+///```
+///# use ofws_core::data::math::selector::Selector;
+///# use ofws_core::data::math::transformer::threshold::OverwriteWithThreshold;
+///# use ofws_core::data::math::transformer::transformer1d::{Transformer1dData, assert_eq};
+/// let overwrite_data = OverwriteWithThreshold::new(100, 200);
+/// let hashmap = vec![(1u8, 25u8), (3, 100)].into_iter().collect();
+///
+/// assert_eq(Transformer1dData::ApplySelector(Selector::new_interpolate_pair(10, 20)));
+/// assert_eq(Transformer1dData::Clamp { min: 10, max: 20 });
+/// assert_eq(Transformer1dData::Const(42));
+/// assert_eq(Transformer1dData::OverwriteIfAbove(overwrite_data));
+/// assert_eq(Transformer1dData::OverwriteIfBelow(overwrite_data));
+/// assert_eq(Transformer1dData::OverwriteWithMap(hashmap));
+///```
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub enum Transformer1dData {
+    ApplySelector(Selector<u8>),
+    Clamp { min: u8, max: u8 },
+    Const(u8),
+    OverwriteIfAbove(OverwriteWithThreshold<u8>),
+    OverwriteIfBelow(OverwriteWithThreshold<u8>),
+    OverwriteWithMap(HashMap<u8, u8>),
+}
+
+type Data = Transformer1dData;
+
+impl TryFrom<Transformer1dData> for Transformer1d {
+    type Error = Transformer1dError;
+
+    fn try_from(data: Transformer1dData) -> Result<Self, Self::Error> {
+        match data {
+            Data::ApplySelector(selector) => Ok(ApplySelector(selector)),
+            Data::Clamp { min, max } => Transformer1d::new_clamp(min, max),
+            Data::Const(value) => Ok(Const(value)),
+            Data::OverwriteIfAbove(o) => Ok(OverwriteIfAbove(o)),
+            Data::OverwriteIfBelow(o) => Ok(OverwriteIfBelow(o)),
+            Data::OverwriteWithMap(hashmap) => Ok(OverwriteWithMap(hashmap)),
         }
     }
 }
+
+impl From<&Transformer1d> for Transformer1dData {
+    fn from(transformer: &Transformer1d) -> Self {
+        match transformer {
+            ApplySelector(selector) => Data::ApplySelector(selector.clone()),
+            Clamp { min, max } => Data::Clamp {
+                min: *min,
+                max: *max,
+            },
+            Const(value) => Data::Const(*value),
+            OverwriteIfAbove(o) => Data::OverwriteIfAbove(*o),
+            OverwriteIfBelow(o) => Data::OverwriteIfBelow(*o),
+            OverwriteWithMap(hashmap) => Data::OverwriteWithMap(hashmap.clone()),
+        }
+    }
+}
+
+pub fn assert_eq(data: Transformer1dData) {
+    let transformer: Transformer1d = Transformer1d::try_from(data.clone()).unwrap();
+    let result: Transformer1dData = (&transformer).into();
+    assert_eq!(result, data)
+}
@@ -1,5 +1,7 @@
+use crate::data::math::interpolation::Interpolate;
+use crate::data::math::selector::Selector;
 use crate::data::math::transformer::clusterer2d::{Clusterer2d, Clusterer2dData, Clusterer2dError};
-use crate::data::math::transformer::threshold::OverwriteWithThreshold;
+use crate::data::math::transformer::threshold::{OverwriteWithRange, OverwriteWithThreshold};
 use serde::{Deserialize, Serialize};
 use std::convert::{TryFrom, TryInto};
 use Transformer2d::*;
@@ -18,6 +20,27 @@ impl From<Clusterer2dError> for Transformer2dError {
 /// Transforms 2 inputs into an output.
 #[derive(Debug)]
 pub enum Transformer2d {
+    /// Feeds one input to a [`Selector`] & uses the other as the factor to interpolate
+    /// from the first input to the selected value.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::selector::Selector;
+    ///# use ofws_core::data::math::transformer::transformer2d::Transformer2d;
+    /// let transformer = Transformer2d::new_blend(Selector::Const(200), true);
+    ///
+    /// assert_eq!(transformer.transform(100,   0), 100);
+    /// assert_eq!(transformer.transform(100, 128), 150);
+    /// assert_eq!(transformer.transform(100, 255), 200);
+    ///
+    /// let transformer = Transformer2d::new_blend(Selector::Const(200), false);
+    ///
+    /// assert_eq!(transformer.transform(  0, 100), 100);
+    /// assert_eq!(transformer.transform(255, 100), 200);
+    /// ```
+    Blend {
+        selector: Selector<u8>,
+        by_first: bool,
+    },
     /// Determine a cluster id from both inputs. E.g. biome from rainfall & temperature.
     Clusterer(Clusterer2d),
     /// Returns a const value.
@@ -26,9 +49,25 @@ pub enum Transformer2d {
     OverwriteIfAbove(OverwriteWithThreshold<u8>),
     /// Overwrites the input, if it is below a threshold.
     OverwriteIfBelow(OverwriteWithThreshold<u8>),
+    /// Overwrites the input, if it is inside a range.
+    ///
+    /// ```
+    ///# use ofws_core::data::math::transformer::transformer2d::Transformer2d;
+    /// let transformer = Transformer2d::new_overwrite_if_between(42, 100, 150);
+    ///
+    /// assert_eq!(transformer.transform( 99, 10), 10);
+    /// assert_eq!(transformer.transform(100, 10), 42);
+    /// assert_eq!(transformer.transform(150, 10), 42);
+    /// assert_eq!(transformer.transform(151, 10), 10);
+    /// ```
+    OverwriteIfBetween(OverwriteWithRange<u8>),
 }
 
 impl Transformer2d {
+    pub fn new_blend(selector: Selector<u8>, by_first: bool) -> Transformer2d {
+        Blend { selector, by_first }
+    }
+
     pub fn new_overwrite_if_above(value: u8, threshold: u8) -> Transformer2d {
         OverwriteIfAbove(OverwriteWithThreshold::new(value, threshold))
     }
@@ -37,13 +76,26 @@ impl Transformer2d {
         OverwriteIfBelow(OverwriteWithThreshold::new(value, threshold))
     }
 
+    pub fn new_overwrite_if_between(value: u8, lower: u8, upper: u8) -> Transformer2d {
+        OverwriteIfBetween(OverwriteWithRange::new(value, lower, upper))
+    }
+
     /// Transforms 2 inputs into an output.
     pub fn transform(&self, input0: u8, input1: u8) -> u8 {
         match self {
+            Blend { selector, by_first } => {
+                let (value, factor) = if *by_first {
+                    (input0, input1)
+                } else {
+                    (input1, input0)
+                };
+                value.lerp(&selector.get(value), factor as f32 / 255.0)
+            }
             Clusterer(clusterer) => clusterer.cluster(input0, input1),
             Const(value) => *value,
             OverwriteIfAbove(data) => data.overwrite_output_if_above(input0, input1),
             OverwriteIfBelow(data) => data.overwrite_output_if_below(input0, input1),
+            OverwriteIfBetween(data) => data.overwrite_output_if_between(input0, input1),
         }
     }
 }
@@ -51,24 +103,32 @@ impl Transformer2d {
 /// For serializing, deserializing & validating [`Transformer2d`].
 ///
 ///```
+///# use ofws_core::data::math::selector::Selector;
 ///# use ofws_core::data::math::size2d::Size2d;
 ///# use ofws_core::data::math::transformer::clusterer2d::Clusterer2dData;
-///# use ofws_core::data::math::transformer::threshold::OverwriteWithThreshold;
+///# use ofws_core::data::math::transformer::threshold::{OverwriteWithRange, OverwriteWithThreshold};
 ///# use ofws_core::data::math::transformer::transformer2d::{Transformer2dData, assert_eq};
 /// let clusterer = Clusterer2dData::new(Size2d::new(1, 2), vec![10, 11]);
 /// let overwrite_data = OverwriteWithThreshold::new(100, 200);
 ///
+/// assert_eq(Transformer2dData::Blend { selector: Selector::new_interpolate_pair(10, 20), by_first: false });
 /// assert_eq(Transformer2dData::Clusterer(clusterer));
 /// assert_eq(Transformer2dData::Const(42));
 /// assert_eq(Transformer2dData::OverwriteIfAbove(overwrite_data));
 /// assert_eq(Transformer2dData::OverwriteIfBelow(overwrite_data));
+/// assert_eq(Transformer2dData::OverwriteIfBetween(OverwriteWithRange::new(1, 2, 3)));
 ///```
-#[derive(new, Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[derive(new, Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub enum Transformer2dData {
+    Blend {
+        selector: Selector<u8>,
+        by_first: bool,
+    },
     Clusterer(Clusterer2dData),
     Const(u8),
     OverwriteIfAbove(OverwriteWithThreshold<u8>),
     OverwriteIfBelow(OverwriteWithThreshold<u8>),
+    OverwriteIfBetween(OverwriteWithRange<u8>),
 }
 
 type Data = Transformer2dData;
@@ -78,10 +138,12 @@ impl TryFrom<Transformer2dData> for Transformer2d {
 
     fn try_from(data: Transformer2dData) -> Result<Self, Self::Error> {
         match data {
+            Data::Blend { selector, by_first } => Ok(Blend { selector, by_first }),
             Data::Clusterer(c) => Ok(Clusterer(c.try_into()?)),
             Data::Const(value) => Ok(Const(value)),
             Data::OverwriteIfAbove(o) => Ok(OverwriteIfAbove(o)),
             Data::OverwriteIfBelow(o) => Ok(OverwriteIfBelow(o)),
+            Data::OverwriteIfBetween(o) => Ok(OverwriteIfBetween(o)),
         }
     }
 }
@@ -89,10 +151,15 @@ impl TryFrom<Transformer2dData> for Transformer2d {
 impl From<&Transformer2d> for Transformer2dData {
     fn from(generator: &Transformer2d) -> Self {
         match generator {
+            Blend { selector, by_first } => Data::Blend {
+                selector: selector.clone(),
+                by_first: *by_first,
+            },
             Clusterer(c) => Data::Clusterer(c.into()),
             Const(value) => Data::Const(*value),
             OverwriteIfAbove(o) => Data::OverwriteIfAbove(*o),
             OverwriteIfBelow(o) => Data::OverwriteIfBelow(*o),
+            OverwriteIfBetween(o) => Data::OverwriteIfBetween(*o),
         }
     }
 }
@@ -1,5 +1,6 @@
 use super::rendering::{Initialization, Renderer};
 use crate::interface::input::{KeyCode, MouseButton};
+use std::collections::HashSet;
 
 /// A trait to handle simple applications like the examples.
 pub trait App {
@@ -12,6 +13,12 @@ pub trait App {
     /// Handles keyboard input
     fn on_key_released(&mut self, _key: KeyCode) {}
 
+    /// Handles the keys, that are currently held down. Is called once per frame, e.g. for smooth movement.
+    fn on_key_held(&mut self, _keys: &HashSet<KeyCode>) {}
+
     /// Handles mouse input
     fn on_button_released(&mut self, _button: MouseButton, _point: (u32, u32)) {}
+
+    /// Handles the mouse wheel. A positive delta scrolls up, e.g. to zoom in around the point.
+    fn on_scroll(&mut self, _delta: f32, _point: (u32, u32)) {}
 }
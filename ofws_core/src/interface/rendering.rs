@@ -48,6 +48,16 @@ pub trait Renderer {
 
 pub type Point = (f32, f32);
 
+/// Defines the direction of the y-axis & therefore the position of the origin.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum YAxis {
+    /// The origin is at the bottom-left & y increases upwards.
+    #[default]
+    Up,
+    /// The origin is at the top-left & y increases downwards.
+    Down,
+}
+
 /// A trait that focuses on rendering colored polygons.
 pub trait ColorRenderer {
     #[svgbobdoc::transform]
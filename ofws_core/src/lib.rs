@@ -1,11 +1,16 @@
 #[macro_use]
 extern crate derive_new;
+#[cfg(feature = "logging")]
 #[macro_use]
 extern crate log;
 #[macro_use]
 extern crate unwrap;
 
+#[macro_use]
+mod macros;
+
 pub mod data;
 pub mod interface;
+#[cfg(feature = "logging")]
 pub mod logging;
 pub mod rendering;
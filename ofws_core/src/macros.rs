@@ -0,0 +1,29 @@
+//! Wrappers around the macros of [`log`], which compile to nothing without the feature `logging`.
+
+#[cfg(feature = "logging")]
+macro_rules! log_info {
+    ($($arg:tt)+) => { info!($($arg)+) };
+}
+
+#[cfg(not(feature = "logging"))]
+macro_rules! log_info {
+    ($($arg:tt)+) => {
+        if false {
+            let _ = format!($($arg)+);
+        }
+    };
+}
+
+#[cfg(feature = "logging")]
+macro_rules! log_debug {
+    ($($arg:tt)+) => { debug!($($arg)+) };
+}
+
+#[cfg(not(feature = "logging"))]
+macro_rules! log_debug {
+    ($($arg:tt)+) => {
+        if false {
+            let _ = format!($($arg)+);
+        }
+    };
+}
@@ -1,11 +1,12 @@
 use crate::data::color::{Color, PINK};
 use crate::data::map::Map2d;
+use crate::data::math::interpolation::Interpolate;
 use crate::data::math::selector::Selector;
 use crate::rendering::tile::EMPTY_TILE;
 use serde::{Deserialize, Serialize};
 
 /// Renders a cell of a [`Map2d`].
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum CellRenderer {
     /// Renders a cell of a [`Map2d`] based on a specific attribute & a selector.
     AttributeRenderer {
@@ -64,3 +65,70 @@ impl CellRenderer {
         }
     }
 }
+
+/// Defines how a layer of a [`CompositeRenderer`] is combined with the layers below.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum LayerMode {
+    /// Replaces the tile & both colors.
+    Overwrite,
+    /// Blends both colors with the given alpha & replaces the tile, if it isn't empty.
+    Blend(f32),
+}
+
+/// Renders a cell of a [`Map2d`] by compositing multiple [`CellRenderer`]s in order.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CompositeRenderer {
+    layers: Vec<(CellRenderer, LayerMode)>,
+}
+
+impl CompositeRenderer {
+    pub fn new(layers: Vec<(CellRenderer, LayerMode)>) -> CompositeRenderer {
+        CompositeRenderer { layers }
+    }
+
+    /// Returns the ascii code & color of the cell for rendering.
+    ///
+    /// ```
+    ///# use ofws_core::data::color::{Color, BLACK, WHITE};
+    ///# use ofws_core::data::map::Map2d;
+    ///# use ofws_core::data::math::selector::Selector;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    ///# use ofws_core::rendering::cell::{CellRenderer, CompositeRenderer, LayerMode};
+    ///# use ofws_core::rendering::tile::EMPTY_TILE;
+    /// let mut map = Map2d::new(Size2d::new(1, 1));
+    /// map.create_attribute("elevation", 0);
+    /// let base = CellRenderer::new_color_renderer(0, Selector::Const(BLACK));
+    /// let overlay = CellRenderer::new_color_renderer(0, Selector::Const(WHITE));
+    /// let renderer = CompositeRenderer::new(vec![
+    ///     (base, LayerMode::Overwrite),
+    ///     (overlay, LayerMode::Blend(0.5)),
+    /// ]);
+    ///
+    /// let (tile, _, background) = renderer.get(&map, 0);
+    ///
+    /// assert_eq!(tile, EMPTY_TILE);
+    /// assert_eq!(background, Color::gray(128));
+    /// ```
+    pub fn get(&self, map: &Map2d, index: usize) -> (u8, Color, Color) {
+        let start = (EMPTY_TILE, Color::default(), Color::default());
+
+        self.layers
+            .iter()
+            .fold(start, |(tile, foreground, background), (renderer, mode)| {
+                let (layer_tile, layer_foreground, layer_background) = renderer.get(map, index);
+
+                match mode {
+                    LayerMode::Overwrite => (layer_tile, layer_foreground, layer_background),
+                    LayerMode::Blend(alpha) => (
+                        if layer_tile == EMPTY_TILE {
+                            tile
+                        } else {
+                            layer_tile
+                        },
+                        foreground.lerp(&layer_foreground, *alpha),
+                        background.lerp(&layer_background, *alpha),
+                    ),
+                }
+            })
+    }
+}
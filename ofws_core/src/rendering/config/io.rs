@@ -0,0 +1,55 @@
+use crate::rendering::config::{RenderConfig, RenderConfigData, RenderConfigError};
+use std::convert::TryInto;
+use std::fs;
+use std::fs::File;
+use std::io::Write;
+
+/// Reads a [`RenderConfig`] & resolves the named selectors.
+pub fn read_render_config(path: &str) -> Result<RenderConfig, RenderConfigError> {
+    let string = fs::read_to_string(path)?;
+    let data: RenderConfigData = serde_yaml::from_str(&string)?;
+    data.try_into()
+}
+
+pub fn write_render_config(config: &RenderConfig, path: &str) -> Result<(), RenderConfigError> {
+    let mut file = File::create(path)?;
+
+    let data: RenderConfigData = config.into();
+    let s = serde_yaml::to_string(&data)?;
+
+    file.write_all(s.as_bytes())?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::color::{BLUE, WHITE};
+    use crate::data::math::selector::Selector;
+    use crate::rendering::config::{CellRendererData, ColorSelectorData};
+
+    #[test]
+    fn test_shared_selector_round_trip() {
+        let path = std::env::temp_dir().join("ofws_core_test_shared_selector.yaml");
+        let path = path.to_str().unwrap();
+        let renderer = |attribute_id| CellRendererData::AttributeRenderer {
+            attribute_id,
+            background_selector: ColorSelectorData::Named("ocean".to_string()),
+            foreground_selector: ColorSelectorData::Selector(Selector::Const(WHITE)),
+            tile_selector: Selector::Const(0),
+        };
+        let ocean = Selector::new_interpolate_pair(BLUE, WHITE);
+        let selectors = vec![("ocean".to_string(), ocean)].into_iter().collect();
+        let data = RenderConfigData::new(selectors, vec![renderer(0), renderer(1)]);
+        let config: RenderConfig = data.clone().try_into().unwrap();
+
+        write_render_config(&config, path).unwrap();
+        let result = read_render_config(path).unwrap();
+
+        assert_eq!(result, config);
+        assert_eq!(RenderConfigData::from(&result), data);
+
+        fs::remove_file(path).unwrap();
+    }
+}
@@ -0,0 +1,219 @@
+use crate::data::color::Color;
+use crate::data::math::selector::Selector;
+use crate::rendering::cell::CellRenderer;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+pub mod io;
+
+#[derive(Debug)]
+pub enum RenderConfigError {
+    IoError(std::io::Error),
+    SerdeError(serde_yaml::Error),
+    UnknownSelector(String),
+}
+
+impl From<std::io::Error> for RenderConfigError {
+    fn from(error: std::io::Error) -> Self {
+        RenderConfigError::IoError(error)
+    }
+}
+
+impl From<serde_yaml::Error> for RenderConfigError {
+    fn from(error: serde_yaml::Error) -> Self {
+        RenderConfigError::SerdeError(error)
+    }
+}
+
+/// The [`CellRenderer`]s of an application & the color selectors they share.
+///
+/// The definitions of the renderers keep the references to the named selectors,
+/// so they are written back as references.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RenderConfig {
+    selectors: HashMap<String, Selector<Color>>,
+    renderers: Vec<CellRenderer>,
+    definitions: Vec<CellRendererData>,
+}
+
+impl RenderConfig {
+    /// Creates a config without references to the named selectors.
+    pub fn new(
+        selectors: HashMap<String, Selector<Color>>,
+        renderers: Vec<CellRenderer>,
+    ) -> RenderConfig {
+        let definitions = renderers.iter().map(|renderer| renderer.into()).collect();
+
+        RenderConfig {
+            selectors,
+            renderers,
+            definitions,
+        }
+    }
+
+    pub fn get_selectors(&self) -> &HashMap<String, Selector<Color>> {
+        &self.selectors
+    }
+
+    pub fn get_renderers(&self) -> &[CellRenderer] {
+        &self.renderers
+    }
+}
+
+/// A color selector, that is either defined directly or references a shared one by name.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ColorSelectorData {
+    Named(String),
+    Selector(Selector<Color>),
+}
+
+impl ColorSelectorData {
+    fn resolve(
+        self,
+        selectors: &HashMap<String, Selector<Color>>,
+    ) -> Result<Selector<Color>, RenderConfigError> {
+        match self {
+            ColorSelectorData::Named(name) => selectors
+                .get(&name)
+                .cloned()
+                .ok_or(RenderConfigError::UnknownSelector(name)),
+            ColorSelectorData::Selector(selector) => Ok(selector),
+        }
+    }
+}
+
+/// For serializing & deserializing [`CellRenderer`]s with shared color selectors.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CellRendererData {
+    AttributeRenderer {
+        attribute_id: usize,
+        background_selector: ColorSelectorData,
+        foreground_selector: ColorSelectorData,
+        tile_selector: Selector<u8>,
+    },
+}
+
+impl CellRendererData {
+    fn resolve(
+        self,
+        selectors: &HashMap<String, Selector<Color>>,
+    ) -> Result<CellRenderer, RenderConfigError> {
+        match self {
+            CellRendererData::AttributeRenderer {
+                attribute_id,
+                background_selector,
+                foreground_selector,
+                tile_selector,
+            } => Ok(CellRenderer::new_attribute_renderer(
+                attribute_id,
+                background_selector.resolve(selectors)?,
+                foreground_selector.resolve(selectors)?,
+                tile_selector,
+            )),
+        }
+    }
+}
+
+impl From<&CellRenderer> for CellRendererData {
+    fn from(renderer: &CellRenderer) -> Self {
+        match renderer {
+            CellRenderer::AttributeRenderer {
+                attribute_id,
+                background_selector,
+                foreground_selector,
+                tile_selector,
+            } => CellRendererData::AttributeRenderer {
+                attribute_id: *attribute_id,
+                background_selector: ColorSelectorData::Selector(background_selector.clone()),
+                foreground_selector: ColorSelectorData::Selector(foreground_selector.clone()),
+                tile_selector: tile_selector.clone(),
+            },
+        }
+    }
+}
+
+/// For serializing, deserializing & validating [`RenderConfig`].
+///
+/// The renderers can reference the selectors by name, which are resolved during the conversion.
+///
+///```
+///# use ofws_core::data::color::{BLUE, WHITE};
+///# use ofws_core::data::math::selector::Selector;
+///# use ofws_core::rendering::cell::CellRenderer;
+///# use ofws_core::rendering::config::{CellRendererData, ColorSelectorData, RenderConfig, RenderConfigData};
+///# use std::convert::TryInto;
+/// let ocean = Selector::new_interpolate_pair(BLUE, WHITE);
+/// let renderer = |attribute_id| CellRendererData::AttributeRenderer {
+///     attribute_id,
+///     background_selector: ColorSelectorData::Named("ocean".to_string()),
+///     foreground_selector: ColorSelectorData::Selector(Selector::Const(WHITE)),
+///     tile_selector: Selector::Const(0),
+/// };
+/// let selectors = vec![("ocean".to_string(), ocean.clone())].into_iter().collect();
+/// let data = RenderConfigData::new(selectors, vec![renderer(0), renderer(1)]);
+///
+/// let config: RenderConfig = data.clone().try_into().unwrap();
+/// let expected = |attribute_id| {
+///     CellRenderer::new_attribute_renderer(attribute_id, ocean.clone(), Selector::Const(WHITE), Selector::Const(0))
+/// };
+///
+/// assert_eq!(config.get_renderers(), &[expected(0), expected(1)]);
+///
+/// let result: RenderConfigData = (&config).into();
+/// assert_eq!(result, data);
+///
+/// let result: RenderConfig = result.try_into().unwrap();
+/// assert_eq!(result, config);
+///```
+#[derive(new, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RenderConfigData {
+    #[serde(default)]
+    selectors: HashMap<String, Selector<Color>>,
+    renderers: Vec<CellRendererData>,
+}
+
+impl TryFrom<RenderConfigData> for RenderConfig {
+    type Error = RenderConfigError;
+
+    /// The conversion fails, if a renderer references an unknown selector.
+    ///
+    ///```
+    ///# use ofws_core::data::math::selector::Selector;
+    ///# use ofws_core::rendering::config::{CellRendererData, ColorSelectorData, RenderConfig, RenderConfigData, RenderConfigError};
+    ///# use std::collections::HashMap;
+    ///# use std::convert::TryFrom;
+    /// let renderer = CellRendererData::AttributeRenderer {
+    ///     attribute_id: 0,
+    ///     background_selector: ColorSelectorData::Named("unknown".to_string()),
+    ///     foreground_selector: ColorSelectorData::Named("unknown".to_string()),
+    ///     tile_selector: Selector::Const(0),
+    /// };
+    /// let data = RenderConfigData::new(HashMap::new(), vec![renderer]);
+    ///
+    /// match RenderConfig::try_from(data) {
+    ///     Err(RenderConfigError::UnknownSelector(name)) => assert_eq!(name, "unknown"),
+    ///     _ => panic!("Expected an unknown selector!"),
+    /// }
+    ///```
+    fn try_from(data: RenderConfigData) -> Result<Self, Self::Error> {
+        let selectors = data.selectors;
+        let renderers = data
+            .renderers
+            .iter()
+            .map(|renderer| renderer.clone().resolve(&selectors))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(RenderConfig {
+            selectors,
+            renderers,
+            definitions: data.renderers,
+        })
+    }
+}
+
+impl From<&RenderConfig> for RenderConfigData {
+    fn from(config: &RenderConfig) -> Self {
+        RenderConfigData::new(config.selectors.clone(), config.definitions.clone())
+    }
+}
@@ -0,0 +1,97 @@
+use crate::data::map::Map2d;
+use crate::rendering::cell::CellRenderer;
+use std::io;
+use std::path::Path;
+
+/// Renders the background color of each cell of a [`Map2d`] into a png without a window.
+///
+/// Each cell is a square of tile_size * tile_size pixels.
+pub fn render_attribute_to_png(
+    map: &Map2d,
+    renderer: &CellRenderer,
+    tile_size: u32,
+    path: &Path,
+) -> io::Result<()> {
+    if tile_size == 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "The tile size must be greater than 0!",
+        ));
+    }
+
+    let size = map.get_size();
+    let width = checked_mul(size.width(), tile_size)?;
+    let height = checked_mul(size.height(), tile_size)?;
+    let mut buffer = Vec::with_capacity(width as usize * height as usize * 4);
+
+    for y in 0..height {
+        for x in 0..width {
+            let index = size.to_index_risky(x / tile_size, y / tile_size);
+            let (_, _, background) = renderer.get(map, index);
+            buffer.extend_from_slice(&[background.r(), background.g(), background.b(), 255]);
+        }
+    }
+
+    let image = image::RgbaImage::from_raw(width, height, buffer)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "Invalid image size!"))?;
+
+    image.save(path).map_err(io::Error::other)
+}
+
+fn checked_mul(cells: u32, tile_size: u32) -> io::Result<u32> {
+    cells
+        .checked_mul(tile_size)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "The image is too large!"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::color::{BLACK, WHITE};
+    use crate::data::math::selector::Selector;
+    use crate::data::math::size2d::Size2d;
+    use std::fs;
+
+    #[test]
+    fn test_render_attribute_to_png() {
+        let dir = std::env::temp_dir().join("ofws_core_test_render_attribute_to_png");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("elevation.png");
+        let mut map = Map2d::new(Size2d::new(3, 2));
+        map.create_attribute_from("elevation", vec![0, 50, 100, 150, 200, 250]);
+        let renderer =
+            CellRenderer::new_color_renderer(0, Selector::new_interpolate_pair(BLACK, WHITE));
+
+        render_attribute_to_png(&map, &renderer, 4, &path).unwrap();
+
+        assert!(path.exists());
+        assert_eq!(image::image_dimensions(&path).unwrap(), (12, 8));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_render_with_zero_tile_size() {
+        let path = std::env::temp_dir().join("ofws_core_test_render_with_zero_tile_size.png");
+        let mut map = Map2d::new(Size2d::new(3, 2));
+        map.create_attribute("elevation", 0);
+        let renderer = CellRenderer::new_color_renderer(0, Selector::Const(BLACK));
+
+        assert!(render_attribute_to_png(&map, &renderer, 0, &path).is_err());
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_render_with_too_large_tile_size() {
+        let path = std::env::temp_dir().join("ofws_core_test_render_with_too_large_tile_size.png");
+        let mut map = Map2d::new(Size2d::new(3, 2));
+        map.create_attribute("elevation", 0);
+        let renderer = CellRenderer::new_color_renderer(0, Selector::Const(BLACK));
+
+        let error = render_attribute_to_png(&map, &renderer, u32::MAX, &path).unwrap_err();
+
+        assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+        assert!(!path.exists());
+    }
+}
@@ -0,0 +1,226 @@
+use crate::data::color::Color;
+use crate::data::math::size2d::Size2d;
+use crate::interface::rendering::*;
+use crate::rendering::tile::{calculate_tiles, TileRenderer, EMPTY_TILE};
+
+/// Loads no resources, but hands out a `TextureId` for each filename.
+#[derive(Debug, Default)]
+pub struct HeadlessInitialization {
+    textures: Vec<String>,
+}
+
+impl HeadlessInitialization {
+    pub fn get_textures(&self) -> &[String] {
+        &self.textures
+    }
+}
+
+impl Initialization for HeadlessInitialization {
+    fn load_texture(&mut self, filename: &str) -> TextureId {
+        self.textures.push(filename.to_string());
+        self.textures.len() - 1
+    }
+}
+
+/// Records the rendered ascii characters as tiles instead of drawing them.
+#[derive(Debug)]
+pub struct TileRecorder {
+    size: Size2d,
+    tiles: Vec<(usize, u8, Color)>,
+}
+
+impl TileRecorder {
+    fn record(&mut self, position: Point, size: Point, ascii: u8, color: Color) {
+        let columns = (self.size.width() as f32 / size.0).ceil() as usize;
+        let x = (position.0 / size.0) as usize;
+        let y = (position.1 / size.1) as usize;
+        self.tiles.push((y * columns + x, ascii, color));
+    }
+}
+
+impl ColorRenderer for TileRecorder {
+    fn render_triangle(&mut self, _a: Point, _b: Point, _c: Point, _color: Color) {}
+
+    fn render_rectangle(&mut self, _position: Point, _size: Point, _color: Color) {}
+}
+
+impl TextureRenderer for TileRecorder {
+    fn render_rectangle(
+        &mut self,
+        _position: Point,
+        _size: Point,
+        _tc: TextureCoordinate,
+        _tc_size: TextureCoordinate,
+        _color: Color,
+    ) {
+    }
+}
+
+impl AsciiRenderer for TileRecorder {
+    fn render_text(&mut self, position: Point, size: Point, string: &str, color: Color) {
+        for (i, character) in string.chars().enumerate() {
+            let point = (position.0 + i as f32 * size.0, position.1);
+            self.render_char(point, size, character, color);
+        }
+    }
+
+    fn render_char(&mut self, position: Point, size: Point, character: char, color: Color) {
+        if character.is_ascii() {
+            self.record(position, size, character as u8, color);
+        }
+    }
+
+    fn render_u8(&mut self, position: Point, size: Point, ascii: u8, color: Color) {
+        self.record(position, size, ascii, color);
+    }
+}
+
+/// A [`Renderer`] without a window, that records the rendered tiles in memory.
+///
+/// Useful for testing an [`App`](crate::interface::app::App) without a gpu.
+/// Colored & textured polygons are ignored.
+#[derive(Debug)]
+pub struct HeadlessRenderer {
+    background: Option<Color>,
+    recorder: TileRecorder,
+}
+
+impl HeadlessRenderer {
+    /// Creates a renderer for a render target with `size` in pixels.
+    pub fn new(size: Size2d) -> HeadlessRenderer {
+        HeadlessRenderer {
+            background: None,
+            recorder: TileRecorder {
+                size,
+                tiles: Vec::new(),
+            },
+        }
+    }
+
+    /// Returns the color of the last call to [`Renderer::start`].
+    pub fn get_background(&self) -> Option<Color> {
+        self.background
+    }
+
+    /// Returns the index, ascii code & color of each rendered tile in order.
+    pub fn get_tiles(&self) -> &[(usize, u8, Color)] {
+        &self.recorder.tiles
+    }
+
+    /// Dumps the ascii codes of the rendered tiles into a buffer with one element per tile.
+    ///
+    /// ```
+    ///# use ofws_core::data::color::WHITE;
+    ///# use ofws_core::data::math::size2d::Size2d;
+    ///# use ofws_core::interface::rendering::Renderer;
+    ///# use ofws_core::rendering::headless::HeadlessRenderer;
+    /// let mut renderer = HeadlessRenderer::new(Size2d::new(30, 20));
+    /// let tile_size = Size2d::new(10, 10);
+    ///
+    /// renderer.start(WHITE);
+    /// renderer.get_tile_renderer(0, tile_size).render_text(1, "ab", WHITE);
+    /// renderer.finish();
+    ///
+    /// assert_eq!(renderer.to_buffer(tile_size), vec![0, b'a', b'b', 0, 0, 0]);
+    /// ```
+    pub fn to_buffer(&self, tile_size: Size2d) -> Vec<u8> {
+        let tiles = calculate_tiles(self.recorder.size, tile_size);
+        let mut buffer = vec![EMPTY_TILE; tiles.get_area()];
+
+        for (index, ascii, _) in &self.recorder.tiles {
+            if let Some(tile) = buffer.get_mut(*index) {
+                *tile = *ascii;
+            }
+        }
+
+        buffer
+    }
+}
+
+impl Renderer for HeadlessRenderer {
+    fn get_size(&self) -> Size2d {
+        self.recorder.size
+    }
+
+    fn start(&mut self, color: Color) {
+        self.background = Some(color);
+        self.recorder.tiles.clear();
+    }
+
+    fn finish(&mut self) {}
+
+    fn take_screenshot(&self, _filename: &str) {}
+
+    fn get_color_renderer(&mut self) -> &mut dyn ColorRenderer {
+        &mut self.recorder
+    }
+
+    fn get_texture_renderer(&mut self, _id: TextureId) -> &mut dyn TextureRenderer {
+        &mut self.recorder
+    }
+
+    fn get_ascii_renderer(&mut self, _id: TextureId) -> &mut dyn AsciiRenderer {
+        &mut self.recorder
+    }
+
+    fn get_tile_renderer(&mut self, _id: TextureId, tile_size: Size2d) -> TileRenderer<'_> {
+        let tiles = calculate_tiles(self.recorder.size, tile_size);
+        TileRenderer::new(tiles, tile_size, &mut self.recorder)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::color::{BLACK, RED, WHITE};
+    use crate::interface::app::App;
+
+    #[derive(Default)]
+    struct SimpleApp {
+        font_id: TextureId,
+    }
+
+    impl App for SimpleApp {
+        fn init(&mut self, initialization: &mut dyn Initialization) {
+            self.font_id = initialization.load_texture("ascii.png");
+        }
+
+        fn render(&mut self, renderer: &mut dyn Renderer) {
+            renderer.start(BLACK);
+
+            let mut tile_renderer = renderer.get_tile_renderer(self.font_id, Size2d::new(10, 10));
+            tile_renderer.render_ascii(3, b'@', WHITE);
+            tile_renderer.render_text(5, "ab", RED);
+
+            renderer.finish();
+        }
+    }
+
+    #[test]
+    fn test_render_app() {
+        let mut initialization = HeadlessInitialization::default();
+        let mut renderer = HeadlessRenderer::new(Size2d::new(40, 30));
+        let mut app = SimpleApp::default();
+
+        app.init(&mut initialization);
+        app.render(&mut renderer);
+
+        assert_eq!(initialization.get_textures(), &["ascii.png".to_string()]);
+        assert_eq!(renderer.get_background(), Some(BLACK));
+        assert_eq!(
+            renderer.get_tiles(),
+            &[(3, b'@', WHITE), (5, b'a', RED), (6, b'b', RED)]
+        );
+    }
+
+    #[test]
+    fn test_render_twice() {
+        let mut renderer = HeadlessRenderer::new(Size2d::new(40, 30));
+        let mut app = SimpleApp::default();
+
+        app.render(&mut renderer);
+        app.render(&mut renderer);
+
+        assert_eq!(renderer.get_tiles().len(), 3);
+    }
+}
@@ -0,0 +1,110 @@
+use crate::data::color::Color;
+use crate::data::math::selector::Selector;
+use crate::interface::rendering::{AsciiRenderer, Point, YAxis};
+use crate::rendering::tile::FULL_TILE;
+
+/// The maximum number of rows of a legend, one for each input of a [`Selector`].
+const MAX_ROWS: usize = 256;
+
+/// Renders a vertical color strip explaining a [`Selector`], e.g. next to a map of the temperature.
+///
+/// The rectangle starts at position, which is its bottom-left corner for [`YAxis::Up`]
+/// & its top-left corner for [`YAxis::Down`].
+/// Its bottom row shows the color of 0 & its top row the color of 255 for both.
+/// Each row is a stretched [`FULL_TILE`] & there is at most 1 row per pixel.
+pub fn render_color_legend(
+    renderer: &mut dyn AsciiRenderer,
+    selector: &Selector<Color>,
+    position: Point,
+    size: Point,
+    y_axis: YAxis,
+) {
+    let rows = (size.1 as usize).clamp(1, MAX_ROWS);
+    let row_height = size.1 / rows as f32;
+
+    for row in 0..rows {
+        let y = match y_axis {
+            YAxis::Up => position.1 + row as f32 * row_height,
+            YAxis::Down => position.1 + size.1 - (row + 1) as f32 * row_height,
+        };
+        let color = selector.get(calculate_input(row, rows));
+        renderer.render_u8((position.0, y), (size.0, row_height), FULL_TILE, color);
+    }
+}
+
+/// Returns the input of the [`Selector`] for a row, so that the rows cover 0..=255.
+fn calculate_input(row: usize, rows: usize) -> u8 {
+    if rows < 2 {
+        return 255;
+    }
+
+    (row * 255 / (rows - 1)) as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::color::{BLUE, RED};
+    use crate::data::math::size2d::Size2d;
+    use crate::interface::rendering::Renderer;
+    use crate::rendering::headless::HeadlessRenderer;
+
+    fn create_selector() -> Selector<Color> {
+        Selector::new_interpolate_vector(vec![(0, BLUE), (255, RED)]).unwrap()
+    }
+
+    fn render(height: u32, y_axis: YAxis) -> Vec<(usize, u8, Color)> {
+        let mut renderer = HeadlessRenderer::new(Size2d::new(5, height));
+        let size = (5.0, height as f32);
+
+        render_color_legend(
+            renderer.get_ascii_renderer(0),
+            &create_selector(),
+            (0.0, 0.0),
+            size,
+            y_axis,
+        );
+
+        renderer.get_tiles().to_vec()
+    }
+
+    #[test]
+    fn test_render_color_legend_with_y_axis_up() {
+        let selector = create_selector();
+        let mut tiles = render(100, YAxis::Up);
+        tiles.sort_by_key(|tile| tile.0);
+
+        assert_eq!(tiles.len(), 100);
+        assert_eq!(tiles[0], (0, FULL_TILE, selector.get(0)));
+        assert_eq!(tiles[99], (99, FULL_TILE, selector.get(255)));
+    }
+
+    #[test]
+    fn test_render_color_legend_with_y_axis_down() {
+        let selector = create_selector();
+        let mut tiles = render(100, YAxis::Down);
+        tiles.sort_by_key(|tile| tile.0);
+
+        assert_eq!(tiles.len(), 100);
+        assert_eq!(tiles[0], (0, FULL_TILE, selector.get(255)));
+        assert_eq!(tiles[99], (99, FULL_TILE, selector.get(0)));
+    }
+
+    #[test]
+    fn test_render_tall_color_legend() {
+        let mut tiles = render(512, YAxis::Up);
+        tiles.sort_by_key(|tile| tile.0);
+
+        assert_eq!(tiles.len(), 256);
+        assert_eq!(tiles[0], (0, FULL_TILE, BLUE));
+        assert_eq!(tiles[255], (255, FULL_TILE, RED));
+    }
+
+    #[test]
+    fn test_calculate_input() {
+        assert_eq!(calculate_input(0, 1), 255);
+        assert_eq!(calculate_input(0, 3), 0);
+        assert_eq!(calculate_input(1, 3), 127);
+        assert_eq!(calculate_input(2, 3), 255);
+    }
+}
@@ -1,2 +1,6 @@
 pub mod cell;
+pub mod config;
+pub mod export;
+pub mod headless;
+pub mod legend;
 pub mod tile;
@@ -1,14 +1,18 @@
 use crate::data::color::Color;
 use crate::data::math::size2d::Size2d;
-use crate::interface::rendering::{AsciiRenderer, Point};
+use crate::interface::rendering::{AsciiRenderer, Point, YAxis};
 
 pub const EMPTY_TILE: u8 = 0;
 pub const FULL_TILE: u8 = 219;
+/// The width of the lines rendered by [`TileRenderer::render_grid`].
+pub const GRID_LINE_WIDTH: f32 = 1.0;
 
 /// Simplifies rendering by focusing on a grid of tiles
 pub struct TileRenderer<'a> {
     tiles: Size2d,
     tile_size: Point,
+    y_axis: YAxis,
+    height: f32,
     renderer: &'a mut dyn AsciiRenderer,
 }
 
@@ -23,10 +27,21 @@ impl<'a> TileRenderer<'a> {
         TileRenderer {
             tiles,
             tile_size,
+            y_axis: YAxis::Up,
+            height: tiles.height() as f32 * tile_size.1,
             renderer,
         }
     }
 
+    /// Changes the direction of the y-axis. The first row is at the top for [`YAxis::Down`].
+    pub fn with_y_axis(self, y_axis: YAxis, window_height: u32) -> TileRenderer<'a> {
+        TileRenderer {
+            y_axis,
+            height: window_height as f32,
+            ..self
+        }
+    }
+
     pub fn get_tiles(&self) -> Size2d {
         self.tiles
     }
@@ -38,18 +53,58 @@ impl<'a> TileRenderer<'a> {
             .render_text(point, self.tile_size, string, color);
     }
 
+    /// Renders a whole string starting at `index` & advances by `advance` per character.
+    pub fn render_text_spaced(&mut self, index: usize, string: &str, color: Color, advance: f32) {
+        let (x, y) = self.calculate_point(index);
+
+        for (i, character) in string.chars().enumerate() {
+            let point = (x + i as f32 * advance, y);
+            self.renderer
+                .render_char(point, self.tile_size, character, color);
+        }
+    }
+
     /// Renders the tile at `index` as an ascii character.
     pub fn render_ascii(&mut self, index: usize, ascii: u8, color: Color) {
         let point = self.calculate_point(index);
         self.renderer.render_u8(point, self.tile_size, ascii, color);
     }
 
+    /// Renders thin lines at every tile boundary as rectangles of [`FULL_TILE`].
+    pub fn render_grid(&mut self, color: Color) {
+        let width = self.tiles.width() as f32 * self.tile_size.0;
+        let height = self.tiles.height() as f32 * self.tile_size.1;
+        let start_y = match self.y_axis {
+            YAxis::Up => 0.0,
+            YAxis::Down => self.height - height,
+        };
+        let offset = GRID_LINE_WIDTH / 2.0;
+
+        for column in 0..=self.tiles.width() {
+            let x = column as f32 * self.tile_size.0 - offset;
+            self.renderer
+                .render_u8((x, start_y), (GRID_LINE_WIDTH, height), FULL_TILE, color);
+        }
+
+        for row in 0..=self.tiles.height() {
+            let y = start_y + row as f32 * self.tile_size.1 - offset;
+            self.renderer.render_u8(
+                (-offset, y),
+                (width + GRID_LINE_WIDTH, GRID_LINE_WIDTH),
+                FULL_TILE,
+                color,
+            );
+        }
+    }
+
     fn calculate_point(&mut self, index: usize) -> Point {
         let point0 = self.tiles.to_x_and_y(index);
-        (
-            point0[0] as f32 * self.tile_size.0,
-            point0[1] as f32 * self.tile_size.1,
-        )
+        let x = point0[0] as f32 * self.tile_size.0;
+
+        match self.y_axis {
+            YAxis::Up => (x, point0[1] as f32 * self.tile_size.1),
+            YAxis::Down => (x, self.height - (point0[1] + 1) as f32 * self.tile_size.1),
+        }
     }
 }
 
@@ -83,3 +138,92 @@ pub fn calculate_tile_index(window_size: Size2d, tile_size: Size2d, point: (u32,
     let tiles = calculate_tiles(window_size, tile_size);
     tiles.to_index_risky(point.0 / tile_size.width(), point.1 / tile_size.height())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::color::WHITE;
+
+    #[derive(Default)]
+    struct MockRenderer {
+        chars: Vec<(Point, char)>,
+        tiles: Vec<(Point, Point, u8)>,
+    }
+
+    impl AsciiRenderer for MockRenderer {
+        fn render_text(&mut self, _position: Point, _size: Point, _string: &str, _color: Color) {}
+
+        fn render_char(&mut self, position: Point, _size: Point, character: char, _color: Color) {
+            self.chars.push((position, character));
+        }
+
+        fn render_u8(&mut self, position: Point, size: Point, ascii: u8, _color: Color) {
+            self.tiles.push((position, size, ascii));
+        }
+    }
+
+    fn render_spaced(advance: f32) -> Vec<(Point, char)> {
+        let mut renderer = MockRenderer::default();
+        let mut tile_renderer =
+            TileRenderer::new(Size2d::new(10, 5), Size2d::new(8, 12), &mut renderer);
+
+        tile_renderer.render_text_spaced(12, "abc", WHITE, advance);
+
+        renderer.chars
+    }
+
+    #[test]
+    fn test_render_text_spaced() {
+        assert_eq!(
+            render_spaced(8.0),
+            vec![
+                ((16.0, 12.0), 'a'),
+                ((24.0, 12.0), 'b'),
+                ((32.0, 12.0), 'c')
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_text_condensed() {
+        assert_eq!(
+            render_spaced(5.0),
+            vec![
+                ((16.0, 12.0), 'a'),
+                ((21.0, 12.0), 'b'),
+                ((26.0, 12.0), 'c')
+            ]
+        );
+    }
+
+    fn render_grid(y_axis: YAxis) -> Vec<(Point, Point, u8)> {
+        let mut renderer = MockRenderer::default();
+        TileRenderer::new(Size2d::new(3, 2), Size2d::new(8, 12), &mut renderer)
+            .with_y_axis(y_axis, 30)
+            .render_grid(WHITE);
+
+        renderer.tiles
+    }
+
+    #[test]
+    fn test_render_grid() {
+        let lines = render_grid(YAxis::Up);
+
+        assert_eq!(lines.len(), 7);
+        assert!(lines.iter().all(|(_, _, ascii)| *ascii == FULL_TILE));
+        assert_eq!(lines[0], ((-0.5, 0.0), (1.0, 24.0), FULL_TILE));
+        assert_eq!(lines[3], ((23.5, 0.0), (1.0, 24.0), FULL_TILE));
+        assert_eq!(lines[4], ((-0.5, -0.5), (25.0, 1.0), FULL_TILE));
+        assert_eq!(lines[6], ((-0.5, 23.5), (25.0, 1.0), FULL_TILE));
+    }
+
+    #[test]
+    fn test_render_grid_with_y_axis_down() {
+        let lines = render_grid(YAxis::Down);
+
+        assert_eq!(lines.len(), 7);
+        assert_eq!(lines[0], ((-0.5, 6.0), (1.0, 24.0), FULL_TILE));
+        assert_eq!(lines[4], ((-0.5, 5.5), (25.0, 1.0), FULL_TILE));
+        assert_eq!(lines[6], ((-0.5, 29.5), (25.0, 1.0), FULL_TILE));
+    }
+}
@@ -1,7 +1,7 @@
 use crate::renderer::GliumRenderer;
 use crate::texture::load_texture;
 use ofws_core::data::math::size2d::Size2d;
-use ofws_core::interface::rendering::{Initialization, TextureId};
+use ofws_core::interface::rendering::{Initialization, TextureId, YAxis};
 
 pub struct GliumInitialization {
     display: glium::Display,
@@ -16,8 +16,8 @@ impl GliumInitialization {
         }
     }
 
-    pub fn finish(self, size: Size2d) -> GliumRenderer {
-        GliumRenderer::new(self.display, self.textures, size)
+    pub fn finish(self, size: Size2d, y_axis: YAxis) -> GliumRenderer {
+        GliumRenderer::new(self.display, self.textures, size, y_axis)
     }
 }
 
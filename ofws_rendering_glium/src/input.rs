@@ -1,4 +1,4 @@
-use glium::glutin::event::VirtualKeyCode;
+use glium::glutin::event::{MouseScrollDelta, VirtualKeyCode};
 use ofws_core::interface::input::{KeyCode, MouseButton};
 
 pub fn convert_key_code(value: VirtualKeyCode) -> Option<KeyCode> {
@@ -99,3 +99,38 @@ pub fn convert_mouse_button(value: GliumMouseButton) -> Option<MouseButton> {
         _ => None,
     }
 }
+
+/// How many pixels of a touchpad are treated like a single line of a mouse wheel.
+const PIXELS_PER_LINE: f32 = 20.0;
+
+/// Converts the scrolling of a mouse wheel or touchpad into lines. Positive values scroll up.
+pub fn convert_mouse_scroll_delta(value: MouseScrollDelta) -> f32 {
+    match value {
+        MouseScrollDelta::LineDelta(_, y) => y,
+        MouseScrollDelta::PixelDelta(position) => position.y as f32 / PIXELS_PER_LINE,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use glium::glutin::dpi::PhysicalPosition;
+
+    #[test]
+    fn test_convert_line_delta() {
+        assert_eq!(
+            convert_mouse_scroll_delta(MouseScrollDelta::LineDelta(5.0, -2.0)),
+            -2.0
+        );
+    }
+
+    #[test]
+    fn test_convert_pixel_delta() {
+        let position = PhysicalPosition::new(100.0, 30.0);
+
+        assert_eq!(
+            convert_mouse_scroll_delta(MouseScrollDelta::PixelDelta(position)),
+            1.5
+        );
+    }
+}
@@ -6,7 +6,7 @@ use glium::{Program, Surface};
 use ofws_core::data::color::Color;
 use ofws_core::data::math::size2d::Size2d;
 use ofws_core::interface::rendering::{
-    AsciiRenderer, ColorRenderer, Renderer, TextureId, TextureRenderer,
+    AsciiRenderer, ColorRenderer, Renderer, TextureId, TextureRenderer, YAxis,
 };
 use ofws_core::rendering::tile::{calculate_tiles, TileRenderer};
 
@@ -20,6 +20,7 @@ struct TextureData {
 
 pub struct GliumRenderer {
     size: Size2d,
+    y_axis: YAxis,
     display: glium::Display,
     target: Option<glium::Frame>,
     color_builder: ColorBuilder,
@@ -34,6 +35,7 @@ impl GliumRenderer {
         display: glium::Display,
         textures: Vec<glium::texture::Texture2d>,
         size: Size2d,
+        y_axis: YAxis,
     ) -> GliumRenderer {
         let colored_program = load_program(&display, "colored.vertex", "colored.fragment");
         let textured_program = load_program(&display, "textured.vertex", "textured.fragment");
@@ -57,6 +59,7 @@ impl GliumRenderer {
 
         GliumRenderer {
             size,
+            y_axis,
             display,
             target: None,
             color_builder: ColorBuilder::default(),
@@ -171,6 +174,7 @@ impl Renderer for GliumRenderer {
     fn get_tile_renderer(&mut self, id: usize, tile_size: Size2d) -> TileRenderer {
         let tiles = calculate_tiles(self.size, tile_size);
         TileRenderer::new(tiles, tile_size, &mut self.texture_data[id].builder)
+            .with_y_axis(self.y_axis, self.size.height())
     }
 }
 
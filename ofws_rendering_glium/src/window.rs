@@ -1,31 +1,50 @@
 use crate::initialization::GliumInitialization;
-use crate::input::{convert_key_code, convert_mouse_button};
-use core::cmp;
+use crate::input::{convert_key_code, convert_mouse_button, convert_mouse_scroll_delta};
 use glium::glutin::dpi::PhysicalPosition;
-use glium::glutin::event::{ElementState, KeyboardInput, MouseButton};
+use glium::glutin::event::{ElementState, KeyboardInput, MouseButton, MouseScrollDelta};
 use glium::{glutin, Display};
 use ofws_core::data::math::size2d::Size2d;
 use ofws_core::interface::app::App;
+use ofws_core::interface::input::KeyCode;
+use ofws_core::interface::rendering::YAxis;
 use ofws_core::interface::window::Window;
 use ofws_core::logging::init_logging;
 use std::cell::RefCell;
+use std::collections::HashSet;
 use std::ops::Sub;
 use std::rc::Rc;
 
 pub struct GliumWindow {
     title: &'static str,
     size: Size2d,
+    y_axis: YAxis,
+    target_fps: u32,
 }
 
 impl GliumWindow {
     pub fn new(title: &'static str, size: Size2d) -> GliumWindow {
-        GliumWindow { title, size }
+        GliumWindow {
+            title,
+            size,
+            y_axis: YAxis::default(),
+            target_fps: 60,
+        }
     }
 
     pub fn default_size(title: &'static str) -> GliumWindow {
         GliumWindow::new(title, Size2d::new(800, 600))
     }
 
+    /// Sets the direction of the y-axis for rendering & mouse input.
+    pub fn set_y_axis(&mut self, y_axis: YAxis) {
+        self.y_axis = y_axis;
+    }
+
+    /// Sets the targeted frames per second. 0 renders as fast as possible.
+    pub fn set_target_fps(&mut self, target_fps: u32) {
+        self.target_fps = target_fps;
+    }
+
     fn create_display(&self, event_loop: &glutin::event_loop::EventLoop<()>) -> Display {
         let size = glutin::dpi::LogicalSize::new(self.size.width(), self.size.height());
         let wb = glutin::window::WindowBuilder::new()
@@ -50,20 +69,24 @@ impl Window for GliumWindow {
             reference.init(&mut initialization);
         }
 
-        let mut renderer = initialization.finish(self.size);
+        let mut renderer = initialization.finish(self.size, self.y_axis);
         let size = self.size;
+        let y_axis = self.y_axis;
+        let target_fps = self.target_fps;
         let mut last_rendering = std::time::Instant::now();
         let mut mouse_point = None;
+        let mut pressed_keys = HashSet::new();
 
         info!("Initialization finished");
 
         event_loop.run(move |event, _, control_flow| {
-            *control_flow = run_with_frequency(60);
+            *control_flow = run_with_frequency(target_fps);
 
             match event {
                 glutin::event::Event::NewEvents(event) => match event {
                     glutin::event::StartCause::ResumeTimeReached { .. } => {}
                     glutin::event::StartCause::WaitCancelled { .. } => {}
+                    glutin::event::StartCause::Poll => {}
                     _ => return,
                 },
                 glutin::event::Event::WindowEvent { event, .. } => match event {
@@ -72,11 +95,15 @@ impl Window for GliumWindow {
                         return;
                     }
                     glutin::event::WindowEvent::KeyboardInput { input, .. } => {
-                        handle_keyboard_input(&app, input);
+                        handle_keyboard_input(&app, &mut pressed_keys, input);
+                        return;
+                    }
+                    glutin::event::WindowEvent::Focused(false) => {
+                        pressed_keys.clear();
                         return;
                     }
                     glutin::event::WindowEvent::CursorMoved { position, .. } => {
-                        mouse_point = calculate_mouse_point(size, position);
+                        mouse_point = calculate_mouse_point(size, y_axis, position);
                         return;
                     }
                     glutin::event::WindowEvent::MouseInput { state, button, .. } => {
@@ -85,6 +112,12 @@ impl Window for GliumWindow {
                         }
                         return;
                     }
+                    glutin::event::WindowEvent::MouseWheel { delta, .. } => {
+                        if let Some(point) = mouse_point {
+                            handle_mouse_wheel(&app, point, delta);
+                        }
+                        return;
+                    }
                     _ => return,
                 },
                 glutin::event::Event::RedrawRequested(_) => (),
@@ -94,6 +127,7 @@ impl Window for GliumWindow {
             let start = std::time::Instant::now();
 
             let mut reference = app.borrow_mut();
+            reference.on_key_held(&pressed_keys);
             reference.render(&mut renderer);
 
             analyze_performance(start, &mut last_rendering);
@@ -101,22 +135,34 @@ impl Window for GliumWindow {
     }
 }
 
+/// Waits until the next frame or polls continuously, if the frequency is 0.
 fn run_with_frequency(frequency: u32) -> glutin::event_loop::ControlFlow {
+    if frequency == 0 {
+        return glutin::event_loop::ControlFlow::Poll;
+    }
+
     let next_frame_time =
         std::time::Instant::now() + std::time::Duration::from_secs_f32(1.0 / frequency as f32);
     glutin::event_loop::ControlFlow::WaitUntil(next_frame_time)
 }
 
-fn handle_keyboard_input(app: &Rc<RefCell<dyn App>>, input: KeyboardInput) {
-    if input.state == glutin::event::ElementState::Released {
-        if let Some(glutin_key) = input.virtual_keycode {
-            if let Some(key) = convert_key_code(glutin_key) {
+fn handle_keyboard_input(
+    app: &Rc<RefCell<dyn App>>,
+    pressed_keys: &mut HashSet<KeyCode>,
+    input: KeyboardInput,
+) {
+    if let Some(glutin_key) = input.virtual_keycode {
+        if let Some(key) = convert_key_code(glutin_key) {
+            if input.state == glutin::event::ElementState::Pressed {
+                pressed_keys.insert(key);
+            } else {
                 info!("Pressed key {:?}", key);
+                pressed_keys.remove(&key);
                 let mut reference = app.borrow_mut();
                 reference.on_key_released(key);
-            } else {
-                warn!("Unsupported key {:?}", glutin_key);
             }
+        } else if input.state == glutin::event::ElementState::Released {
+            warn!("Unsupported key {:?}", glutin_key);
         }
     }
 }
@@ -136,16 +182,36 @@ fn handle_mouse_input(
     }
 }
 
-fn calculate_mouse_point(size: Size2d, position: PhysicalPosition<f64>) -> Option<(u32, u32)> {
+fn handle_mouse_wheel(
+    app: &Rc<RefCell<dyn App>>,
+    mouse_point: (u32, u32),
+    delta: MouseScrollDelta,
+) {
+    let delta = convert_mouse_scroll_delta(delta);
+
+    if delta != 0.0 {
+        debug!("Scrolled {} at {:?}", delta, mouse_point);
+        let mut reference = app.borrow_mut();
+        reference.on_scroll(delta, mouse_point);
+    }
+}
+
+fn calculate_mouse_point(
+    size: Size2d,
+    y_axis: YAxis,
+    position: PhysicalPosition<f64>,
+) -> Option<(u32, u32)> {
     let x = position.x as u32;
     let y = position.y as u32;
 
-    if x > size.width() || y > size.height() {
+    if x >= size.width() || y >= size.height() {
         return None;
     }
 
-    let y = cmp::max(size.height() - y, 1) - 1;
-    Some((x, y))
+    match y_axis {
+        YAxis::Up => Some((x, size.height() - 1 - y)),
+        YAxis::Down => Some((x, y)),
+    }
 }
 
 fn analyze_performance(start: std::time::Instant, last_rendering: &mut std::time::Instant) {
@@ -156,3 +222,61 @@ fn analyze_performance(start: std::time::Instant, last_rendering: &mut std::time
     trace!("Finished after {:?}", duration);
     *last_rendering = end;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ofws_core::rendering::tile::calculate_tile_index;
+
+    fn calculate_tile(y_axis: YAxis, x: f64, y: f64) -> Option<usize> {
+        let size = Size2d::new(800, 600);
+        let tile_size = Size2d::new(20, 30);
+        calculate_mouse_point(size, y_axis, PhysicalPosition::new(x, y))
+            .map(|point| calculate_tile_index(size, tile_size, point))
+    }
+
+    #[test]
+    fn test_mouse_with_y_axis_up() {
+        assert_eq!(calculate_tile(YAxis::Up, 0.0, 599.0), Some(0));
+        assert_eq!(calculate_tile(YAxis::Up, 799.0, 599.0), Some(39));
+        assert_eq!(calculate_tile(YAxis::Up, 0.0, 0.0), Some(760));
+        assert_eq!(calculate_tile(YAxis::Up, 799.0, 0.0), Some(799));
+    }
+
+    #[test]
+    fn test_mouse_with_y_axis_down() {
+        assert_eq!(calculate_tile(YAxis::Down, 0.0, 0.0), Some(0));
+        assert_eq!(calculate_tile(YAxis::Down, 799.0, 0.0), Some(39));
+        assert_eq!(calculate_tile(YAxis::Down, 0.0, 599.0), Some(760));
+        assert_eq!(calculate_tile(YAxis::Down, 799.0, 599.0), Some(799));
+    }
+
+    #[test]
+    fn test_run_as_fast_as_possible() {
+        assert_eq!(run_with_frequency(0), glutin::event_loop::ControlFlow::Poll);
+    }
+
+    #[test]
+    fn test_run_with_frequency() {
+        for frequency in [30, 60] {
+            let start = std::time::Instant::now();
+            let control_flow = run_with_frequency(frequency);
+            let end = std::time::Instant::now();
+            let frame = std::time::Duration::from_secs_f32(1.0 / frequency as f32);
+
+            match control_flow {
+                glutin::event_loop::ControlFlow::WaitUntil(time) => {
+                    assert!(time >= start + frame);
+                    assert!(time <= end + frame);
+                }
+                _ => panic!("Unexpected control flow {:?}", control_flow),
+            }
+        }
+    }
+
+    #[test]
+    fn test_mouse_outside_window() {
+        assert_eq!(calculate_tile(YAxis::Up, 800.0, 0.0), None);
+        assert_eq!(calculate_tile(YAxis::Down, 0.0, 600.0), None);
+    }
+}